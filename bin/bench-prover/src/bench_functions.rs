@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use anyhow::Result;
 use miden_objects::{
     Felt,
@@ -8,7 +10,7 @@ use miden_objects::{
     transaction::ExecutedTransaction,
 };
 use miden_testing::{Auth, MockChain};
-use miden_tx::{LocalTransactionProver, ProvingOptions, TransactionProver};
+use miden_tx::{CalibrationPoint, LocalTransactionProver, ProvingOptions, TransactionProver};
 
 pub fn setup_consume_note_with_new_account() -> Result<ExecutedTransaction> {
     let mut mock_chain = MockChain::new();
@@ -112,3 +114,22 @@ pub fn prove_transaction(executed_transaction: ExecutedTransaction) -> Result<()
     assert_eq!(proven_transaction.id(), executed_transaction_id);
     Ok(())
 }
+
+/// Proves the provided transaction once using the default proving options and returns a
+/// [CalibrationPoint] consisting of the trace length of the transaction, the provided proving time
+/// and the size of the resulting proof.
+pub fn measure_calibration_point(
+    executed_transaction: ExecutedTransaction,
+    proving_time: Duration,
+) -> Result<CalibrationPoint> {
+    let trace_length = executed_transaction.measurements().trace_length();
+
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(executed_transaction.into())?;
+
+    Ok(CalibrationPoint {
+        trace_length,
+        proving_time,
+        proof_size: proven_transaction.proof().to_bytes().len(),
+    })
+}
@@ -1,23 +1,36 @@
-use std::path::PathBuf;
+use std::{fs, path::PathBuf, time::Duration};
 
 use anyhow::{Context, Result};
 use bench_prover::{
+    bench_functions::{
+        measure_calibration_point, setup_consume_multiple_notes,
+        setup_consume_note_with_new_account,
+    },
     benchmark_names::{BENCH_CONSUME_MULTIPLE_NOTES, BENCH_CONSUME_NOTE_NEW_ACCOUNT, BENCH_GROUP},
     utils::{cargo_target_directory, process_benchmark_data, save_json_to_file},
 };
+use miden_objects::{transaction::ExecutedTransaction, utils::Serializable};
+use miden_tx::{ProvingCalibration, ProvingOptions};
 use serde_json::json;
 
+/// A function setting up the transaction proven in a benchmark.
+type BenchmarkSetup = fn() -> Result<ExecutedTransaction>;
+
 fn main() -> Result<()> {
     let target_dir = cargo_target_directory().unwrap_or_else(|| PathBuf::from("target"));
     let base_path = target_dir.join("criterion").join(BENCH_GROUP);
 
     println!("Looking for benchmark results in: {}", base_path.display());
 
-    let benchmarks = [BENCH_CONSUME_NOTE_NEW_ACCOUNT, BENCH_CONSUME_MULTIPLE_NOTES];
+    let benchmarks: [(&str, BenchmarkSetup); 2] = [
+        (BENCH_CONSUME_NOTE_NEW_ACCOUNT, setup_consume_note_with_new_account),
+        (BENCH_CONSUME_MULTIPLE_NOTES, setup_consume_multiple_notes),
+    ];
 
     let mut consolidated_results = json!({});
+    let mut calibration_points = Vec::new();
 
-    for benchmark in benchmarks {
+    for (benchmark, setup) in benchmarks {
         let benchmark_path = base_path.join(benchmark).join("new");
 
         println!("\nProcessing benchmark: {benchmark}");
@@ -29,15 +42,26 @@ fn main() -> Result<()> {
             ));
         }
 
-        match process_benchmark_data(&benchmark_path) {
-            Ok(benchmark_data) => {
-                consolidated_results[benchmark] = benchmark_data;
-            },
+        let mut benchmark_data = match process_benchmark_data(&benchmark_path) {
+            Ok(benchmark_data) => benchmark_data,
             Err(err) => {
                 return Err(err)
                     .with_context(|| format!("Failed to process benchmark: {benchmark}"));
             },
-        }
+        };
+
+        // prove the benchmarked transaction once more to measure its trace length and proof size
+        let mean_sec = benchmark_data["mean_sec"]
+            .as_f64()
+            .with_context(|| format!("Missing mean proving time of benchmark: {benchmark}"))?;
+        let calibration_point =
+            measure_calibration_point(setup()?, Duration::from_secs_f64(mean_sec))
+                .with_context(|| format!("Failed to measure proof of benchmark: {benchmark}"))?;
+        benchmark_data["trace_length"] = json!(calibration_point.trace_length);
+        benchmark_data["proof_size_bytes"] = json!(calibration_point.proof_size);
+
+        consolidated_results[benchmark] = benchmark_data;
+        calibration_points.push(calibration_point);
     }
 
     // Rest of the code remains the same
@@ -47,5 +71,14 @@ fn main() -> Result<()> {
     save_json_to_file(&consolidated_results, &output_path)
         .with_context(|| format!("Failed to save results to {}", output_path.display()))?;
 
+    let calibration = ProvingCalibration::new(&ProvingOptions::default(), calibration_points)
+        .context("Failed to build proving calibration")?;
+    let calibration_path = target_dir.join("criterion").join("proving_calibration.bin");
+    println!("Writing proving calibration to {}", calibration_path.display());
+
+    fs::write(&calibration_path, calibration.to_bytes()).with_context(|| {
+        format!("Failed to save proving calibration to {}", calibration_path.display())
+    })?;
+
     Ok(())
 }
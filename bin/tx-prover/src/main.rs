@@ -0,0 +1,38 @@
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::{env, net::TcpListener};
+
+use anyhow::Context;
+use miden_tx::{LocalTransactionProver, ProverServer, ProvingOptions, ServiceAddress};
+
+const USAGE: &str = "usage: tx-prover <ADDRESS>
+
+Proves the transactions sent by `RemoteTransactionProver`s using a `LocalTransactionProver`.
+
+ADDRESS is either `<host>:<port>` to listen on a TCP socket or `unix:<path>` to listen on a
+Unix domain socket.";
+
+fn main() -> anyhow::Result<()> {
+    let Some(address) = env::args().nth(1).filter(|arg| arg != "-h" && arg != "--help") else {
+        println!("{USAGE}");
+        return Ok(());
+    };
+    let address: ServiceAddress =
+        address.parse().map_err(anyhow::Error::msg).context("failed to parse address")?;
+
+    let server = ProverServer::new(LocalTransactionProver::new(ProvingOptions::default()));
+    println!("listening on {address}");
+    match address {
+        ServiceAddress::Tcp(addr) => {
+            let listener = TcpListener::bind(addr).context("failed to bind TCP socket")?;
+            server.serve_tcp(listener)?;
+        },
+        #[cfg(unix)]
+        ServiceAddress::Unix(path) => {
+            let listener = UnixListener::bind(&path).context("failed to bind Unix socket")?;
+            server.serve_unix(listener)?;
+        },
+    }
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::{env, fs, net::TcpListener};
+
+use anyhow::{Context, bail};
+use miden_objects::{Digest, Word, account::AuthSecretKey, utils::Deserializable};
+use miden_tx::auth::{BasicAuthenticator, SignerAddress, SignerServer};
+use rand::rngs::StdRng;
+
+const USAGE: &str = "usage: tx-signer <ADDRESS> <KEY_FILE>...
+
+Serves signature requests from `SocketAuthenticator`s using the provided keys.
+
+ADDRESS is either `<host>:<port>` to listen on a TCP socket or `unix:<path>` to listen on a
+Unix domain socket. Each KEY_FILE contains a serialized `AuthSecretKey`.";
+
+fn main() -> anyhow::Result<()> {
+    let mut args = env::args().skip(1);
+    let Some(address) = args.next().filter(|arg| arg != "-h" && arg != "--help") else {
+        println!("{USAGE}");
+        return Ok(());
+    };
+    let address: SignerAddress =
+        address.parse().map_err(anyhow::Error::msg).context("failed to parse address")?;
+
+    let keys = args.map(|path| read_key(&path)).collect::<anyhow::Result<Vec<_>>>()?;
+    if keys.is_empty() {
+        bail!("no key files provided\n\n{USAGE}");
+    }
+    for (pub_key, _) in &keys {
+        println!("loaded key with public key commitment {}", Digest::from(*pub_key));
+    }
+
+    let server = SignerServer::new(BasicAuthenticator::<StdRng>::new(&keys));
+    println!("listening on {address}");
+    match address {
+        SignerAddress::Tcp(addr) => {
+            let listener = TcpListener::bind(addr).context("failed to bind TCP socket")?;
+            server.serve_tcp(listener)?;
+        },
+        #[cfg(unix)]
+        SignerAddress::Unix(path) => {
+            let listener = UnixListener::bind(&path).context("failed to bind Unix socket")?;
+            server.serve_unix(listener)?;
+        },
+    }
+
+    Ok(())
+}
+
+/// Reads the secret key from the file at the provided path and returns it together with the
+/// commitment to its public key.
+fn read_key(path: &str) -> anyhow::Result<(Word, AuthSecretKey)> {
+    let bytes = fs::read(path).with_context(|| format!("failed to read key file {path}"))?;
+    let key = AuthSecretKey::read_from_bytes(&bytes)
+        .with_context(|| format!("failed to deserialize key file {path}"))?;
+    let pub_key = match &key {
+        AuthSecretKey::RpoFalcon512(secret_key) => secret_key.public_key().into(),
+    };
+
+    Ok((pub_key, key))
+}
@@ -77,6 +77,7 @@ impl LocalBlockProver {
 
         let block_num = proposed_block.block_num();
         let timestamp = proposed_block.timestamp();
+        let fee = proposed_block.fee();
 
         // Split the proposed block into its parts.
         // --------------------------------------------------------------------------------------------
@@ -91,6 +92,7 @@ impl LocalBlockProver {
         ) = proposed_block.into_parts();
 
         let prev_block_commitment = prev_block_header.commitment();
+        let fee_parameters = *prev_block_header.fee_parameters();
 
         // Compute the root of the block note tree.
         // --------------------------------------------------------------------------------------------
@@ -163,6 +165,7 @@ impl LocalBlockProver {
             tx_kernel_commitment,
             proof_commitment,
             timestamp,
+            fee_parameters,
         );
 
         // Construct the new proven block.
@@ -174,6 +177,7 @@ impl LocalBlockProver {
             output_note_batches,
             created_nullifiers,
             txs,
+            fee,
         );
 
         Ok(proven_block)
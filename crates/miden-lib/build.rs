@@ -696,6 +696,21 @@ fn generate_error_file_content(category: ErrorCategory, errors: Vec<NamedError>)
         .into_diagnostic()?;
     }
 
+    // Generate a table of all errors in the file along with the names of their constants, which is
+    // used to decode error codes.
+    writeln!(output).into_diagnostic()?;
+    writeln!(
+        output,
+        "/// All errors defined in this file along with the names of their constants."
+    )
+    .into_diagnostic()?;
+    writeln!(output, "pub const {}: &[(&str, MasmError)] = &[", category.array_name())
+        .into_diagnostic()?;
+    for NamedError { name, .. } in errors.iter() {
+        writeln!(output, "    (\"ERR_{name}\", ERR_{name}),").into_diagnostic()?;
+    }
+    writeln!(output, "];").into_diagnostic()?;
+
     Ok(output)
 }
 
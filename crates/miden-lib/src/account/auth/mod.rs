@@ -1,12 +1,15 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
 
 use miden_objects::{
-    AccountError, Digest, Felt, FieldElement,
-    account::{AccountCode, AccountComponent, StorageMap, StorageSlot},
+    AccountError, Digest, Felt, FieldElement, Word,
+    account::{AccountCode, AccountComponent, AccountType, StorageMap, StorageSlot},
     crypto::dsa::rpo_falcon512::PublicKey,
 };
 
-use crate::account::components::{rpo_falcon_512_library, rpo_falcon_512_procedure_acl_library};
+use crate::account::components::{
+    rpo_falcon_512_code_update_library, rpo_falcon_512_library, rpo_falcon_512_multisig_library,
+    rpo_falcon_512_procedure_acl_library,
+};
 
 /// An [`AccountComponent`] implementing the RpoFalcon512 signature scheme for authentication of
 /// transactions.
@@ -43,6 +46,44 @@ impl From<RpoFalcon512> for AccountComponent {
     }
 }
 
+/// An [`AccountComponent`] implementing the RpoFalcon512 signature scheme for authentication of
+/// transactions, which allows the code of the account to be replaced.
+///
+/// It reexports the procedure `auth__tx_rpo_falcon512_code_update` from
+/// `miden::contracts::auth::basic`, which:
+/// - Sets the code of the account to the code provided to the transaction via
+///   [`TransactionArgs::with_new_account_code`](miden_objects::transaction::TransactionArgs::with_new_account_code).
+///   If no code is provided, the code remains unchanged.
+/// - Computes the same message as [`RpoFalcon512`], extended with the commitment of the new account
+///   code, so that the code can only be replaced with the approval of the key holder.
+/// - Verifies a signature over the message provided via the advice stack.
+/// - Always increments the nonce.
+///
+/// The storage layout is the same as the one of [`RpoFalcon512`].
+///
+/// This component supports only accounts with updatable code.
+pub struct RpoFalcon512CodeUpdate {
+    public_key: PublicKey,
+}
+
+impl RpoFalcon512CodeUpdate {
+    /// Creates a new [`RpoFalcon512CodeUpdate`] component with the given `public_key`.
+    pub fn new(public_key: PublicKey) -> Self {
+        Self { public_key }
+    }
+}
+
+impl From<RpoFalcon512CodeUpdate> for AccountComponent {
+    fn from(falcon: RpoFalcon512CodeUpdate) -> Self {
+        AccountComponent::new(
+            rpo_falcon_512_code_update_library(),
+            vec![StorageSlot::Value(falcon.public_key.into())],
+        )
+        .expect("falcon code update component should satisfy the requirements of a valid account component")
+        .with_supported_type(AccountType::RegularAccountUpdatableCode)
+    }
+}
+
 /// An [`AccountComponent`] implementing a procedure-ACL based RpoFalcon512 signature scheme for
 /// authentication of transactions.
 ///
@@ -119,12 +160,109 @@ impl From<RpoFalcon512ProcedureAcl> for AccountComponent {
     }
 }
 
+/// An [`AccountComponent`] implementing a k-of-n threshold variant of the RpoFalcon512 signature
+/// scheme for authentication of transactions.
+///
+/// It reexports the procedure `auth__tx_rpo_falcon512_multisig` from
+/// `miden::contracts::auth::multisig`, which:
+/// - Computes the same message (transaction summary) as [`RpoFalcon512`].
+/// - Requests a signature over the message from each approver in order, until `threshold`
+///   signatures have been verified. Approvers which do not provide a signature are skipped.
+/// - Fails if any provided signature is invalid, or if fewer than `threshold` signatures are
+///   provided.
+/// - Always increments the nonce.
+///
+/// Since the signature of each approver is verified at most once and the public keys of the
+/// approvers are distinct, the signatures are always provided by `threshold` distinct approvers.
+///
+/// The storage layout is:
+/// - Slot 0(value): `[threshold, num_approvers, 0, 0]`
+/// - Slot 1(map): A map from `[i, 0, 0, 0]` to the public key of the i-th approver
+///
+/// This component supports all account types.
+pub struct RpoFalcon512Multisig {
+    threshold: u32,
+    approvers: Vec<PublicKey>,
+}
+
+impl RpoFalcon512Multisig {
+    /// Creates a new [`RpoFalcon512Multisig`] component requiring signatures from `threshold` of
+    /// the given approvers.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The threshold is zero or greater than the number of approvers.
+    /// - The same public key is provided for several approvers.
+    pub fn new(threshold: u32, approvers: Vec<PublicKey>) -> Result<Self, AccountError> {
+        if threshold == 0 || threshold as usize > approvers.len() {
+            return Err(AccountError::AssumptionViolated(format!(
+                "multisig threshold must be between 1 and the number of approvers ({}), but was \
+                 {threshold}",
+                approvers.len()
+            )));
+        }
+
+        let unique_approvers: BTreeSet<Digest> =
+            approvers.iter().map(|pub_key| Digest::from(Word::from(*pub_key))).collect();
+        if unique_approvers.len() != approvers.len() {
+            return Err(AccountError::AssumptionViolated(
+                "multisig approvers must have distinct public keys".to_string(),
+            ));
+        }
+
+        Ok(Self { threshold, approvers })
+    }
+
+    /// Returns the number of signatures required to authenticate a transaction.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns the public keys of the approvers.
+    pub fn approvers(&self) -> &[PublicKey] {
+        &self.approvers
+    }
+}
+
+impl From<RpoFalcon512Multisig> for AccountComponent {
+    fn from(multisig: RpoFalcon512Multisig) -> Self {
+        let mut storage_slots = Vec::with_capacity(2);
+
+        // Slot 0: Threshold and number of approvers
+        let num_approvers = Felt::from(multisig.approvers.len() as u32);
+        storage_slots.push(StorageSlot::Value([
+            Felt::from(multisig.threshold),
+            num_approvers,
+            Felt::ZERO,
+            Felt::ZERO,
+        ]));
+
+        // Slot 1: A map with the public keys of the approvers
+        let map_entries = multisig.approvers.iter().enumerate().map(|(i, pub_key)| {
+            (
+                [Felt::from(i as u32), Felt::ZERO, Felt::ZERO, Felt::ZERO].into(),
+                Word::from(*pub_key),
+            )
+        });
+
+        // Safe to unwrap because we know that the map keys are unique.
+        storage_slots.push(StorageSlot::Map(StorageMap::with_entries(map_entries).unwrap()));
+
+        AccountComponent::new(rpo_falcon_512_multisig_library(), storage_slots)
+            .expect(
+                "multisig auth component should satisfy the requirements of a valid account \
+                 component",
+            )
+            .with_supports_all_types()
+    }
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use miden_objects::{Word, ZERO, account::AccountBuilder};
+    use miden_objects::{ONE, Word, ZERO, account::AccountBuilder};
 
     use super::*;
     use crate::account::{components::basic_wallet_library, wallets::BasicWallet};
@@ -199,4 +337,32 @@ mod tests {
             .expect("storage map access failed");
         assert_eq!(proc_root_1, Word::from(auth_trigger_procedures[1]));
     }
+
+    #[test]
+    fn test_rpo_falcon_512_multisig() {
+        let approvers = vec![PublicKey::new([ONE; 4]), PublicKey::new([Felt::new(2); 4])];
+
+        assert!(RpoFalcon512Multisig::new(0, approvers.clone()).is_err());
+        assert!(RpoFalcon512Multisig::new(3, approvers.clone()).is_err());
+        assert!(RpoFalcon512Multisig::new(1, vec![approvers[0], approvers[0]]).is_err());
+
+        let component =
+            RpoFalcon512Multisig::new(2, approvers.clone()).expect("component creation failed");
+        let (account, _) = AccountBuilder::new([0; 32])
+            .with_auth_component(component)
+            .with_component(BasicWallet)
+            .build()
+            .expect("account building failed");
+
+        let config_slot = account.storage().get_item(0).expect("storage slot 0 access failed");
+        assert_eq!(config_slot, [Felt::new(2), Felt::new(2), ZERO, ZERO].into());
+
+        for (i, approver) in approvers.iter().enumerate() {
+            let pub_key = account
+                .storage()
+                .get_map_item(1, [Felt::new(i as u64), ZERO, ZERO, ZERO])
+                .expect("storage map access failed");
+            assert_eq!(pub_key, Word::from(*approver));
+        }
+    }
 }
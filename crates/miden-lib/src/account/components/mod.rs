@@ -36,6 +36,25 @@ static RPO_FALCON_512_PROCEDURE_ACL_LIBRARY: LazyLock<Library> = LazyLock::new(|
         .expect("Shipped Rpo Falcon 512 Procedure ACL library is well-formed")
 });
 
+// Initialize the Rpo Falcon 512 Multisig library only once.
+static RPO_FALCON_512_MULTISIG_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/rpo_falcon_512_multisig.masl"
+    ));
+    Library::read_from_bytes(bytes).expect("Shipped Rpo Falcon 512 Multisig library is well-formed")
+});
+
+// Initialize the Rpo Falcon 512 Code Update library only once.
+static RPO_FALCON_512_CODE_UPDATE_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/rpo_falcon_512_code_update.masl"
+    ));
+    Library::read_from_bytes(bytes)
+        .expect("Shipped Rpo Falcon 512 Code Update library is well-formed")
+});
+
 /// Returns the Basic Wallet Library.
 pub fn basic_wallet_library() -> Library {
     BASIC_WALLET_LIBRARY.clone()
@@ -55,3 +74,13 @@ pub fn basic_fungible_faucet_library() -> Library {
 pub fn rpo_falcon_512_procedure_acl_library() -> Library {
     RPO_FALCON_512_PROCEDURE_ACL_LIBRARY.clone()
 }
+
+/// Returns the Rpo Falcon 512 Multisig Library.
+pub fn rpo_falcon_512_multisig_library() -> Library {
+    RPO_FALCON_512_MULTISIG_LIBRARY.clone()
+}
+
+/// Returns the Rpo Falcon 512 Code Update Library.
+pub fn rpo_falcon_512_code_update_library() -> Library {
+    RPO_FALCON_512_CODE_UPDATE_LIBRARY.clone()
+}
@@ -0,0 +1,175 @@
+use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
+
+use miden_objects::{
+    Digest, Felt,
+    account::AccountComponent,
+    assembly::{
+        Library,
+        mast::{MastForest, error_code_from_msg},
+    },
+};
+
+use super::{note_script_errors::NOTE_SCRIPT_ERRORS, tx_kernel_errors::TX_KERNEL_ERRORS};
+use crate::note::well_known_note::WellKnownNote;
+
+// ERROR CODE REGISTRY
+// ================================================================================================
+
+/// A registry used to decode the numeric error codes of failed assertions into the errors they
+/// were derived from.
+///
+/// The errors defined in the transaction kernel and in the MASM code of this library (e.g., in the
+/// standard note scripts and account components) are always part of the registry and are decoded
+/// into the names of their constants (e.g., `ERR_VAULT_FUNGIBLE_MAX_AMOUNT_EXCEEDED`).
+///
+/// Additional errors can be registered by name via [ErrorCodeRegistry::with_error], and the error
+/// messages embedded in compiled libraries, such as the libraries of user-defined account
+/// components, can be registered via [ErrorCodeRegistry::with_library]. As the names of the
+/// constants are not part of compiled code, errors of registered libraries are decoded into their
+/// messages only.
+///
+/// Additionally, the registry resolves the roots of the procedures exported by registered
+/// libraries and the roots of the well-known note scripts (e.g., `P2ID`) into their names, which
+/// allows the procedure in which an execution failed to be reported.
+#[derive(Debug, Clone, Default)]
+pub struct ErrorCodeRegistry {
+    named_errors: BTreeMap<u64, DecodedError>,
+    mast_forests: Vec<Arc<MastForest>>,
+    procedure_names: BTreeMap<Digest, Arc<str>>,
+}
+
+impl ErrorCodeRegistry {
+    /// Returns a new [ErrorCodeRegistry] containing the errors defined in this library.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an error with the provided name and message.
+    ///
+    /// The code of the error is derived from its message, in the same way as the assembler derives
+    /// it from the message of an `assert.err="<message>"` instruction.
+    pub fn with_error(mut self, name: impl Into<Arc<str>>, message: impl Into<Arc<str>>) -> Self {
+        let message = message.into();
+        let code = error_code_from_msg(&message);
+        self.named_errors
+            .insert(code.as_int(), DecodedError { name: Some(name.into()), message });
+        self
+    }
+
+    /// Registers the error messages embedded in the provided library and the names of the
+    /// procedures it exports.
+    pub fn with_library(mut self, library: &Library) -> Self {
+        for name in library.exports() {
+            let root = library.mast_forest()[library.get_export_node_id(name)].digest();
+            self.procedure_names.insert(root, Arc::from(name.to_string()));
+        }
+        self.mast_forests.push(library.mast_forest().clone());
+        self
+    }
+
+    /// Registers the error messages embedded in the library of the provided account component and
+    /// the names of the procedures it exports.
+    pub fn with_account_component(self, component: &AccountComponent) -> Self {
+        self.with_library(component.library())
+    }
+
+    /// Decodes the provided error code.
+    ///
+    /// Errors registered by name take precedence over the errors of this library, which in turn
+    /// take precedence over the errors embedded in registered libraries.
+    ///
+    /// Returns `None` if the code does not belong to any of the known errors.
+    pub fn decode(&self, code: Felt) -> Option<DecodedError> {
+        if let Some(error) = self.named_errors.get(&code.as_int()) {
+            return Some(error.clone());
+        }
+
+        let builtin_error = TX_KERNEL_ERRORS
+            .iter()
+            .chain(NOTE_SCRIPT_ERRORS)
+            .find(|(_, error)| error.code() == code);
+        if let Some((name, error)) = builtin_error {
+            return Some(DecodedError {
+                name: Some(Arc::from(*name)),
+                message: Arc::from(error.message()),
+            });
+        }
+
+        self.mast_forests
+            .iter()
+            .find_map(|mast_forest| mast_forest.resolve_error_message(code))
+            .map(|message| DecodedError { name: None, message })
+    }
+
+    /// Returns the fully-qualified name of the procedure with the provided MAST root, if the
+    /// procedure is exported by a registered library, or the name of the well-known note script
+    /// with the provided root.
+    pub fn procedure_name(&self, root: Digest) -> Option<&str> {
+        if let Some(name) = self.procedure_names.get(&root) {
+            return Some(name);
+        }
+
+        [
+            (WellKnownNote::P2ID, "P2ID"),
+            (WellKnownNote::P2IDE, "P2IDE"),
+            (WellKnownNote::SWAP, "SWAP"),
+        ]
+        .into_iter()
+        .find(|(note, _)| note.script_root() == root)
+        .map(|(_, name)| name)
+    }
+}
+
+// DECODED ERROR
+// ================================================================================================
+
+/// An error decoded from its code via an [ErrorCodeRegistry].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedError {
+    name: Option<Arc<str>>,
+    message: Arc<str>,
+}
+
+impl DecodedError {
+    /// Returns the name of the constant defining the error, if known.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the message of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl core::fmt::Display for DecodedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{name} (\"{}\")", self.message),
+            None => write!(f, "\"{}\"", self.message),
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::{MasmError, tx_kernel_errors::ERR_VAULT_FUNGIBLE_MAX_AMOUNT_EXCEEDED};
+
+    #[test]
+    fn decode_error_codes() {
+        let registry = ErrorCodeRegistry::new().with_error("ERR_CUSTOM", "custom error");
+
+        let decoded = registry.decode(ERR_VAULT_FUNGIBLE_MAX_AMOUNT_EXCEEDED.code()).unwrap();
+        assert_eq!(decoded.name(), Some("ERR_VAULT_FUNGIBLE_MAX_AMOUNT_EXCEEDED"));
+        assert_eq!(decoded.message(), ERR_VAULT_FUNGIBLE_MAX_AMOUNT_EXCEEDED.message());
+
+        let decoded = registry.decode(MasmError::from_static_str("custom error").code()).unwrap();
+        assert_eq!(decoded.name(), Some("ERR_CUSTOM"));
+
+        assert!(registry.decode(MasmError::from_static_str("unknown error").code()).is_none());
+    }
+}
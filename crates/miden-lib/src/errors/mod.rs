@@ -1,10 +1,19 @@
 #[cfg(any(feature = "testing", test))]
 #[rustfmt::skip]
 pub mod tx_kernel_errors;
+#[cfg(not(any(feature = "testing", test)))]
+#[rustfmt::skip]
+mod tx_kernel_errors;
 
 #[cfg(any(feature = "testing", test))]
 #[rustfmt::skip]
 pub mod note_script_errors;
+#[cfg(not(any(feature = "testing", test)))]
+#[rustfmt::skip]
+mod note_script_errors;
+
+mod error_code_registry;
+pub use error_code_registry::{DecodedError, ErrorCodeRegistry};
 
 mod masm_error;
 pub use masm_error::MasmError;
@@ -14,5 +23,6 @@ pub use script_builder_errors::ScriptBuilderError;
 
 mod transaction_errors;
 pub use transaction_errors::{
-    TransactionEventError, TransactionKernelError, TransactionTraceParsingError,
+    TransactionEventError, TransactionKernelError, TransactionKernelSelectionError,
+    TransactionTraceParsingError,
 };
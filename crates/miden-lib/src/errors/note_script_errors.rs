@@ -10,6 +10,9 @@ use crate::errors::MasmError;
 // NOTE SCRIPT ERRORS
 // ================================================================================================
 
+/// Error Message: "number of valid signatures is below the multisig threshold"
+pub const ERR_MULTISIG_THRESHOLD_NOT_MET: MasmError = MasmError::from_static_str("number of valid signatures is below the multisig threshold");
+
 /// Error Message: "failed to reclaim P2IDE note because the reclaiming account is not the sender"
 pub const ERR_P2IDE_RECLAIM_ACCT_IS_NOT_SENDER: MasmError = MasmError::from_static_str("failed to reclaim P2IDE note because the reclaiming account is not the sender");
 /// Error Message: "P2IDE reclaim is disabled"
@@ -30,3 +33,17 @@ pub const ERR_P2ID_WRONG_NUMBER_OF_INPUTS: MasmError = MasmError::from_static_st
 pub const ERR_SWAP_WRONG_NUMBER_OF_ASSETS: MasmError = MasmError::from_static_str("SWAP script requires exactly 1 note asset");
 /// Error Message: "SWAP script expects exactly 10 note inputs"
 pub const ERR_SWAP_WRONG_NUMBER_OF_INPUTS: MasmError = MasmError::from_static_str("SWAP script expects exactly 10 note inputs");
+
+/// All errors defined in this file along with the names of their constants.
+pub const NOTE_SCRIPT_ERRORS: &[(&str, MasmError)] = &[
+    ("ERR_MULTISIG_THRESHOLD_NOT_MET", ERR_MULTISIG_THRESHOLD_NOT_MET),
+    ("ERR_P2IDE_RECLAIM_ACCT_IS_NOT_SENDER", ERR_P2IDE_RECLAIM_ACCT_IS_NOT_SENDER),
+    ("ERR_P2IDE_RECLAIM_DISABLED", ERR_P2IDE_RECLAIM_DISABLED),
+    ("ERR_P2IDE_RECLAIM_HEIGHT_NOT_REACHED", ERR_P2IDE_RECLAIM_HEIGHT_NOT_REACHED),
+    ("ERR_P2IDE_TIMELOCK_HEIGHT_NOT_REACHED", ERR_P2IDE_TIMELOCK_HEIGHT_NOT_REACHED),
+    ("ERR_P2IDE_WRONG_NUMBER_OF_INPUTS", ERR_P2IDE_WRONG_NUMBER_OF_INPUTS),
+    ("ERR_P2ID_TARGET_ACCT_MISMATCH", ERR_P2ID_TARGET_ACCT_MISMATCH),
+    ("ERR_P2ID_WRONG_NUMBER_OF_INPUTS", ERR_P2ID_WRONG_NUMBER_OF_INPUTS),
+    ("ERR_SWAP_WRONG_NUMBER_OF_ASSETS", ERR_SWAP_WRONG_NUMBER_OF_ASSETS),
+    ("ERR_SWAP_WRONG_NUMBER_OF_INPUTS", ERR_SWAP_WRONG_NUMBER_OF_INPUTS),
+];
@@ -25,6 +25,18 @@ pub enum TransactionKernelError {
     FailedToPushAdviceStack(Felt),
     #[error("failed to generate signature: {0}")]
     FailedSignatureGeneration(&'static str),
+    #[error("transaction authenticator failed to generate signature")]
+    SignatureGenerationFailed(
+        // This is always an AuthenticationError, but it is defined in miden-tx, so we make it a
+        // trait object instead.
+        #[source] Box<dyn Error + Send + Sync + 'static>,
+    ),
+    #[error("signature generation was deferred and transaction execution was halted")]
+    SignatureGenerationDeferred,
+    #[error(
+        "account data accessed by the transaction is missing from the advice provider and transaction execution was halted"
+    )]
+    AccountDataNotAvailable,
     #[error("asset data extracted from the stack by event handler `{handler}` is not well formed")]
     MalformedAssetInEventHandler {
         handler: &'static str,
@@ -61,6 +73,8 @@ pub enum TransactionKernelError {
     UnknownAccountProcedure(Digest),
     #[error("code commitment {0} is not in the advice provider")]
     UnknownCodeCommitment(Digest),
+    #[error("new account code with commitment {0} was not provided to the transaction")]
+    MissingNewAccountCode(Digest),
     #[error("account storage slots number is missing in memory at address {0}")]
     AccountStorageSlotsNumMissing(u32),
 }
@@ -87,6 +101,17 @@ pub enum TransactionTraceParsingError {
     UnknownTransactionTrace(u32),
 }
 
+// TRANSACTION KERNEL SELECTION ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TransactionKernelSelectionError {
+    #[error(
+        "reference block with transaction kernel commitment {0} does not support any registered transaction kernel"
+    )]
+    UnsupportedTransactionKernel(Digest),
+}
+
 #[cfg(test)]
 mod error_assertions {
     use super::*;
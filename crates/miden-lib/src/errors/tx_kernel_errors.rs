@@ -63,6 +63,8 @@ pub const ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS: MasmError = MasmError::from_static
 
 /// Error Message: "executed transaction neither changed the account state, nor consumed any notes"
 pub const ERR_EPILOGUE_EXECUTED_TRANSACTION_IS_EMPTY: MasmError = MasmError::from_static_str("executed transaction neither changed the account state, nor consumed any notes");
+/// Error Message: "native account does not hold enough of the native asset to pay the transaction fee"
+pub const ERR_EPILOGUE_INSUFFICIENT_NATIVE_ASSET_BALANCE_TO_PAY_FEE: MasmError = MasmError::from_static_str("native account does not hold enough of the native asset to pay the transaction fee");
 /// Error Message: "total number of assets in the account and all involved notes must stay the same"
 pub const ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME: MasmError = MasmError::from_static_str("total number of assets in the account and all involved notes must stay the same");
 
@@ -223,3 +225,109 @@ pub const ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS: MasmError = MasmError::fr
 pub const ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND: MasmError = MasmError::from_static_str("failed to remove non-existent non-fungible asset from the vault");
 /// Error Message: "failed to remove fungible asset from the asset vault due to the initial value being invalid"
 pub const ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID: MasmError = MasmError::from_static_str("failed to remove fungible asset from the asset vault due to the initial value being invalid");
+
+/// All errors defined in this file along with the names of their constants.
+pub const TX_KERNEL_ERRORS: &[(&str, MasmError)] = &[
+    ("ERR_ACCOUNT_CODE_COMMITMENT_MISMATCH", ERR_ACCOUNT_CODE_COMMITMENT_MISMATCH),
+    ("ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE", ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE),
+    ("ERR_ACCOUNT_DELTA_NONCE_MUST_BE_INCREMENTED_WITH_VAULT_OR_STORAGE_CHANGES", ERR_ACCOUNT_DELTA_NONCE_MUST_BE_INCREMENTED_WITH_VAULT_OR_STORAGE_CHANGES),
+    ("ERR_ACCOUNT_ID_NON_PUBLIC_NETWORK_ACCOUNT", ERR_ACCOUNT_ID_NON_PUBLIC_NETWORK_ACCOUNT),
+    ("ERR_ACCOUNT_ID_SUFFIX_LEAST_SIGNIFICANT_BYTE_MUST_BE_ZERO", ERR_ACCOUNT_ID_SUFFIX_LEAST_SIGNIFICANT_BYTE_MUST_BE_ZERO),
+    ("ERR_ACCOUNT_ID_SUFFIX_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO", ERR_ACCOUNT_ID_SUFFIX_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO),
+    ("ERR_ACCOUNT_ID_UNKNOWN_STORAGE_MODE", ERR_ACCOUNT_ID_UNKNOWN_STORAGE_MODE),
+    ("ERR_ACCOUNT_ID_UNKNOWN_VERSION", ERR_ACCOUNT_ID_UNKNOWN_VERSION),
+    ("ERR_ACCOUNT_INVALID_STORAGE_OFFSET_FOR_SIZE", ERR_ACCOUNT_INVALID_STORAGE_OFFSET_FOR_SIZE),
+    ("ERR_ACCOUNT_IS_NOT_NATIVE", ERR_ACCOUNT_IS_NOT_NATIVE),
+    ("ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE", ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE),
+    ("ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32", ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32),
+    ("ERR_ACCOUNT_PROC_INDEX_OUT_OF_BOUNDS", ERR_ACCOUNT_PROC_INDEX_OUT_OF_BOUNDS),
+    ("ERR_ACCOUNT_PROC_NOT_AUTH_PROC", ERR_ACCOUNT_PROC_NOT_AUTH_PROC),
+    ("ERR_ACCOUNT_PROC_NOT_PART_OF_ACCOUNT_CODE", ERR_ACCOUNT_PROC_NOT_PART_OF_ACCOUNT_CODE),
+    ("ERR_ACCOUNT_READING_MAP_VALUE_FROM_NON_MAP_SLOT", ERR_ACCOUNT_READING_MAP_VALUE_FROM_NON_MAP_SLOT),
+    ("ERR_ACCOUNT_SEED_AND_COMMITMENT_DIGEST_MISMATCH", ERR_ACCOUNT_SEED_AND_COMMITMENT_DIGEST_MISMATCH),
+    ("ERR_ACCOUNT_SETTING_MAP_ITEM_ON_NON_MAP_SLOT", ERR_ACCOUNT_SETTING_MAP_ITEM_ON_NON_MAP_SLOT),
+    ("ERR_ACCOUNT_SETTING_VALUE_ITEM_ON_NON_VALUE_SLOT", ERR_ACCOUNT_SETTING_VALUE_ITEM_ON_NON_VALUE_SLOT),
+    ("ERR_ACCOUNT_STACK_OVERFLOW", ERR_ACCOUNT_STACK_OVERFLOW),
+    ("ERR_ACCOUNT_STACK_UNDERFLOW", ERR_ACCOUNT_STACK_UNDERFLOW),
+    ("ERR_ACCOUNT_STORAGE_COMMITMENT_MISMATCH", ERR_ACCOUNT_STORAGE_COMMITMENT_MISMATCH),
+    ("ERR_ACCOUNT_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS", ERR_ACCOUNT_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS),
+    ("ERR_ACCOUNT_TOO_MANY_PROCEDURES", ERR_ACCOUNT_TOO_MANY_PROCEDURES),
+    ("ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS", ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS),
+    ("ERR_EPILOGUE_EXECUTED_TRANSACTION_IS_EMPTY", ERR_EPILOGUE_EXECUTED_TRANSACTION_IS_EMPTY),
+    ("ERR_EPILOGUE_INSUFFICIENT_NATIVE_ASSET_BALANCE_TO_PAY_FEE", ERR_EPILOGUE_INSUFFICIENT_NATIVE_ASSET_BALANCE_TO_PAY_FEE),
+    ("ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME", ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME),
+    ("ERR_FAUCET_BURN_CANNOT_EXCEED_EXISTING_TOTAL_SUPPLY", ERR_FAUCET_BURN_CANNOT_EXCEED_EXISTING_TOTAL_SUPPLY),
+    ("ERR_FAUCET_BURN_NON_FUNGIBLE_ASSET_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET", ERR_FAUCET_BURN_NON_FUNGIBLE_ASSET_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET),
+    ("ERR_FAUCET_INVALID_STORAGE_OFFSET", ERR_FAUCET_INVALID_STORAGE_OFFSET),
+    ("ERR_FAUCET_IS_NF_ASSET_ISSUED_PROC_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET", ERR_FAUCET_IS_NF_ASSET_ISSUED_PROC_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET),
+    ("ERR_FAUCET_NEW_TOTAL_SUPPLY_WOULD_EXCEED_MAX_ASSET_AMOUNT", ERR_FAUCET_NEW_TOTAL_SUPPLY_WOULD_EXCEED_MAX_ASSET_AMOUNT),
+    ("ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED", ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED),
+    ("ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND", ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND),
+    ("ERR_FAUCET_STORAGE_DATA_SLOT_IS_RESERVED", ERR_FAUCET_STORAGE_DATA_SLOT_IS_RESERVED),
+    ("ERR_FAUCET_TOTAL_ISSUANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET", ERR_FAUCET_TOTAL_ISSUANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET),
+    ("ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT", ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT),
+    ("ERR_FOREIGN_ACCOUNT_ID_IS_ZERO", ERR_FOREIGN_ACCOUNT_ID_IS_ZERO),
+    ("ERR_FOREIGN_ACCOUNT_INVALID_COMMITMENT", ERR_FOREIGN_ACCOUNT_INVALID_COMMITMENT),
+    ("ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED", ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED),
+    ("ERR_FUNGIBLE_ASSET_AMOUNT_EXCEEDS_MAX_ALLOWED_AMOUNT", ERR_FUNGIBLE_ASSET_AMOUNT_EXCEEDS_MAX_ALLOWED_AMOUNT),
+    ("ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED", ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED),
+    ("ERR_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN", ERR_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN),
+    ("ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ONE_MUST_BE_ZERO", ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ONE_MUST_BE_ZERO),
+    ("ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_TWO_AND_THREE_MUST_BE_FUNGIBLE_FAUCET_ID", ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_TWO_AND_THREE_MUST_BE_FUNGIBLE_FAUCET_ID),
+    ("ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ZERO_MUST_BE_WITHIN_LIMITS", ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ZERO_MUST_BE_WITHIN_LIMITS),
+    ("ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID", ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID),
+    ("ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS", ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS),
+    ("ERR_LINK_MAP_CANNOT_BE_EMPTY_ON_ABSENCE_AFTER_ENTRY", ERR_LINK_MAP_CANNOT_BE_EMPTY_ON_ABSENCE_AFTER_ENTRY),
+    ("ERR_LINK_MAP_ENTRY_PTR_IS_NOT_ENTRY_ALIGNED", ERR_LINK_MAP_ENTRY_PTR_IS_NOT_ENTRY_ALIGNED),
+    ("ERR_LINK_MAP_ENTRY_PTR_IS_OUTSIDE_VALID_MEMORY_REGION", ERR_LINK_MAP_ENTRY_PTR_IS_OUTSIDE_VALID_MEMORY_REGION),
+    ("ERR_LINK_MAP_MAP_PTR_IN_ENTRY_DOES_NOT_MATCH_EXPECTED_MAP_PTR", ERR_LINK_MAP_MAP_PTR_IN_ENTRY_DOES_NOT_MATCH_EXPECTED_MAP_PTR),
+    ("ERR_LINK_MAP_MAX_ENTRIES_EXCEEDED", ERR_LINK_MAP_MAX_ENTRIES_EXCEEDED),
+    ("ERR_LINK_MAP_PROVIDED_KEY_NOT_EQUAL_TO_ENTRY_KEY", ERR_LINK_MAP_PROVIDED_KEY_NOT_EQUAL_TO_ENTRY_KEY),
+    ("ERR_LINK_MAP_PROVIDED_KEY_NOT_GREATER_THAN_ENTRY_KEY", ERR_LINK_MAP_PROVIDED_KEY_NOT_GREATER_THAN_ENTRY_KEY),
+    ("ERR_LINK_MAP_PROVIDED_KEY_NOT_LESS_THAN_ENTRY_KEY", ERR_LINK_MAP_PROVIDED_KEY_NOT_LESS_THAN_ENTRY_KEY),
+    ("ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS", ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS),
+    ("ERR_NON_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN", ERR_NON_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN),
+    ("ERR_NON_FUNGIBLE_ASSET_FORMAT_ELEMENT_THREE_MUST_BE_FUNGIBLE_FAUCET_ID", ERR_NON_FUNGIBLE_ASSET_FORMAT_ELEMENT_THREE_MUST_BE_FUNGIBLE_FAUCET_ID),
+    ("ERR_NON_FUNGIBLE_ASSET_FORMAT_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO", ERR_NON_FUNGIBLE_ASSET_FORMAT_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO),
+    ("ERR_NON_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID", ERR_NON_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID),
+    ("ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_ASSETS_FROM_INCORRECT_CONTEXT", ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_ASSETS_FROM_INCORRECT_CONTEXT),
+    ("ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_INPUTS_FROM_INCORRECT_CONTEXT", ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_INPUTS_FROM_INCORRECT_CONTEXT),
+    ("ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SENDER_FROM_INCORRECT_CONTEXT", ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SENDER_FROM_INCORRECT_CONTEXT),
+    ("ERR_NOTE_DATA_DOES_NOT_MATCH_COMMITMENT", ERR_NOTE_DATA_DOES_NOT_MATCH_COMMITMENT),
+    ("ERR_NOTE_FUNGIBLE_MAX_AMOUNT_EXCEEDED", ERR_NOTE_FUNGIBLE_MAX_AMOUNT_EXCEEDED),
+    ("ERR_NOTE_INVALID_INDEX", ERR_NOTE_INVALID_INDEX),
+    ("ERR_NOTE_INVALID_NOTE_TYPE_FOR_NOTE_TAG_PREFIX", ERR_NOTE_INVALID_NOTE_TYPE_FOR_NOTE_TAG_PREFIX),
+    ("ERR_NOTE_INVALID_NUMBER_OF_NOTE_INPUTS", ERR_NOTE_INVALID_NUMBER_OF_NOTE_INPUTS),
+    ("ERR_NOTE_INVALID_TYPE", ERR_NOTE_INVALID_TYPE),
+    ("ERR_NOTE_NETWORK_EXECUTION_DOES_NOT_TARGET_NETWORK_ACCOUNT", ERR_NOTE_NETWORK_EXECUTION_DOES_NOT_TARGET_NETWORK_ACCOUNT),
+    ("ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT", ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT),
+    ("ERR_NOTE_TAG_MUST_BE_U32", ERR_NOTE_TAG_MUST_BE_U32),
+    ("ERR_PROLOGUE_EXISTING_ACCOUNT_MUST_HAVE_NON_ZERO_NONCE", ERR_PROLOGUE_EXISTING_ACCOUNT_MUST_HAVE_NON_ZERO_NONCE),
+    ("ERR_PROLOGUE_GLOBAL_INPUTS_PROVIDED_DO_NOT_MATCH_BLOCK_COMMITMENT", ERR_PROLOGUE_GLOBAL_INPUTS_PROVIDED_DO_NOT_MATCH_BLOCK_COMMITMENT),
+    ("ERR_PROLOGUE_GLOBAL_INPUTS_PROVIDED_DO_NOT_MATCH_BLOCK_NUMBER_COMMITMENT", ERR_PROLOGUE_GLOBAL_INPUTS_PROVIDED_DO_NOT_MATCH_BLOCK_NUMBER_COMMITMENT),
+    ("ERR_PROLOGUE_INPUT_NOTES_COMMITMENT_MISMATCH", ERR_PROLOGUE_INPUT_NOTES_COMMITMENT_MISMATCH),
+    ("ERR_PROLOGUE_MISMATCH_OF_ACCOUNT_IDS_FROM_GLOBAL_INPUTS_AND_ADVICE_PROVIDER", ERR_PROLOGUE_MISMATCH_OF_ACCOUNT_IDS_FROM_GLOBAL_INPUTS_AND_ADVICE_PROVIDER),
+    ("ERR_PROLOGUE_MISMATCH_OF_REFERENCE_BLOCK_MMR_AND_NOTE_AUTHENTICATION_MMR", ERR_PROLOGUE_MISMATCH_OF_REFERENCE_BLOCK_MMR_AND_NOTE_AUTHENTICATION_MMR),
+    ("ERR_PROLOGUE_NEW_ACCOUNT_NONCE_MUST_BE_ZERO", ERR_PROLOGUE_NEW_ACCOUNT_NONCE_MUST_BE_ZERO),
+    ("ERR_PROLOGUE_NEW_ACCOUNT_VAULT_MUST_BE_EMPTY", ERR_PROLOGUE_NEW_ACCOUNT_VAULT_MUST_BE_EMPTY),
+    ("ERR_PROLOGUE_NEW_FUNGIBLE_FAUCET_RESERVED_SLOT_INVALID_TYPE", ERR_PROLOGUE_NEW_FUNGIBLE_FAUCET_RESERVED_SLOT_INVALID_TYPE),
+    ("ERR_PROLOGUE_NEW_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_EMPTY", ERR_PROLOGUE_NEW_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_EMPTY),
+    ("ERR_PROLOGUE_NEW_NON_FUNGIBLE_FAUCET_RESERVED_SLOT_INVALID_TYPE", ERR_PROLOGUE_NEW_NON_FUNGIBLE_FAUCET_RESERVED_SLOT_INVALID_TYPE),
+    ("ERR_PROLOGUE_NEW_NON_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_VALID_EMPTY_SMT", ERR_PROLOGUE_NEW_NON_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_VALID_EMPTY_SMT),
+    ("ERR_PROLOGUE_NUMBER_OF_INPUT_NOTES_EXCEEDS_LIMIT", ERR_PROLOGUE_NUMBER_OF_INPUT_NOTES_EXCEEDS_LIMIT),
+    ("ERR_PROLOGUE_NUMBER_OF_NOTE_ASSETS_EXCEEDS_LIMIT", ERR_PROLOGUE_NUMBER_OF_NOTE_ASSETS_EXCEEDS_LIMIT),
+    ("ERR_PROLOGUE_NUMBER_OF_NOTE_INPUTS_EXCEEDED_LIMIT", ERR_PROLOGUE_NUMBER_OF_NOTE_INPUTS_EXCEEDED_LIMIT),
+    ("ERR_PROLOGUE_PROVIDED_ACCOUNT_DATA_DOES_NOT_MATCH_ON_CHAIN_COMMITMENT", ERR_PROLOGUE_PROVIDED_ACCOUNT_DATA_DOES_NOT_MATCH_ON_CHAIN_COMMITMENT),
+    ("ERR_PROLOGUE_PROVIDED_INPUT_ASSETS_INFO_DOES_NOT_MATCH_ITS_COMMITMENT", ERR_PROLOGUE_PROVIDED_INPUT_ASSETS_INFO_DOES_NOT_MATCH_ITS_COMMITMENT),
+    ("ERR_TX_INVALID_EXPIRATION_DELTA", ERR_TX_INVALID_EXPIRATION_DELTA),
+    ("ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT", ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT),
+    ("ERR_TX_TRANSACTION_SCRIPT_IS_MISSING", ERR_TX_TRANSACTION_SCRIPT_IS_MISSING),
+    ("ERR_VAULT_ADD_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID", ERR_VAULT_ADD_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID),
+    ("ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW", ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW),
+    ("ERR_VAULT_FUNGIBLE_MAX_AMOUNT_EXCEEDED", ERR_VAULT_FUNGIBLE_MAX_AMOUNT_EXCEEDED),
+    ("ERR_VAULT_GET_BALANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET", ERR_VAULT_GET_BALANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET),
+    ("ERR_VAULT_HAS_NON_FUNGIBLE_ASSET_PROC_CAN_BE_CALLED_ONLY_WITH_NON_FUNGIBLE_ASSET", ERR_VAULT_HAS_NON_FUNGIBLE_ASSET_PROC_CAN_BE_CALLED_ONLY_WITH_NON_FUNGIBLE_ASSET),
+    ("ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS", ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS),
+    ("ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND", ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND),
+    ("ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID", ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID),
+];
@@ -51,6 +51,24 @@ const EPILOGUE_END: u32 = 0x2_0019; // 131097
 const LINK_MAP_SET_EVENT: u32 = 0x2_001a; // 131098
 const LINK_MAP_GET_EVENT: u32 = 0x2_001b; // 131099
 
+const ACCOUNT_STORAGE_BEFORE_GET_MAP_ITEM: u32 = 0x2_001c; // 131100
+
+const ACCOUNT_VAULT_BEFORE_GET_BALANCE: u32 = 0x2_001d; // 131101
+const ACCOUNT_VAULT_BEFORE_HAS_NON_FUNGIBLE_ASSET: u32 = 0x2_001e; // 131102
+
+const ASSET_VAULT_BEFORE_UPDATE: u32 = 0x2_001f; // 131103
+
+const FALCON_SIG_TO_STACK_IF_AVAILABLE: u32 = 0x2_0020; // 131104
+
+const ACCOUNT_BEFORE_FOREIGN_LOAD: u32 = 0x2_0021; // 131105
+
+const ACCOUNT_STORAGE_BEFORE_GET_ITEM: u32 = 0x2_0022; // 131106
+
+const FOREIGN_CONTEXT_START: u32 = 0x2_0023; // 131107
+const FOREIGN_CONTEXT_END: u32 = 0x2_0024; // 131108
+
+const ACCOUNT_AFTER_SET_CODE: u32 = 0x2_0025; // 131109
+
 /// Events which may be emitted by a transaction kernel.
 ///
 /// The events are emitted via the `emit.<event_id>` instruction. The event ID is a 32-bit
@@ -103,6 +121,24 @@ pub enum TransactionEvent {
 
     LinkMapSetEvent = LINK_MAP_SET_EVENT,
     LinkMapGetEvent = LINK_MAP_GET_EVENT,
+
+    AccountStorageBeforeGetMapItem = ACCOUNT_STORAGE_BEFORE_GET_MAP_ITEM,
+
+    AccountVaultBeforeGetBalance = ACCOUNT_VAULT_BEFORE_GET_BALANCE,
+    AccountVaultBeforeHasNonFungibleAsset = ACCOUNT_VAULT_BEFORE_HAS_NON_FUNGIBLE_ASSET,
+
+    AssetVaultBeforeUpdate = ASSET_VAULT_BEFORE_UPDATE,
+
+    FalconSigToStackIfAvailable = FALCON_SIG_TO_STACK_IF_AVAILABLE,
+
+    AccountBeforeForeignLoad = ACCOUNT_BEFORE_FOREIGN_LOAD,
+
+    AccountStorageBeforeGetItem = ACCOUNT_STORAGE_BEFORE_GET_ITEM,
+
+    ForeignContextStart = FOREIGN_CONTEXT_START,
+    ForeignContextEnd = FOREIGN_CONTEXT_END,
+
+    AccountAfterSetCode = ACCOUNT_AFTER_SET_CODE,
 }
 
 impl TransactionEvent {
@@ -174,6 +210,28 @@ impl TryFrom<u32> for TransactionEvent {
             LINK_MAP_SET_EVENT => Ok(TransactionEvent::LinkMapSetEvent),
             LINK_MAP_GET_EVENT => Ok(TransactionEvent::LinkMapGetEvent),
 
+            ACCOUNT_STORAGE_BEFORE_GET_MAP_ITEM => {
+                Ok(TransactionEvent::AccountStorageBeforeGetMapItem)
+            },
+
+            ACCOUNT_VAULT_BEFORE_GET_BALANCE => Ok(TransactionEvent::AccountVaultBeforeGetBalance),
+            ACCOUNT_VAULT_BEFORE_HAS_NON_FUNGIBLE_ASSET => {
+                Ok(TransactionEvent::AccountVaultBeforeHasNonFungibleAsset)
+            },
+
+            ASSET_VAULT_BEFORE_UPDATE => Ok(TransactionEvent::AssetVaultBeforeUpdate),
+
+            FALCON_SIG_TO_STACK_IF_AVAILABLE => Ok(TransactionEvent::FalconSigToStackIfAvailable),
+
+            ACCOUNT_BEFORE_FOREIGN_LOAD => Ok(TransactionEvent::AccountBeforeForeignLoad),
+
+            ACCOUNT_STORAGE_BEFORE_GET_ITEM => Ok(TransactionEvent::AccountStorageBeforeGetItem),
+
+            FOREIGN_CONTEXT_START => Ok(TransactionEvent::ForeignContextStart),
+            FOREIGN_CONTEXT_END => Ok(TransactionEvent::ForeignContextEnd),
+
+            ACCOUNT_AFTER_SET_CODE => Ok(TransactionEvent::AccountAfterSetCode),
+
             _ => Err(TransactionEventError::InvalidTransactionEvent(value)),
         }
     }
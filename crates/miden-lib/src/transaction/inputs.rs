@@ -1,15 +1,18 @@
 use alloc::vec::Vec;
 
 use miden_objects::{
-    Digest, EMPTY_WORD, Felt, FieldElement, WORD_SIZE, Word, ZERO,
-    account::{AccountHeader, AccountId, PartialAccount},
+    Digest, EMPTY_WORD, Felt, FieldElement, Word, ZERO,
+    account::{AccountCode, AccountHeader, AccountId, PartialAccount},
     block::AccountWitness,
-    crypto::merkle::InnerNodeInfo,
-    transaction::{InputNote, PartialBlockchain, TransactionArgs, TransactionInputs},
+    crypto::{hash::rpo::Rpo256, merkle::InnerNodeInfo},
+    transaction::{
+        AccountInputs, InputNote, PartialBlockchain, TransactionArgs, TransactionInputs,
+    },
     vm::AdviceInputs,
 };
 
-use super::TransactionKernel;
+use super::{TransactionKernelRegistry, kernel_registry::kernels_commitment};
+use crate::errors::TransactionKernelSelectionError;
 
 // TRANSACTION ADVICE INPUTS
 // ================================================================================================
@@ -27,19 +30,54 @@ impl TransactionAdviceInputs {
     /// optional account seed (required for new accounts), and the input note data, including
     /// core note data + authentication paths all the way to the root of one of partial
     /// blockchain peaks.
-    pub fn new(tx_inputs: &TransactionInputs, tx_args: &TransactionArgs) -> Self {
+    ///
+    /// The inputs are created for the built-in kernel matching the transaction kernel commitment of
+    /// the reference block.
+    ///
+    /// # Errors
+    /// Returns an error if the reference block does not support any of the built-in kernels.
+    pub fn new(
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+    ) -> Result<Self, TransactionKernelSelectionError> {
+        let kernel_registry = TransactionKernelRegistry::default();
+        let tx_kernel_commitment = tx_inputs.block_header().tx_kernel_commitment();
+        let kernel_version = kernel_registry
+            .select(tx_kernel_commitment)
+            .ok_or(TransactionKernelSelectionError::UnsupportedTransactionKernel(
+                tx_kernel_commitment,
+            ))?
+            .version();
+
+        Ok(Self::with_kernel(tx_inputs, tx_args, &kernel_registry, kernel_version))
+    }
+
+    /// Creates a [`TransactionAdviceInputs`] for executing the kernel with the specified version
+    /// from the provided registry.
+    ///
+    /// # Panics
+    /// Panics if the kernel with the specified version is not registered.
+    pub(super) fn with_kernel(
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        kernel_registry: &TransactionKernelRegistry,
+        kernel_version: u8,
+    ) -> Self {
         let mut inputs = TransactionAdviceInputs::default();
-        let kernel_version = 0; // TODO: replace with user input
 
         inputs.build_stack(tx_inputs, tx_args, kernel_version);
-        inputs.add_kernel_commitments(kernel_version);
+        inputs.add_kernel_commitments(
+            kernel_registry,
+            kernel_version,
+            tx_inputs.block_header().tx_kernel_commitment(),
+        );
         inputs.add_partial_blockchain(tx_inputs.blockchain());
         inputs.add_input_notes(tx_inputs, tx_args);
 
         // --- native account injection ---------------------------------------
 
-        let native_acc = PartialAccount::from(tx_inputs.account());
-        inputs.add_account(&native_acc);
+        let native_acc = tx_inputs.account();
+        inputs.add_account(native_acc);
 
         // if a seed was provided, extend the map appropriately
         if let Some(seed) = tx_inputs.account_seed() {
@@ -48,19 +86,30 @@ impl TransactionAdviceInputs {
             inputs.add_map_entry(account_id_key, seed.to_vec());
         }
 
-        // --- foreign account injection --------------------------------------
+        // --- new account code injection -------------------------------------
 
-        for foreign_acc in tx_args.foreign_account_inputs() {
-            inputs.add_account(foreign_acc.account());
-            inputs.add_account_witness(foreign_acc.witness());
+        // the procedures of the new code are validated when the code is set
+        if let Some(new_code) = tx_args.new_account_code() {
+            // NEW_CODE_COMMITMENT |-> procedures vector
+            inputs.add_map_entry(new_code.commitment(), new_code.as_elements());
+        }
 
-            // for foreign accounts, we need to insert the id to state mapping
-            // NOTE: keep this in sync with the start_foreign_context kernel procedure
-            let account_id_key = build_account_id_key(foreign_acc.id());
-            let header = AccountHeader::from(foreign_acc.account());
+        // hash(CODE_COMMITMENT, EMPTY_WORD) |-> NEW_CODE_COMMITMENT, which is read by the
+        // authentication procedures replacing the account code
+        let code_commitment = native_acc.code().commitment();
+        let new_code_commitment = tx_args
+            .new_account_code()
+            .map(AccountCode::commitment)
+            .unwrap_or(code_commitment);
+        inputs.add_map_entry(
+            build_new_account_code_key(code_commitment),
+            new_code_commitment.as_elements().to_vec(),
+        );
 
-            // ACCOUNT_ID |-> [ID_AND_NONCE, VAULT_ROOT, STORAGE_COMMITMENT, CODE_COMMITMENT]
-            inputs.add_map_entry(account_id_key, header.as_elements());
+        // --- foreign account injection --------------------------------------
+
+        for foreign_acc in tx_args.foreign_account_inputs() {
+            inputs.add_foreign_account(foreign_acc);
         }
 
         // any extra user-supplied advice
@@ -82,6 +131,25 @@ impl TransactionAdviceInputs {
         self.0.extend(adv_inputs);
     }
 
+    /// Adds the data required to access the provided foreign account during transaction execution
+    /// to these advice inputs.
+    ///
+    /// This includes the code, storage and vault data of the account, the witness of the account
+    /// against the account root of the reference block, and the account header keyed by the
+    /// account ID.
+    pub fn add_foreign_account(&mut self, foreign_acc: &AccountInputs) {
+        self.add_account(foreign_acc.account());
+        self.add_account_witness(foreign_acc.witness());
+
+        // for foreign accounts, we need to insert the id to state mapping
+        // NOTE: keep this in sync with the start_foreign_context kernel procedure
+        let account_id_key = build_account_id_key(foreign_acc.id());
+        let header = AccountHeader::from(foreign_acc.account());
+
+        // ACCOUNT_ID |-> [ID_AND_NONCE, VAULT_ROOT, STORAGE_COMMITMENT, CODE_COMMITMENT]
+        self.add_map_entry(account_id_key, header.as_elements());
+    }
+
     /// Extend the advice stack with the transaction inputs.
     ///
     /// The following data is pushed to the advice stack:
@@ -95,6 +163,8 @@ impl TransactionAdviceInputs {
     ///     TX_KERNEL_COMMITMENT
     ///     PROOF_COMMITMENT,
     ///     [block_num, version, timestamp, 0],
+    ///     [native_asset_id_suffix, native_asset_id_prefix, verification_base_fee, 0],
+    ///     EMPTY_WORD,
     ///     NOTE_ROOT,
     ///     kernel_version
     ///     [account_id, 0, 0, account_nonce],
@@ -127,6 +197,8 @@ impl TransactionAdviceInputs {
             header.timestamp().into(),
             ZERO,
         ]);
+        self.extend_stack(header.fee_parameters().to_word());
+        self.extend_stack(EMPTY_WORD);
         self.extend_stack(header.note_root());
 
         // --- kernel version (keep in sync with process_kernel_data) ---------
@@ -184,23 +256,36 @@ impl TransactionAdviceInputs {
     /// Inserts kernel commitments and hashes of their procedures into the advice inputs.
     ///
     /// Inserts the following entries into the advice map:
-    /// - The accumulative hash of all kernels |-> array of each kernel commitment.
+    /// - The accumulative hash of all kernels supported by the reference block |-> array of each
+    ///   kernel commitment.
     /// - The hash of the selected kernel |-> array of the kernel's procedure roots.
-    fn add_kernel_commitments(&mut self, kernel_version: u8) {
-        const NUM_KERNELS: usize = TransactionKernel::NUM_VERSIONS;
-
+    ///
+    /// If the reference block does not commit to a prefix of the registered kernels, all
+    /// registered kernels are inserted, in which case the kernel will fail to find the kernels of
+    /// the reference block.
+    fn add_kernel_commitments(
+        &mut self,
+        kernel_registry: &TransactionKernelRegistry,
+        kernel_version: u8,
+        tx_kernel_commitment: Digest,
+    ) {
         // insert kernels root with kernel commitments into the advice map
-        let mut kernel_commitments: Vec<Felt> = Vec::with_capacity(NUM_KERNELS * WORD_SIZE);
-        for version in 0..NUM_KERNELS {
-            let kernel_commitment = TransactionKernel::commitment(version as u8);
-            kernel_commitments.extend_from_slice(kernel_commitment.as_elements());
-        }
-        self.add_map_entry(TransactionKernel::kernel_commitment(), kernel_commitments);
+        let kernels = kernel_registry
+            .supported_kernels(tx_kernel_commitment)
+            .unwrap_or(kernel_registry.kernels());
+        let kernel_commitments: Vec<Felt> = kernels
+            .iter()
+            .flat_map(|kernel| kernel.commitment().as_elements().to_vec())
+            .collect();
+        self.add_map_entry(kernels_commitment(kernels), kernel_commitments);
 
         // insert the selected kernel commitment with its procedure roots into the advice map
+        let kernel = kernel_registry
+            .get(kernel_version)
+            .expect("provided kernel version is not registered");
         self.add_map_entry(
-            TransactionKernel::commitment(kernel_version),
-            TransactionKernel::procedures_as_elements(kernel_version),
+            kernel.commitment(),
+            Digest::digests_as_elements(kernel.procedures()).to_vec(),
         );
     }
 
@@ -381,6 +466,12 @@ impl From<AdviceInputs> for TransactionAdviceInputs {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns the key under which the commitment of the new code of the account with the provided code
+/// commitment is stored in the advice map.
+fn build_new_account_code_key(code_commitment: Digest) -> Digest {
+    Rpo256::merge(&[code_commitment, Digest::default()])
+}
+
 fn build_account_id_key(id: AccountId) -> Digest {
     Digest::from([id.suffix(), id.prefix().as_felt(), ZERO, ZERO])
 }
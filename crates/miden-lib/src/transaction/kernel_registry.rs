@@ -0,0 +1,315 @@
+use alloc::{string::String, vec::Vec};
+
+use miden_objects::{
+    Digest, Felt, Hasher,
+    assembly::{KernelLibrary, Library},
+    transaction::{TransactionArgs, TransactionInputs},
+    utils::sync::LazyLock,
+    vm::{AdviceInputs, Program, ProgramInfo, StackInputs},
+};
+
+use super::{TransactionAdviceInputs, TransactionKernel};
+
+// Initialize the registry of the kernels built into this library only once
+static BUILT_IN_KERNELS: LazyLock<TransactionKernelRegistry> = LazyLock::new(|| {
+    TransactionKernelRegistry { kernels: Vec::new() }.with_kernel(
+        TransactionKernel::kernel(),
+        TransactionKernel::main(),
+        TransactionKernel::tx_script_main(),
+        TransactionKernel::PROCEDURES[0].to_vec(),
+    )
+});
+
+// VERSIONED KERNEL
+// ================================================================================================
+
+/// A version of the transaction kernel registered in a [TransactionKernelRegistry].
+///
+/// Contains everything required to execute, prove and verify transactions against this version
+/// of the kernel, i.e., the kernel library, the executable kernel program, the program used to
+/// execute transaction scripts outside of a transaction and the roots of the kernel procedures in
+/// the order of their offsets.
+#[derive(Debug, Clone)]
+pub struct VersionedKernel {
+    version: u8,
+    library: KernelLibrary,
+    main: Program,
+    tx_script_main: Program,
+    procedures: Vec<Digest>,
+    procedure_names: Vec<Option<String>>,
+    commitment: Digest,
+}
+
+impl VersionedKernel {
+    /// Returns the version of this kernel, i.e., its index in the [TransactionKernelRegistry].
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the library with the system procedures of this kernel.
+    pub fn library(&self) -> &KernelLibrary {
+        &self.library
+    }
+
+    /// Returns the executable program of this kernel.
+    pub fn main(&self) -> &Program {
+        &self.main
+    }
+
+    /// Returns the program used to execute transaction scripts against this kernel outside of a
+    /// transaction, e.g., for view calls.
+    pub fn tx_script_main(&self) -> &Program {
+        &self.tx_script_main
+    }
+
+    /// Returns [ProgramInfo] for the executable program of this kernel.
+    pub fn program_info(&self) -> ProgramInfo {
+        ProgramInfo::new(self.main.hash(), self.library.kernel().clone())
+    }
+
+    /// Returns the roots of the procedures of this kernel, ordered by their offsets.
+    pub fn procedures(&self) -> &[Digest] {
+        &self.procedures
+    }
+
+    /// Returns the name of the procedure with the specified offset, or `None` if the offset is
+    /// out of bounds or the procedure is not exported by the kernel library.
+    pub fn procedure_name(&self, offset: usize) -> Option<&str> {
+        self.procedure_names.get(offset)?.as_deref()
+    }
+
+    /// Returns the sequential hash of all procedure roots of this kernel.
+    pub fn commitment(&self) -> Digest {
+        self.commitment
+    }
+}
+
+// TRANSACTION KERNEL REGISTRY
+// ================================================================================================
+
+/// A registry of transaction kernels keyed by their version.
+///
+/// The versions of the registered kernels are contiguous, starting at version `0`. The transaction
+/// kernel commitment of a block header (see
+/// [`BlockHeader::tx_kernel_commitment()`](miden_objects::block::BlockHeader::tx_kernel_commitment))
+/// is the sequential hash of the commitments of all kernel versions supported by the block, which
+/// are always the versions from `0` up to the latest version at the time the block was created.
+/// Thus, during an upgrade of the kernel, blocks created before the upgrade commit to a prefix of
+/// the kernels supported by blocks created afterwards, and the registry can select the kernel
+/// matching the reference block of a transaction.
+#[derive(Debug, Clone)]
+pub struct TransactionKernelRegistry {
+    kernels: Vec<VersionedKernel>,
+}
+
+impl TransactionKernelRegistry {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionKernelRegistry] containing all kernel versions built into this
+    /// library.
+    pub fn new() -> Self {
+        BUILT_IN_KERNELS.clone()
+    }
+
+    /// Registers the provided kernel as the next version.
+    ///
+    /// The procedure roots must be ordered by the offsets of the procedures in the kernel.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - The registry already contains the maximum number of kernel versions.
+    /// - The provided programs were not compiled against the provided kernel library.
+    pub fn with_kernel(
+        mut self,
+        library: KernelLibrary,
+        main: Program,
+        tx_script_main: Program,
+        procedures: Vec<Digest>,
+    ) -> Self {
+        let version =
+            u8::try_from(self.kernels.len()).expect("maximum number of kernel versions exceeded");
+        assert_eq!(
+            main.kernel(),
+            library.kernel(),
+            "kernel program must be compiled against the kernel library"
+        );
+        assert_eq!(
+            tx_script_main.kernel(),
+            library.kernel(),
+            "transaction script program must be compiled against the kernel library"
+        );
+
+        let commitment = Hasher::hash_elements(Digest::digests_as_elements(&procedures));
+        let procedure_names = procedure_names(&library, &procedures);
+        self.kernels.push(VersionedKernel {
+            version,
+            library,
+            main,
+            tx_script_main,
+            procedures,
+            procedure_names,
+            commitment,
+        });
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of registered kernel versions.
+    pub fn num_versions(&self) -> usize {
+        self.kernels.len()
+    }
+
+    /// Returns the kernel with the specified version, or `None` if it is not registered.
+    pub fn get(&self, version: u8) -> Option<&VersionedKernel> {
+        self.kernels.get(version as usize)
+    }
+
+    /// Returns the kernel with the highest registered version.
+    pub fn latest(&self) -> &VersionedKernel {
+        self.kernels.last().expect("registry contains at least the built-in kernels")
+    }
+
+    /// Returns all registered kernels, ordered by their version.
+    pub fn kernels(&self) -> &[VersionedKernel] {
+        &self.kernels
+    }
+
+    /// Returns the transaction kernel commitment of a block supporting all registered kernels.
+    pub fn commitment(&self) -> Digest {
+        kernels_commitment(&self.kernels)
+    }
+
+    /// Returns the kernels supported by a block with the provided transaction kernel commitment,
+    /// or `None` if the commitment does not match any prefix of the registered kernels.
+    pub fn supported_kernels(&self, tx_kernel_commitment: Digest) -> Option<&[VersionedKernel]> {
+        (1..=self.kernels.len())
+            .map(|num_kernels| &self.kernels[..num_kernels])
+            .find(|kernels| kernels_commitment(kernels) == tx_kernel_commitment)
+    }
+
+    /// Returns the latest kernel supported by a block with the provided transaction kernel
+    /// commitment, or `None` if the commitment does not match any prefix of the registered
+    /// kernels.
+    pub fn select(&self, tx_kernel_commitment: Digest) -> Option<&VersionedKernel> {
+        self.supported_kernels(tx_kernel_commitment).and_then(|kernels| kernels.last())
+    }
+
+    // INPUTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Transforms the provided [TransactionInputs] and [TransactionArgs] into stack and advice
+    /// inputs needed to execute the kernel with the specified version for a specific transaction.
+    ///
+    /// If `init_advice_inputs` is provided, they will be included in the returned advice inputs.
+    ///
+    /// # Panics
+    /// Panics if the kernel with the specified version is not registered.
+    pub fn prepare_inputs(
+        &self,
+        kernel_version: u8,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        init_advice_inputs: Option<AdviceInputs>,
+    ) -> (StackInputs, TransactionAdviceInputs) {
+        let account = tx_inputs.account();
+
+        let stack_inputs = TransactionKernel::build_input_stack(
+            account.id(),
+            account.init_commitment(),
+            tx_inputs.input_notes().commitment(),
+            tx_inputs.block_header().commitment(),
+            tx_inputs.block_header().block_num(),
+        );
+
+        let mut tx_advice_inputs =
+            TransactionAdviceInputs::with_kernel(tx_inputs, tx_args, self, kernel_version);
+        if let Some(init_advice_inputs) = init_advice_inputs {
+            tx_advice_inputs.extend(init_advice_inputs);
+        }
+
+        (stack_inputs, tx_advice_inputs)
+    }
+}
+
+impl Default for TransactionKernelRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the names of the provided procedures, as exported by the provided kernel library.
+fn procedure_names(library: &KernelLibrary, procedures: &[Digest]) -> Vec<Option<String>> {
+    let library: &Library = library.as_ref();
+    procedures
+        .iter()
+        .map(|root| {
+            library.module_infos().find_map(|module| {
+                module
+                    .procedures()
+                    .find(|(_, procedure)| procedure.digest == *root)
+                    .map(|(_, procedure)| String::from(procedure.name.as_str()))
+            })
+        })
+        .collect()
+}
+
+/// Computes the sequential hash of the commitments of the provided kernels.
+pub(super) fn kernels_commitment(kernels: &[VersionedKernel]) -> Digest {
+    let elements: Vec<Felt> = kernels
+        .iter()
+        .flat_map(|kernel| kernel.commitment.as_elements())
+        .copied()
+        .collect();
+    Hasher::hash_elements(&elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_kernel_by_tx_kernel_commitment() {
+        let registry = TransactionKernelRegistry::default();
+        assert_eq!(registry.num_versions(), TransactionKernel::NUM_VERSIONS);
+        assert_eq!(registry.commitment(), TransactionKernel::kernel_commitment());
+        assert_eq!(registry.latest().commitment(), TransactionKernel::commitment(0));
+
+        // register the built-in kernel a second time to simulate an upgrade of the kernel
+        let pre_upgrade_commitment = registry.commitment();
+        let latest = registry.latest().clone();
+        let registry = registry.with_kernel(
+            latest.library().clone(),
+            latest.main().clone(),
+            latest.tx_script_main().clone(),
+            latest.procedures().to_vec(),
+        );
+        let post_upgrade_commitment = registry.commitment();
+        assert_ne!(pre_upgrade_commitment, post_upgrade_commitment);
+
+        let selected = registry.select(pre_upgrade_commitment).unwrap();
+        assert_eq!(selected.version(), 0);
+        assert_eq!(registry.supported_kernels(pre_upgrade_commitment).unwrap().len(), 1);
+
+        let selected = registry.select(post_upgrade_commitment).unwrap();
+        assert_eq!(selected.version(), 1);
+        assert_eq!(registry.supported_kernels(post_upgrade_commitment).unwrap().len(), 2);
+
+        assert!(registry.select(Digest::default()).is_none());
+    }
+
+    #[test]
+    fn kernel_procedure_names() {
+        let kernel = TransactionKernelRegistry::default().latest().clone();
+        assert_eq!(kernel.procedure_name(0), Some("account_get_initial_commitment"));
+        assert!(
+            (0..kernel.procedures().len()).all(|offset| kernel.procedure_name(offset).is_some())
+        );
+        assert_eq!(kernel.procedure_name(kernel.procedures().len()), None);
+    }
+}
@@ -17,8 +17,8 @@ pub type StorageSlot = u8;
 // | Section            | Start address, pointer (word pointer) | End address, pointer (word pointer) | Comment                                     |
 // | ------------------ | :-----------------------------------: | :---------------------------------: | ------------------------------------------- |
 // | Bookkeeping        | 0 (0)                                 | 287 (71)                            |                                             |
-// | Global inputs      | 400 (100)                             | 427 (106)                           |                                             |
-// | Block header       | 800 (200)                             | 835 (208)                           |                                             |
+// | Global inputs      | 400 (100)                             | 431 (107)                           |                                             |
+// | Block header       | 800 (200)                             | 843 (210)                           |                                             |
 // | Partial blockchain | 1_200 (300)                           | 1_331? (332?)                       |                                             |
 // | Kernel data        | 1_600 (400)                           | 1_739 (434)                         | 34 procedures in total, 4 elements each     |
 // | Accounts data      | 8_192 (2048)                          | 532_479 (133_119)                   | 64 accounts max, 8192 elements each         |
@@ -109,6 +109,10 @@ pub const TX_EXPIRATION_BLOCK_NUM_PTR: MemoryAddress = 24;
 /// ```
 pub const ACCOUNT_STACK_TOP_PTR: MemoryAddress = 28;
 
+/// The memory address at which the offset of the kernel procedure most recently invoked via
+/// `exec_kernel_proc` is stored.
+pub const CURRENT_KERNEL_PROCEDURE_OFFSET_PTR: MemoryAddress = 96;
+
 // GLOBAL INPUTS
 // ------------------------------------------------------------------------------------------------
 
@@ -136,6 +140,9 @@ pub const TX_SCRIPT_ROOT_PTR: MemoryAddress = 420;
 /// The memory address at which the key of the transaction script arguments is stored.
 pub const TX_SCRIPT_ARGS_KEY: MemoryAddress = 424;
 
+/// The memory address at which the initial code commitment of the native account is stored.
+pub const INIT_CODE_COMMITMENT_PTR: MemoryAddress = 428;
+
 // BLOCK DATA
 // ------------------------------------------------------------------------------------------------
 
@@ -175,8 +182,20 @@ pub const PROTOCOL_VERSION_IDX: DataIndex = 1;
 /// The index of the timestamp within the block metadata
 pub const TIMESTAMP_IDX: DataIndex = 2;
 
+/// The memory address at which the fee parameters are stored
+pub const FEE_PARAMETERS_PTR: MemoryAddress = 832;
+
+/// The index of the native asset ID suffix within the fee parameters
+pub const NATIVE_ASSET_ID_SUFFIX_IDX: DataIndex = 0;
+
+/// The index of the native asset ID prefix within the fee parameters
+pub const NATIVE_ASSET_ID_PREFIX_IDX: DataIndex = 1;
+
+/// The index of the verification base fee within the fee parameters
+pub const VERIFICATION_BASE_FEE_IDX: DataIndex = 2;
+
 /// The memory address at which the note root is stored
-pub const NOTE_ROOT_PTR: MemoryAddress = 832;
+pub const NOTE_ROOT_PTR: MemoryAddress = 840;
 
 // CHAIN DATA
 // ------------------------------------------------------------------------------------------------
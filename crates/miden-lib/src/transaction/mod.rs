@@ -1,9 +1,10 @@
 use alloc::{string::ToString, sync::Arc, vec::Vec};
 
 use miden_objects::{
-    Digest, EMPTY_WORD, Felt, Hasher, TransactionOutputError,
+    Digest, EMPTY_WORD, Felt, Hasher, TransactionOutputError, Word,
     account::AccountId,
     assembly::{Assembler, DefaultSourceManager, KernelLibrary, SourceManager},
+    asset::FungibleAsset,
     block::BlockNumber,
     transaction::{
         OutputNote, OutputNotes, TransactionArgs, TransactionInputs, TransactionOutputs,
@@ -24,14 +25,18 @@ pub use events::TransactionEvent;
 mod inputs;
 pub use inputs::TransactionAdviceInputs;
 
+mod kernel_registry;
+pub use kernel_registry::{TransactionKernelRegistry, VersionedKernel};
+
 mod outputs;
 pub use outputs::{
-    ACCOUNT_UPDATE_COMMITMENT_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
+    ACCOUNT_UPDATE_COMMITMENT_WORD_IDX, FEE_ASSET_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
     parse_final_account_header,
 };
 
 pub use crate::errors::{
-    TransactionEventError, TransactionKernelError, TransactionTraceParsingError,
+    TransactionEventError, TransactionKernelError, TransactionKernelSelectionError,
+    TransactionTraceParsingError,
 };
 
 mod procedures;
@@ -96,43 +101,42 @@ impl TransactionKernel {
         TX_SCRIPT_MAIN.clone()
     }
 
-    /// Returns [ProgramInfo] for the transaction kernel executable program.
+    /// Returns [ProgramInfo] for the latest built-in transaction kernel executable program.
+    ///
+    /// To obtain the [ProgramInfo] of other kernel versions, see [TransactionKernelRegistry].
     ///
     /// # Panics
     /// Panics if the transaction kernel source is not well-formed.
     pub fn program_info() -> ProgramInfo {
-        // TODO: make static
-        let program_hash = Self::main().hash();
-        let kernel = Self::kernel().kernel().clone();
-
-        ProgramInfo::new(program_hash, kernel)
+        TransactionKernelRegistry::default().latest().program_info()
     }
 
     /// Transforms the provided [TransactionInputs] and [TransactionArgs] into stack and advice
     /// inputs needed to execute a transaction kernel for a specific transaction.
     ///
+    /// The inputs are prepared for the built-in kernel matching the transaction kernel commitment
+    /// of the reference block. See [`TransactionKernelRegistry::prepare_inputs()`] for preparing
+    /// inputs for a specific kernel.
+    ///
     /// If `init_advice_inputs` is provided, they will be included in the returned advice inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the reference block does not support any of the built-in kernels.
     pub fn prepare_inputs(
         tx_inputs: &TransactionInputs,
         tx_args: &TransactionArgs,
         init_advice_inputs: Option<AdviceInputs>,
-    ) -> (StackInputs, TransactionAdviceInputs) {
-        let account = tx_inputs.account();
-
-        let stack_inputs = TransactionKernel::build_input_stack(
-            account.id(),
-            account.init_commitment(),
-            tx_inputs.input_notes().commitment(),
-            tx_inputs.block_header().commitment(),
-            tx_inputs.block_header().block_num(),
-        );
-
-        let mut tx_advice_inputs = TransactionAdviceInputs::new(tx_inputs, tx_args);
-        if let Some(init_advice_inputs) = init_advice_inputs {
-            tx_advice_inputs.extend(init_advice_inputs);
-        }
-
-        (stack_inputs, tx_advice_inputs)
+    ) -> Result<(StackInputs, TransactionAdviceInputs), TransactionKernelSelectionError> {
+        let kernel_registry = TransactionKernelRegistry::default();
+        let tx_kernel_commitment = tx_inputs.block_header().tx_kernel_commitment();
+        let kernel_version = kernel_registry
+            .select(tx_kernel_commitment)
+            .ok_or(TransactionKernelSelectionError::UnsupportedTransactionKernel(
+                tx_kernel_commitment,
+            ))?
+            .version();
+
+        Ok(kernel_registry.prepare_inputs(kernel_version, tx_inputs, tx_args, init_advice_inputs))
     }
 
     // ASSEMBLER CONSTRUCTOR
@@ -205,6 +209,7 @@ impl TransactionKernel {
     /// [
     ///     OUTPUT_NOTES_COMMITMENT,
     ///     ACCOUNT_UPDATE_COMMITMENT,
+    ///     FEE_ASSET,
     ///     expiration_block_num,
     /// ]
     /// ```
@@ -213,17 +218,20 @@ impl TransactionKernel {
     /// - OUTPUT_NOTES_COMMITMENT is a commitment to the output notes.
     /// - ACCOUNT_UPDATE_COMMITMENT is the hash of the the final account commitment and account
     ///   delta commitment.
+    /// - FEE_ASSET is the fungible asset paid as the fee of the transaction.
     /// - expiration_block_num is the block number at which the transaction will expire.
     pub fn build_output_stack(
         final_account_commitment: Digest,
         account_delta_commitment: Digest,
         output_notes_commitment: Digest,
+        fee: FungibleAsset,
         expiration_block_num: BlockNumber,
     ) -> StackOutputs {
         let account_update_commitment =
             Hasher::merge(&[final_account_commitment, account_delta_commitment]);
-        let mut outputs: Vec<Felt> = Vec::with_capacity(9);
+        let mut outputs: Vec<Felt> = Vec::with_capacity(13);
         outputs.push(Felt::from(expiration_block_num));
+        outputs.extend(Word::from(fee));
         outputs.extend(account_update_commitment);
         outputs.extend(output_notes_commitment);
         outputs.reverse();
@@ -236,12 +244,14 @@ impl TransactionKernel {
     ///
     /// The data on the stack is expected to be arranged as follows:
     ///
-    /// Stack: [OUTPUT_NOTES_COMMITMENT, ACCOUNT_UPDATE_COMMITMENT, tx_expiration_block_num]
+    /// Stack: [OUTPUT_NOTES_COMMITMENT, ACCOUNT_UPDATE_COMMITMENT, FEE_ASSET,
+    ///         tx_expiration_block_num]
     ///
     /// Where:
     /// - OUTPUT_NOTES_COMMITMENT is the commitment of the output notes.
     /// - ACCOUNT_UPDATE_COMMITMENT is the hash of the the final account commitment and account
     ///   delta commitment.
+    /// - FEE_ASSET is the fungible asset paid as the fee of the transaction.
     /// - tx_expiration_block_num is the block height at which the transaction will become expired,
     ///   defined by the sum of the execution block ref and the transaction's block expiration delta
     ///   (if set during transaction execution).
@@ -249,11 +259,12 @@ impl TransactionKernel {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - Indices 9..16 on the stack are not zeroes.
+    /// - The fee asset is not a valid fungible asset.
+    /// - Indices 13..16 on the stack are not zeroes.
     /// - Overflow addresses are not empty.
     pub fn parse_output_stack(
         stack: &StackOutputs,
-    ) -> Result<(Digest, Digest, BlockNumber), TransactionOutputError> {
+    ) -> Result<(Digest, Digest, FungibleAsset, BlockNumber), TransactionOutputError> {
         let output_notes_commitment = stack
             .get_stack_word(OUTPUT_NOTES_COMMITMENT_WORD_IDX * 4)
             .expect("output_notes_commitment (first word) missing")
@@ -264,9 +275,14 @@ impl TransactionKernel {
             .expect("account_update_commitment (second word) missing")
             .into();
 
+        let fee = stack
+            .get_stack_word(FEE_ASSET_WORD_IDX * 4)
+            .expect("fee_asset (third word) missing");
+        let fee = FungibleAsset::try_from(fee).map_err(TransactionOutputError::FeeAssetInvalid)?;
+
         let expiration_block_num = stack
             .get_stack_item(EXPIRATION_BLOCK_ELEMENT_IDX)
-            .expect("element on index 8 missing");
+            .expect("element on index 12 missing");
 
         let expiration_block_num = u32::try_from(expiration_block_num.as_int())
             .map_err(|_| {
@@ -276,21 +292,15 @@ impl TransactionKernel {
             })?
             .into();
 
-        // Make sure that indices 9, 10 and 11 are zeroes (i.e. the third word without the
+        // Make sure that indices 13, 14 and 15 are zeroes (i.e. the fourth word without the
         // expiration block number).
-        if stack.get_stack_word(9).expect("third word missing")[..3] != EMPTY_WORD[..3] {
+        if stack.get_stack_word(12).expect("fourth word missing")[..3] != EMPTY_WORD[..3] {
             return Err(TransactionOutputError::OutputStackInvalid(
-                "indices 9, 10 and 11 on the output stack should be ZERO".into(),
+                "indices 13, 14 and 15 on the output stack should be ZERO".into(),
             ));
         }
 
-        if stack.get_stack_word(12).expect("fourth word missing") != EMPTY_WORD {
-            return Err(TransactionOutputError::OutputStackInvalid(
-                "fourth word on output stack should consist only of ZEROs".into(),
-            ));
-        }
-
-        Ok((output_notes_commitment, account_update_commitment, expiration_block_num))
+        Ok((output_notes_commitment, account_update_commitment, fee, expiration_block_num))
     }
 
     // TRANSACTION OUTPUT PARSER
@@ -300,12 +310,14 @@ impl TransactionKernel {
     ///
     /// The output stack is expected to be arrange as follows:
     ///
-    /// Stack: [OUTPUT_NOTES_COMMITMENT, ACCOUNT_UPDATE_COMMITMENT, tx_expiration_block_num]
+    /// Stack: [OUTPUT_NOTES_COMMITMENT, ACCOUNT_UPDATE_COMMITMENT, FEE_ASSET,
+    ///         tx_expiration_block_num]
     ///
     /// Where:
     /// - OUTPUT_NOTES_COMMITMENT is the commitment of the output notes.
     /// - ACCOUNT_UPDATE_COMMITMENT is the hash of the final account commitment and the account
     ///   delta commitment of the account that the transaction is being executed against.
+    /// - FEE_ASSET is the fungible asset paid as the fee of the transaction.
     /// - tx_expiration_block_num is the block height at which the transaction will become expired,
     ///   defined by the sum of the execution block ref and the transaction's block expiration delta
     ///   (if set during transaction execution).
@@ -319,7 +331,7 @@ impl TransactionKernel {
         adv_map: &AdviceMap,
         output_notes: Vec<OutputNote>,
     ) -> Result<TransactionOutputs, TransactionOutputError> {
-        let (output_notes_commitment, account_update_commitment, expiration_block_num) =
+        let (output_notes_commitment, account_update_commitment, fee, expiration_block_num) =
             Self::parse_output_stack(stack)?;
 
         let (final_account_commitment, account_delta_commitment) =
@@ -347,6 +359,7 @@ impl TransactionKernel {
             account_delta_commitment,
             output_notes,
             expiration_block_num,
+            fee,
         })
     }
 
@@ -513,3 +526,21 @@ mod source_manager_ext {
         Ok(files)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::block::ESTIMATED_EPILOGUE_CYCLES;
+
+    /// Tests that the number of epilogue cycles charged by the kernel matches the estimate exposed
+    /// by miden-objects, which is used to compute fees outside of the kernel.
+    #[test]
+    fn estimated_epilogue_cycles_match_kernel_constant() {
+        let epilogue = include_str!("../../asm/kernels/transaction/lib/epilogue.masm");
+        let kernel_estimate = epilogue
+            .lines()
+            .find_map(|line| line.strip_prefix("const.ESTIMATED_EPILOGUE_CYCLES="))
+            .expect("epilogue should define ESTIMATED_EPILOGUE_CYCLES");
+
+        assert_eq!(kernel_estimate.trim().parse::<u32>().unwrap(), ESTIMATED_EPILOGUE_CYCLES);
+    }
+}
@@ -6,7 +6,7 @@ use miden_objects::{digest, Digest};
 // ================================================================================================
 
 /// Hashes of all dynamically executed procedures from the kernel 0.
-pub const KERNEL0_PROCEDURES: [Digest; 37] = [
+pub const KERNEL0_PROCEDURES: [Digest; 38] = [
     // account_get_initial_commitment
     digest!("0x920898348bacd6d98a399301eb308478fd32b32eab019a5a6ef7a6b44abb61f6"),
     // account_compute_current_commitment
@@ -22,33 +22,33 @@ pub const KERNEL0_PROCEDURES: [Digest; 37] = [
     // account_get_storage_commitment
     digest!("0x73b8d0e1173dbe38274e9e0c53d3f722f9d40fe24485f144a1d43d8e6d6d8ee6"),
     // account_get_item
-    digest!("0x5b429a5694ae8018def0b4eea00aebf43177bf689b6ac28465f368024c388d06"),
+    digest!("0xa9c999947118ffc09dc0b5ac39dcfc96d23a2bdd5358ab494f14a43d7c194065"),
     // account_set_item
     digest!("0xbeff03a4e4920f8e8cd6b5d5f79ffb6703c280c6fc40a8b7f1cb7ab0aeda8d3f"),
     // account_get_map_item
-    digest!("0xc726fda47785fd7252c329ed9cbf9de1408a1340690c5671c5c986072c10721a"),
+    digest!("0x717f8490ae98dd4edcaf2719764fc6335ac9784d442f7366d189042b42d618b1"),
     // account_set_map_item
-    digest!("0x3b8cde3694b7c6e15d815cf5e86e8fc11cfc7828606f87a418488617060e36a6"),
+    digest!("0x070d5af7a768631bfd1953175f7237f55fec8c53b06bf6dae891d122a7c2d4dc"),
     // account_get_vault_root
     digest!("0x279b4a9e5adca07f01cadf8ecc1303fa3c670003a7a4e69f09506b070c4023df"),
     // account_add_asset
-    digest!("0x560f3f294cb8d4a0e66d4571b129663aaa03bbabd2391f549db229b6748aea6d"),
+    digest!("0x16f87eaf3843de9fb6794180ab94e5cb958ce1fe7509d348d2fed39a176a6555"),
     // account_remove_asset
-    digest!("0x7f17e1c3956c37edc13bdf09b958d0ceee4cbdd924fb40fc437c0fba5b9e593a"),
+    digest!("0x797bf2c8181e4224426fe959ce2d20b3dcbc124e3947b27a0e1cb71c5c7e4f60"),
     // account_get_balance
-    digest!("0xc3385953bc66def5211f53a3c44de8facfb4060abbb1c9708859c314268989e8"),
+    digest!("0x57584ba3c2d0643b1e66f34e54566621984803a4e9ec27f0873bf3cdcbfe6b32"),
     // account_has_non_fungible_asset
-    digest!("0x4fea67ed25474d5494a23c5e1e06a93f8aa140d0a673c6e140e0d4f1dd8bd835"),
+    digest!("0x8bef753414c977e7683be306892a4b75407653b94db3a3d880536d6070589e66"),
     // account_was_procedure_called
     digest!("0xe85f7a761f0d90e4d880239c4c1f349125d5a53db1e058a51c462b9442117ab6"),
     // faucet_mint_asset
-    digest!("0xccf64bf06c76447b2b44f5da8d4cd305478f40010f3b8e52bd1a796260b0f0a9"),
+    digest!("0x69c4e3da23fa1a5890eb61f023c90205715fde8e4294e92a52426f4909dac28f"),
     // faucet_burn_asset
-    digest!("0x8a415e6c61522e1e642ab9fb16044dac87f95c6f0e64c3caf14ad39208b46aea"),
+    digest!("0xa6004e94c07cc5cb5e48fb3207ce4ae65baf18224e48670b4907b74e4ff218bb"),
     // faucet_get_total_fungible_asset_issuance
     digest!("0x0383b89b831d88725c21c9963860d4710b90bb1d4aac0098aa3c934762ef6b7d"),
     // faucet_is_non_fungible_asset_issued
-    digest!("0x926c869d3955626fea1c86399f09c1468b412724f608a1539967a4904d6ee1eb"),
+    digest!("0x1d7ce794c7e2e0d4b19d6b5e806d4ae15a190605807fd6d3fb0a6e53904e05fb"),
     // note_get_assets_info
     digest!("0x8a1a66c95fd9dd85e47e30d9ba64be7803dcb7d03f612235722cc484ea865b3f"),
     // note_add_asset
@@ -74,11 +74,13 @@ pub const KERNEL0_PROCEDURES: [Digest; 37] = [
     // tx_get_block_timestamp
     digest!("0x786863e6dbcd5026619afd3831b7dcbf824cda54950b0e0724ebf9d9370ec723"),
     // tx_start_foreign_context
-    digest!("0xa18344624bf0dd93c674e4b3823292a3e1ddf0e09f7fa805f0dbe92b452fd98d"),
+    digest!("0x15d9a4f35c9a2553c1509927baa8851447ad34a26ddeafa879869dac8c82b6d5"),
     // tx_end_foreign_context
-    digest!("0x90a107168d81c1c0c23890e61fb7910a64b4711afd0bf8c3098d74737e4853ba"),
+    digest!("0x34b34c928651780c67a6a5ec072fa0b351d66d77f79c91a24e9054392c52ecce"),
     // tx_get_expiration_delta
     digest!("0x756352beed1624a42d4540c434a4faa986d6d9d08ef8437699d9086fcd9ad9e7"),
     // tx_update_expiration_block_num
     digest!("0x11ca0c8662d20e6b05fbff4a20423bfa52595862b6c7c5c5ef1cc0a917e4cb62"),
+    // account_set_code
+    digest!("0xd0f17cff61ef1383971993be11a8c0a5dbbdd7681fda74528f0555cff2e1139f"),
 ];
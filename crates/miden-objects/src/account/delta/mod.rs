@@ -1,10 +1,13 @@
 use alloc::{string::ToString, vec::Vec};
 
 use super::{
-    Account, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, Serializable,
-    Word, ZERO,
+    Account, AccountCode, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt,
+    Serializable, Word, ZERO,
+};
+use crate::{
+    AccountDeltaError, Digest, EMPTY_WORD, Hasher,
+    account::{AccountId, AccountType},
 };
-use crate::{AccountDeltaError, Digest, EMPTY_WORD, Hasher, account::AccountId};
 
 mod lexicographic_word;
 pub use lexicographic_word::LexicographicWord;
@@ -27,8 +30,7 @@ pub use vault::{
 /// - vault: an [AccountVaultDelta] object that contains the changes to the account vault.
 /// - nonce: if the nonce of the account has changed, the _delta_ of the nonce is stored, i.e. the
 ///   value by which the nonce increased.
-///
-/// TODO: add ability to trace account code updates.
+/// - code: if the code of the account has changed, the new [AccountCode] is stored.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AccountDelta {
     /// The ID of the account to which this delta applies. If the delta is created during
@@ -41,6 +43,9 @@ pub struct AccountDelta {
     /// The value by which the nonce was incremented. Must be greater than zero if storage or vault
     /// are non-empty.
     nonce_delta: Felt,
+    /// The new code of the account, if the code was changed. Only accounts with updatable code
+    /// can change their code.
+    code: Option<AccountCode>,
 }
 
 impl AccountDelta {
@@ -59,9 +64,29 @@ impl AccountDelta {
         nonce_delta: Felt,
     ) -> Result<Self, AccountDeltaError> {
         // nonce must be updated if either account storage or vault were updated
-        validate_nonce(nonce_delta, &storage, &vault)?;
+        validate_nonce(nonce_delta, &storage, &vault, None)?;
+
+        Ok(Self {
+            account_id,
+            storage,
+            vault,
+            nonce_delta,
+            code: None,
+        })
+    }
 
-        Ok(Self { account_id, storage, vault, nonce_delta })
+    /// Returns a new [AccountDelta] with the provided new code of the account.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the account of this delta does not have updatable code.
+    /// - Returns an error if the nonce was not updated.
+    pub fn with_code(mut self, code: AccountCode) -> Result<Self, AccountDeltaError> {
+        validate_code(self.account_id, Some(&code))?;
+        validate_nonce(self.nonce_delta, &self.storage, &self.vault, Some(&code))?;
+
+        self.code = Some(code);
+        Ok(self)
     }
 
     /// Merge another [AccountDelta] into this one.
@@ -78,6 +103,11 @@ impl AccountDelta {
 
         self.nonce_delta = new_nonce_delta;
 
+        // the code of the later delta replaces the code of the earlier one
+        if other.code.is_some() {
+            self.code = other.code;
+        }
+
         self.storage.merge(other.storage)?;
         self.vault.merge(other.vault)
     }
@@ -85,9 +115,13 @@ impl AccountDelta {
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns true if this account delta does not contain any vault, storage or nonce updates.
+    /// Returns true if this account delta does not contain any vault, storage, code or nonce
+    /// updates.
     pub fn is_empty(&self) -> bool {
-        self.storage.is_empty() && self.vault.is_empty() && self.nonce_delta == ZERO
+        self.storage.is_empty()
+            && self.vault.is_empty()
+            && self.code.is_none()
+            && self.nonce_delta == ZERO
     }
 
     /// Returns storage updates for this account delta.
@@ -100,6 +134,11 @@ impl AccountDelta {
         &self.vault
     }
 
+    /// Returns the new code of the account, or `None` if the code was not changed.
+    pub fn code(&self) -> Option<&AccountCode> {
+        self.code.as_ref()
+    }
+
     /// Returns the amount by which the nonce was incremented.
     pub fn nonce_delta(&self) -> Felt {
         self.nonce_delta
@@ -111,8 +150,8 @@ impl AccountDelta {
     }
 
     /// Converts this storage delta into individual delta components.
-    pub fn into_parts(self) -> (AccountStorageDelta, AccountVaultDelta, Felt) {
-        (self.storage, self.vault, self.nonce_delta)
+    pub fn into_parts(self) -> (AccountStorageDelta, AccountVaultDelta, Felt, Option<AccountCode>) {
+        (self.storage, self.vault, self.nonce_delta, self.code)
     }
 
     /// Computes the commitment to the account delta.
@@ -149,6 +188,9 @@ impl AccountDelta {
     ///     - Append `[[domain = 3, slot_idx, num_changed_entries, 0], 0, 0, 0, 0]`, except if
     ///       `num_changed_entries` is 0, where slot_idx is the index of the slot and
     ///       `num_changed_entries` is the number of changed key-value pairs in the map.
+    /// - Code - if the code of the account **has changed**:
+    ///   - Append `[[domain = 4, 0, 0, 0], NEW_CODE_COMMITMENT]` where NEW_CODE_COMMITMENT is the
+    ///     commitment of the new account code.
     ///
     /// # Rationale
     ///
@@ -265,6 +307,14 @@ impl AccountDelta {
         // Storage Delta
         self.storage.append_delta_elements(&mut elements);
 
+        // Code Delta
+        if let Some(code) = &self.code {
+            const DOMAIN_CODE: Felt = Felt::new(4);
+
+            elements.extend_from_slice(&[DOMAIN_CODE, ZERO, ZERO, ZERO]);
+            elements.extend_from_slice(code.commitment().as_elements());
+        }
+
         debug_assert!(
             elements.len() % (2 * crate::WORD_SIZE) == 0,
             "expected elements to contain an even number of words, but it contained {} elements",
@@ -347,6 +397,7 @@ impl Serializable for AccountDelta {
         self.storage.write_into(target);
         self.vault.write_into(target);
         self.nonce_delta.write_into(target);
+        self.code.write_into(target);
     }
 
     fn get_size_hint(&self) -> usize {
@@ -354,6 +405,7 @@ impl Serializable for AccountDelta {
             + self.storage.get_size_hint()
             + self.vault.get_size_hint()
             + self.nonce_delta.get_size_hint()
+            + self.code.get_size_hint()
     }
 }
 
@@ -363,11 +415,19 @@ impl Deserializable for AccountDelta {
         let storage = AccountStorageDelta::read_from(source)?;
         let vault = AccountVaultDelta::read_from(source)?;
         let nonce_delta = Felt::read_from(source)?;
+        let code = Option::<AccountCode>::read_from(source)?;
 
-        validate_nonce(nonce_delta, &storage, &vault)
+        validate_code(account_id, code.as_ref())
+            .and_then(|_| validate_nonce(nonce_delta, &storage, &vault, code.as_ref()))
             .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
 
-        Ok(Self { account_id, storage, vault, nonce_delta })
+        Ok(Self {
+            account_id,
+            storage,
+            vault,
+            nonce_delta,
+            code,
+        })
     }
 }
 
@@ -426,14 +486,28 @@ fn validate_nonce(
     nonce_delta: Felt,
     storage: &AccountStorageDelta,
     vault: &AccountVaultDelta,
+    code: Option<&AccountCode>,
 ) -> Result<(), AccountDeltaError> {
-    if (!storage.is_empty() || !vault.is_empty()) && nonce_delta == ZERO {
+    if (!storage.is_empty() || !vault.is_empty() || code.is_some()) && nonce_delta == ZERO {
         return Err(AccountDeltaError::ZeroNonceForNonEmptyDelta);
     }
 
     Ok(())
 }
 
+/// Checks that the code of the account with the provided ID can be changed, if the code was
+/// changed.
+fn validate_code(
+    account_id: AccountId,
+    code: Option<&AccountCode>,
+) -> Result<(), AccountDeltaError> {
+    if code.is_some() && account_id.account_type() != AccountType::RegularAccountUpdatableCode {
+        return Err(AccountDeltaError::AccountCodeNotUpdatable(account_id));
+    }
+
+    Ok(())
+}
+
 // TESTS
 // ================================================================================================
 
@@ -502,6 +576,49 @@ mod tests {
         });
     }
 
+    #[test]
+    fn account_delta_code_validation() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap();
+        let delta = AccountDelta::new(
+            account_id,
+            AccountStorageDelta::new(),
+            AccountVaultDelta::default(),
+            ONE,
+        )
+        .unwrap();
+        assert_matches!(
+            delta.with_code(AccountCode::mock()).unwrap_err(),
+            AccountDeltaError::AccountCodeNotUpdatable(id) if id == account_id
+        );
+
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE).unwrap();
+        let delta = AccountDelta::new(
+            account_id,
+            AccountStorageDelta::new(),
+            AccountVaultDelta::default(),
+            ZERO,
+        )
+        .unwrap();
+        assert_matches!(
+            delta.clone().with_code(AccountCode::mock()).unwrap_err(),
+            AccountDeltaError::ZeroNonceForNonEmptyDelta
+        );
+
+        let mut delta = AccountDelta::new(
+            account_id,
+            AccountStorageDelta::new(),
+            AccountVaultDelta::default(),
+            ONE,
+        )
+        .unwrap();
+        let code_delta = delta.clone().with_code(AccountCode::mock()).unwrap();
+        assert_ne!(code_delta.commitment(), delta.commitment());
+
+        delta.merge(code_delta).unwrap();
+        assert_eq!(delta.code(), Some(&AccountCode::mock()));
+    }
+
     #[test]
     fn account_update_details_size_hint() {
         // AccountDelta
@@ -262,8 +262,8 @@ impl Account {
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
-    /// Applies the provided delta to this account. This updates account vault, storage, and nonce
-    /// to the values specified by the delta.
+    /// Applies the provided delta to this account. This updates account vault, storage, code and
+    /// nonce to the values specified by the delta.
     ///
     /// # Errors
     /// Returns an error if:
@@ -281,6 +281,11 @@ impl Account {
         // update storage
         self.storage.apply_delta(delta.storage())?;
 
+        // update code; the delta guarantees that only updatable code is changed
+        if let Some(code) = delta.code() {
+            self.code = code.clone();
+        }
+
         // update nonce
         self.increment_nonce(delta.nonce_delta())?;
 
@@ -1,10 +1,14 @@
 use vm_core::{
-    Felt,
+    Felt, ZERO,
     utils::{Deserializable, Serializable},
 };
+use vm_processor::Digest;
 
-use super::{Account, AccountCode, AccountId, PartialStorage};
-use crate::asset::PartialVault;
+use super::{Account, AccountCode, AccountId, AccountStorage, PartialStorage, hash_account};
+use crate::{
+    AccountError,
+    asset::{AssetVault, PartialVault},
+};
 
 /// A partial representation of an account.
 ///
@@ -69,6 +73,54 @@ impl PartialAccount {
     pub fn vault(&self) -> &PartialVault {
         &self.partial_vault
     }
+
+    /// Returns the commitment of this account.
+    ///
+    /// The commitment is the same as the commitment of the full account, see
+    /// [Account::commitment()].
+    pub fn commitment(&self) -> Digest {
+        hash_account(
+            self.id,
+            self.nonce,
+            self.partial_vault.root(),
+            self.partial_storage.commitment(),
+            self.account_code.commitment(),
+        )
+    }
+
+    /// Returns the commitment of this account as used for the initial account state commitment in
+    /// transaction proofs.
+    ///
+    /// See [Account::init_commitment()] for details.
+    pub fn init_commitment(&self) -> Digest {
+        if self.is_new() {
+            Digest::default()
+        } else {
+            self.commitment()
+        }
+    }
+
+    /// Returns true if the account is new (i.e. it has not been initialized yet).
+    pub fn is_new(&self) -> bool {
+        self.nonce == ZERO
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a mutable reference to the partial storage of this account.
+    ///
+    /// This can be used to add proofs for storage map entries to the partial storage.
+    pub fn storage_mut(&mut self) -> &mut PartialStorage {
+        &mut self.partial_storage
+    }
+
+    /// Returns a mutable reference to the partial vault of this account.
+    ///
+    /// This can be used to add proofs for assets to the partial vault.
+    pub fn vault_mut(&mut self) -> &mut PartialVault {
+        &mut self.partial_vault
+    }
 }
 
 impl From<Account> for PartialAccount {
@@ -89,6 +141,29 @@ impl From<&Account> for PartialAccount {
     }
 }
 
+impl TryFrom<PartialAccount> for Account {
+    type Error = AccountError;
+
+    /// Reconstructs the full account from a partial account.
+    ///
+    /// # Errors
+    /// Returns an error if the partial account does not contain the full storage or the full vault
+    /// of the account.
+    fn try_from(partial_account: PartialAccount) -> Result<Self, Self::Error> {
+        let storage = AccountStorage::try_from(&partial_account.partial_storage)?;
+        let vault = AssetVault::try_from(&partial_account.partial_vault)
+            .map_err(AccountError::AssetVaultUpdateError)?;
+
+        Ok(Account::from_parts(
+            partial_account.id,
+            vault,
+            storage,
+            partial_account.account_code,
+            partial_account.nonce,
+        ))
+    }
+}
+
 impl Serializable for PartialAccount {
     fn write_into<W: vm_core::utils::ByteWriter>(&self, target: &mut W) {
         target.write(self.id);
@@ -1,10 +1,10 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_crypto::merkle::{InnerNodeInfo, SmtLeaf, SmtProof};
 use vm_core::utils::{Deserializable, Serializable};
 use vm_processor::Digest;
 
-use super::{AccountStorage, AccountStorageHeader, StorageSlot};
+use super::{AccountStorage, AccountStorageHeader, StorageMap, StorageSlot, StorageSlotType};
 use crate::AccountError;
 
 /// A partial representation of an account storage, containing only a subset of the storage data.
@@ -20,15 +20,30 @@ pub struct PartialStorage {
     header: AccountStorageHeader,
     /// Merkle proofs for a subset of the account's storage maps keys
     storage_map_proofs: Vec<SmtProof>,
+    /// The original (unhashed) keys of the storage map entries contained in the proofs, keyed by
+    /// their hashed values. Keys are known only for entries added with a key, and are required to
+    /// reconstruct the full storage from this partial storage.
+    storage_map_keys: BTreeMap<Digest, Digest>,
 }
 
 impl PartialStorage {
     /// Returns a new instance of partial storage with the specified header and storage map proofs.
     ///
     /// The storage commitment is computed during instantiation based on the provided header.
+    ///
+    /// The proofs contain only the hashed keys of the storage map entries, so the original keys of
+    /// the entries in the provided proofs are unknown. Thus, unless all storage maps of the
+    /// returned partial storage are empty, it can not be converted into the full
+    /// [`AccountStorage`]. To obtain a partial storage which can be converted, create it from the
+    /// full storage, or add the proofs via [`Self::add_storage_map_proof`].
     pub fn new(header: AccountStorageHeader, storage_map_proofs: Vec<SmtProof>) -> Self {
         let commitment = header.compute_commitment();
-        PartialStorage { header, storage_map_proofs, commitment }
+        PartialStorage {
+            header,
+            storage_map_proofs,
+            commitment,
+            storage_map_keys: BTreeMap::new(),
+        }
     }
 
     /// Returns a reference to the storage map proofs of this partial storage.
@@ -73,6 +88,40 @@ impl PartialStorage {
     pub fn leaves(&self) -> impl Iterator<Item = &SmtLeaf> {
         self.storage_map_proofs.iter().map(SmtProof::leaf)
     }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided proof for the specified (unhashed) key of one of the storage maps to this
+    /// partial storage.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The root of the proof does not match the root of any of the storage map slots.
+    /// - The proof does not open the leaf of the specified key.
+    pub fn add_storage_map_proof(
+        &mut self,
+        key: Digest,
+        proof: SmtProof,
+    ) -> Result<(), AccountError> {
+        let root = proof.compute_root();
+        let is_map_root = self.header.slots().any(|(slot_type, value)| {
+            *slot_type == StorageSlotType::Map && Digest::from(*value) == root
+        });
+        if !is_map_root {
+            return Err(AccountError::StorageMapProofRootNotFound(root));
+        }
+
+        let hashed_key = StorageMap::hash_key(key);
+        if proof.get(&hashed_key).is_none() {
+            return Err(AccountError::StorageMapProofKeyMismatch(key));
+        }
+
+        self.storage_map_keys.insert(hashed_key, key);
+        self.storage_map_proofs.push(proof);
+
+        Ok(())
+    }
 }
 
 impl From<&AccountStorage> for PartialStorage {
@@ -82,16 +131,71 @@ impl From<&AccountStorage> for PartialStorage {
     /// in all map slots of the account storage.
     fn from(account_storage: &AccountStorage) -> Self {
         let mut storage_map_proofs = Vec::with_capacity(account_storage.slots().len());
+        let mut storage_map_keys = BTreeMap::new();
         for slot in account_storage.slots() {
             if let StorageSlot::Map(map) = slot {
-                let proofs: Vec<SmtProof> = map.entries().map(|(key, _)| map.open(key)).collect();
-                storage_map_proofs.extend(proofs);
+                for (key, _) in map.entries() {
+                    storage_map_proofs.push(map.open(key));
+                    storage_map_keys.insert(StorageMap::hash_key(*key), *key);
+                }
             }
         }
 
         let header: AccountStorageHeader = account_storage.to_header();
         let commitment = header.compute_commitment();
-        PartialStorage { header, storage_map_proofs, commitment }
+        PartialStorage {
+            header,
+            storage_map_proofs,
+            commitment,
+            storage_map_keys,
+        }
+    }
+}
+
+impl TryFrom<&PartialStorage> for AccountStorage {
+    type Error = AccountError;
+
+    /// Reconstructs the full account storage from a partial storage.
+    ///
+    /// # Errors
+    /// Returns an error if the partial storage does not contain all entries of a storage map, or if
+    /// the original key of any of the entries is unknown.
+    fn try_from(partial_storage: &PartialStorage) -> Result<Self, Self::Error> {
+        let mut slots = Vec::with_capacity(partial_storage.header.num_slots() as usize);
+        for (index, (slot_type, value)) in partial_storage.header.slots().enumerate() {
+            let slot = match slot_type {
+                StorageSlotType::Value => StorageSlot::Value(*value),
+                StorageSlotType::Map => {
+                    let root = Digest::from(*value);
+
+                    // collect the entries into a map first, since the same leaf may be contained
+                    // in several proofs
+                    let mut entries = BTreeMap::new();
+                    for proof in partial_storage.storage_map_proofs.iter() {
+                        if proof.compute_root() != root {
+                            continue;
+                        }
+                        for (hashed_key, value) in proof.leaf().entries() {
+                            let key = partial_storage.storage_map_keys.get(hashed_key).ok_or(
+                                AccountError::PartialStorageMissingMapEntries(index as u8),
+                            )?;
+                            entries.insert(*key, *value);
+                        }
+                    }
+
+                    let map = StorageMap::with_entries(entries)
+                        .expect("btree maps should not contain duplicate keys");
+                    if map.root() != root {
+                        return Err(AccountError::PartialStorageMissingMapEntries(index as u8));
+                    }
+
+                    StorageSlot::Map(map)
+                },
+            };
+            slots.push(slot);
+        }
+
+        AccountStorage::new(slots)
     }
 }
 
@@ -99,6 +203,7 @@ impl Serializable for PartialStorage {
     fn write_into<W: vm_core::utils::ByteWriter>(&self, target: &mut W) {
         target.write(&self.header);
         target.write(&self.storage_map_proofs);
+        target.write(&self.storage_map_keys);
     }
 }
 
@@ -108,9 +213,15 @@ impl Deserializable for PartialStorage {
     ) -> Result<Self, vm_processor::DeserializationError> {
         let header: AccountStorageHeader = source.read()?;
         let storage_map_proofs: Vec<SmtProof> = source.read()?;
+        let storage_map_keys: BTreeMap<Digest, Digest> = source.read()?;
 
         let commitment = header.compute_commitment();
 
-        Ok(PartialStorage { header, storage_map_proofs, commitment })
+        Ok(PartialStorage {
+            header,
+            storage_map_proofs,
+            commitment,
+            storage_map_keys,
+        })
     }
 }
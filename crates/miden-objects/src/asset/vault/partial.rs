@@ -1,10 +1,11 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_crypto::merkle::{InnerNodeInfo, SmtLeaf, SmtProof};
 use vm_core::utils::{Deserializable, Serializable};
 use vm_processor::Digest;
 
 use super::AssetVault;
+use crate::{AssetVaultError, asset::Asset};
 
 /// A partial representation of an asset vault, containing only proofs for a subset of assets.
 ///
@@ -47,6 +48,26 @@ impl PartialVault {
     pub fn leaves(&self) -> impl Iterator<Item = &SmtLeaf> {
         self.vault_proofs.iter().map(SmtProof::leaf)
     }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided proof of an asset (or of its absence) to this partial vault.
+    ///
+    /// # Errors
+    /// Returns an error if the root of the proof does not match the root of the vault.
+    pub fn add_proof(&mut self, proof: SmtProof) -> Result<(), AssetVaultError> {
+        let proof_root = proof.compute_root();
+        if proof_root != self.root {
+            return Err(AssetVaultError::ProofRootMismatch {
+                expected: self.root,
+                actual: proof_root,
+            });
+        }
+
+        self.vault_proofs.push(proof);
+        Ok(())
+    }
 }
 
 impl From<&AssetVault> for PartialVault {
@@ -62,6 +83,32 @@ impl From<&AssetVault> for PartialVault {
     }
 }
 
+impl TryFrom<&PartialVault> for AssetVault {
+    type Error = AssetVaultError;
+
+    /// Reconstructs the full asset vault from a partial vault.
+    ///
+    /// # Errors
+    /// Returns an error if the partial vault does not contain all assets of the vault.
+    fn try_from(partial_vault: &PartialVault) -> Result<Self, Self::Error> {
+        // collect the assets into a map first, since the same leaf may be contained in several
+        // proofs
+        let assets = partial_vault
+            .leaves()
+            .flat_map(SmtLeaf::entries)
+            .map(|(vault_key, value)| Asset::try_from(*value).map(|asset| (*vault_key, asset)))
+            .collect::<Result<BTreeMap<_, _>, _>>()
+            .map_err(AssetVaultError::InvalidAsset)?;
+
+        let vault = AssetVault::new(&assets.into_values().collect::<Vec<_>>())?;
+        if vault.root() != partial_vault.root {
+            return Err(AssetVaultError::PartialVaultMissingAssets);
+        }
+
+        Ok(vault)
+    }
+}
+
 impl Serializable for PartialVault {
     fn write_into<W: vm_core::utils::ByteWriter>(&self, target: &mut W) {
         target.write(self.root);
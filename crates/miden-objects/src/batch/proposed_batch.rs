@@ -7,6 +7,7 @@ use alloc::{
 use crate::{
     MAX_ACCOUNTS_PER_BATCH, MAX_INPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BATCH,
     account::AccountId,
+    asset::FungibleAsset,
     batch::{BatchAccountUpdate, BatchId, InputOutputNoteTracker},
     block::{BlockHeader, BlockNumber},
     errors::ProposedBatchError,
@@ -52,6 +53,9 @@ pub struct ProposedBatch {
     /// The output notes of this batch. This consists of all notes created by transactions in the
     /// batch that are not consumed within the same batch. These are sorted by [`OutputNote::id`].
     output_notes: Vec<OutputNote>,
+    /// The sum of the fees paid by the transactions in this batch, in the native asset of the
+    /// reference block.
+    fee: FungibleAsset,
 }
 
 impl ProposedBatch {
@@ -113,6 +117,8 @@ impl ProposedBatch {
     /// - There are duplicate transactions.
     /// - If any transaction's expiration block number is less than or equal to the batch's
     ///   reference block.
+    /// - The fee of any transaction is not paid in the native asset of the reference block, or the
+    ///   sum of the fees exceeds the maximum amount of a fungible asset.
     pub fn new(
         transactions: Vec<Arc<ProvenTransaction>>,
         reference_block_header: BlockHeader,
@@ -232,6 +238,18 @@ impl ProposedBatch {
             batch_expiration_block_num = batch_expiration_block_num.min(tx.expiration_block_num());
         }
 
+        // Aggregate the fees paid by the transactions.
+        // --------------------------------------------------------------------------------------------
+
+        // The native asset is the same for all blocks of the chain, so the fee of each transaction
+        // must be paid in the native asset of the batch's reference block.
+        let mut fee = reference_block_header.fee_parameters().empty_fee();
+        for tx in transactions.iter() {
+            fee = fee.add(tx.fee()).map_err(|source| {
+                ProposedBatchError::InvalidTransactionFee { transaction_id: tx.id(), source }
+            })?;
+        }
+
         // Check for duplicates in input notes.
         // --------------------------------------------------------------------------------------------
 
@@ -291,6 +309,7 @@ impl ProposedBatch {
             batch_expiration_block_num,
             input_notes,
             output_notes,
+            fee,
         })
     }
 
@@ -350,6 +369,13 @@ impl ProposedBatch {
         &self.output_notes
     }
 
+    /// Returns the sum of the fees paid by the transactions in this batch.
+    ///
+    /// The fee is denominated in the native asset of the reference block.
+    pub fn fee(&self) -> FungibleAsset {
+        self.fee
+    }
+
     /// Consumes the proposed batch and returns its underlying parts.
     #[allow(clippy::type_complexity)]
     pub fn into_parts(
@@ -364,6 +390,7 @@ impl ProposedBatch {
         InputNotes<InputNoteCommitment>,
         Vec<OutputNote>,
         BlockNumber,
+        FungibleAsset,
     ) {
         (
             self.transactions,
@@ -375,6 +402,7 @@ impl ProposedBatch {
             self.input_notes,
             self.output_notes,
             self.batch_expiration_block_num,
+            self.fee,
         )
     }
 }
@@ -432,6 +460,7 @@ mod tests {
     use crate::{
         Digest, Word,
         account::{AccountIdVersion, AccountStorageMode, AccountType},
+        asset::FungibleAsset,
         transaction::ProvenTransactionBuilder,
     };
 
@@ -482,6 +511,7 @@ mod tests {
             block_num,
             block_ref,
             expiration_block_num,
+            FungibleAsset::mock(0).unwrap_fungible(),
             proof,
         )
         .build()
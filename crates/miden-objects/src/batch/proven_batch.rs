@@ -3,6 +3,7 @@ use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 use crate::{
     Digest, MIN_PROOF_SECURITY_LEVEL,
     account::AccountId,
+    asset::FungibleAsset,
     batch::{BatchAccountUpdate, BatchId},
     block::BlockNumber,
     errors::ProvenBatchError,
@@ -21,6 +22,7 @@ pub struct ProvenBatch {
     input_notes: InputNotes<InputNoteCommitment>,
     output_notes: Vec<OutputNote>,
     batch_expiration_block_num: BlockNumber,
+    fee: FungibleAsset,
     transactions: OrderedTransactionHeaders,
 }
 
@@ -34,6 +36,7 @@ impl ProvenBatch {
     ///
     /// Returns an error if the batch expiration block number is not greater than the reference
     /// block number.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: BatchId,
         reference_block_commitment: Digest,
@@ -42,6 +45,7 @@ impl ProvenBatch {
         input_notes: InputNotes<InputNoteCommitment>,
         output_notes: Vec<OutputNote>,
         batch_expiration_block_num: BlockNumber,
+        fee: FungibleAsset,
         transactions: OrderedTransactionHeaders,
     ) -> Result<Self, ProvenBatchError> {
         // Check that the batch expiration block number is greater than the reference block number.
@@ -60,6 +64,7 @@ impl ProvenBatch {
             input_notes,
             output_notes,
             batch_expiration_block_num,
+            fee,
             transactions,
         })
     }
@@ -87,6 +92,13 @@ impl ProvenBatch {
         self.batch_expiration_block_num
     }
 
+    /// Returns the sum of the fees paid by the transactions in this batch.
+    ///
+    /// The fee is denominated in the native asset of the reference block.
+    pub fn fee(&self) -> FungibleAsset {
+        self.fee
+    }
+
     /// Returns an iterator over the IDs of all accounts updated in this batch.
     pub fn updated_accounts(&self) -> impl Iterator<Item = AccountId> + use<'_> {
         self.account_updates.keys().copied()
@@ -154,6 +166,7 @@ impl Serializable for ProvenBatch {
         self.input_notes.write_into(target);
         self.output_notes.write_into(target);
         self.batch_expiration_block_num.write_into(target);
+        self.fee.write_into(target);
         self.transactions.write_into(target);
     }
 }
@@ -167,6 +180,7 @@ impl Deserializable for ProvenBatch {
         let input_notes = InputNotes::<InputNoteCommitment>::read_from(source)?;
         let output_notes = Vec::<OutputNote>::read_from(source)?;
         let batch_expiration_block_num = BlockNumber::read_from(source)?;
+        let fee = FungibleAsset::read_from(source)?;
         let transactions = OrderedTransactionHeaders::read_from(source)?;
 
         Self::new(
@@ -177,6 +191,7 @@ impl Deserializable for ProvenBatch {
             input_notes,
             output_notes,
             batch_expiration_block_num,
+            fee,
             transactions,
         )
         .map_err(|e| DeserializationError::UnknownError(e.to_string()))
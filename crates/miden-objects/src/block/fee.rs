@@ -0,0 +1,162 @@
+use alloc::string::ToString;
+
+use crate::{
+    FeeError, Felt, Word, ZERO,
+    account::{AccountId, AccountType},
+    asset::FungibleAsset,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The number of cycles charged for the part of the transaction epilogue which is executed after
+/// the fee is computed, including the authentication procedure of the account.
+///
+/// The fee is removed from the account vault before the authentication procedure is executed, so
+/// that it is part of the account delta which is authenticated. Thus, the cycles of the remaining
+/// epilogue cannot be measured when the fee is computed, and this fixed estimate is charged
+/// instead. It covers the verification of a single Falcon signature, which dominates the cost of
+/// the standard authentication procedures.
+///
+/// The transaction kernel defines the same constant in its epilogue, and a test in miden-lib
+/// asserts that both values are equal.
+pub const ESTIMATED_EPILOGUE_CYCLES: u32 = 1 << 17;
+
+// FEE PARAMETERS
+// ================================================================================================
+
+/// The parameters which determine the fee paid by transactions executed against a block.
+///
+/// The fee parameters consist of:
+/// - `native_asset_id` is the ID of the fungible faucet issuing the asset in which fees are paid.
+/// - `verification_base_fee` is the amount of the native asset charged per power of two of the
+///   number of cycles of a transaction, i.e., the fee of a transaction is computed as
+///   `verification_base_fee * ceil(log2(num_cycles))`.
+///
+/// The fee is removed from the vault of the native account in the transaction epilogue. A
+/// `verification_base_fee` of zero disables fees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeParameters {
+    native_asset_id: AccountId,
+    verification_base_fee: u32,
+}
+
+impl FeeParameters {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [FeeParameters] instantiated from the provided values.
+    ///
+    /// # Errors
+    /// Returns an error if the provided native asset ID is not the ID of a fungible faucet.
+    pub fn new(native_asset_id: AccountId, verification_base_fee: u32) -> Result<Self, FeeError> {
+        if native_asset_id.account_type() != AccountType::FungibleFaucet {
+            return Err(FeeError::NativeAssetIdNotFungible(native_asset_id));
+        }
+
+        Ok(Self { native_asset_id, verification_base_fee })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the faucet issuing the asset in which fees are paid.
+    pub fn native_asset_id(&self) -> AccountId {
+        self.native_asset_id
+    }
+
+    /// Returns the amount of the native asset charged per power of two of the number of cycles of
+    /// a transaction.
+    pub fn verification_base_fee(&self) -> u32 {
+        self.verification_base_fee
+    }
+
+    /// Returns the fee charged for a transaction with the provided number of cycles.
+    ///
+    /// This mirrors the fee computation of the transaction kernel, which charges the number of
+    /// cycles executed before the fee is computed plus [ESTIMATED_EPILOGUE_CYCLES].
+    pub fn compute_fee(&self, num_cycles: u32) -> FungibleAsset {
+        let log2_num_cycles = num_cycles.max(1).next_power_of_two().ilog2() as u64;
+        let amount = self.verification_base_fee as u64 * log2_num_cycles;
+
+        FungibleAsset::new(self.native_asset_id, amount)
+            .expect("native asset ID should be a fungible faucet and the amount should be valid")
+    }
+
+    /// Returns a fee of zero in the native asset, which is the starting point for aggregating the
+    /// fees of several transactions.
+    pub fn empty_fee(&self) -> FungibleAsset {
+        FungibleAsset::new(self.native_asset_id, 0)
+            .expect("native asset ID should be a fungible faucet")
+    }
+
+    /// Returns the word representation of the fee parameters as stored in the kernel memory and
+    /// hashed into the block commitment:
+    /// `[native_asset_id_suffix, native_asset_id_prefix, verification_base_fee, 0]`.
+    pub fn to_word(&self) -> Word {
+        [
+            self.native_asset_id.suffix(),
+            self.native_asset_id.prefix().as_felt(),
+            Felt::from(self.verification_base_fee),
+            ZERO,
+        ]
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for FeeParameters {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.native_asset_id.write_into(target);
+        self.verification_base_fee.write_into(target);
+    }
+}
+
+impl Deserializable for FeeParameters {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let native_asset_id = source.read()?;
+        let verification_base_fee = source.read()?;
+
+        Self::new(native_asset_id, verification_base_fee)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::testing::account_id::{
+        ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET,
+    };
+
+    #[test]
+    fn fee_parameters_require_fungible_faucet() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET).unwrap();
+        assert_matches!(
+            FeeParameters::new(faucet_id, 10),
+            Err(FeeError::NativeAssetIdNotFungible(id)) if id == faucet_id
+        );
+    }
+
+    #[test]
+    fn fee_is_computed_from_log2_of_cycles() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let fee_parameters = FeeParameters::new(faucet_id, 10).unwrap();
+
+        assert_eq!(fee_parameters.compute_fee(1 << 16).amount(), 160);
+        assert_eq!(fee_parameters.compute_fee((1 << 16) + 1).amount(), 170);
+
+        assert_eq!(fee_parameters.empty_fee().amount(), 0);
+        assert_eq!(fee_parameters.empty_fee().faucet_id(), faucet_id);
+
+        let fee_parameters = FeeParameters::read_from_bytes(&fee_parameters.to_bytes()).unwrap();
+        assert_eq!(fee_parameters.verification_base_fee(), 10);
+    }
+}
@@ -1,8 +1,8 @@
 use alloc::vec::Vec;
 
 use crate::{
-    Digest, Felt, Hasher, ZERO,
-    block::BlockNumber,
+    Digest, EMPTY_WORD, Felt, Hasher, ZERO,
+    block::{BlockNumber, FeeParameters},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
@@ -25,6 +25,8 @@ use crate::{
 ///   transition.
 /// - `timestamp` is the time when the block was created, in seconds since UNIX epoch. Current
 ///   representation is sufficient to represent time up to year 2106.
+/// - `fee_parameters` are the parameters determining the fees paid by transactions executed against
+///   this block.
 /// - `sub_commitment` is a sequential hash of all fields except the note_root.
 /// - `commitment` is a 2-to-1 hash of the sub_commitment and the note_root.
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -40,6 +42,7 @@ pub struct BlockHeader {
     tx_kernel_commitment: Digest,
     proof_commitment: Digest,
     timestamp: u32,
+    fee_parameters: FeeParameters,
     sub_commitment: Digest,
     commitment: Digest,
 }
@@ -59,6 +62,7 @@ impl BlockHeader {
         tx_kernel_commitment: Digest,
         proof_commitment: Digest,
         timestamp: u32,
+        fee_parameters: FeeParameters,
     ) -> Self {
         // compute block sub commitment
         let sub_commitment = Self::compute_sub_commitment(
@@ -72,6 +76,7 @@ impl BlockHeader {
             proof_commitment,
             timestamp,
             block_num,
+            fee_parameters,
         );
 
         // The sub commitment is merged with the note_root - hash(sub_commitment, note_root) to
@@ -92,6 +97,7 @@ impl BlockHeader {
             tx_kernel_commitment,
             proof_commitment,
             timestamp,
+            fee_parameters,
             sub_commitment,
             commitment,
         }
@@ -184,6 +190,12 @@ impl BlockHeader {
         self.timestamp
     }
 
+    /// Returns the parameters determining the fees paid by transactions executed against this
+    /// block.
+    pub fn fee_parameters(&self) -> &FeeParameters {
+        &self.fee_parameters
+    }
+
     /// Returns the block number of the epoch block to which this block belongs.
     pub fn epoch_block_num(&self) -> BlockNumber {
         BlockNumber::from_epoch(self.block_epoch())
@@ -197,7 +209,10 @@ impl BlockHeader {
     /// The sub commitment is computed as a sequential hash of the following fields:
     /// `prev_block_commitment`, `chain_commitment`, `account_root`, `nullifier_root`, `note_root`,
     /// `tx_commitment`, `tx_kernel_commitment`, `proof_commitment`, `version`, `timestamp`,
-    /// `block_num` (all fields except the `note_root`).
+    /// `block_num`, `fee_parameters` (all fields except the `note_root`).
+    ///
+    /// The fee parameters are followed by an empty word so that the number of hashed elements is a
+    /// multiple of the rate width.
     #[allow(clippy::too_many_arguments)]
     fn compute_sub_commitment(
         version: u32,
@@ -210,8 +225,9 @@ impl BlockHeader {
         proof_commitment: Digest,
         timestamp: u32,
         block_num: BlockNumber,
+        fee_parameters: FeeParameters,
     ) -> Digest {
-        let mut elements: Vec<Felt> = Vec::with_capacity(32);
+        let mut elements: Vec<Felt> = Vec::with_capacity(40);
         elements.extend_from_slice(prev_block_commitment.as_elements());
         elements.extend_from_slice(chain_commitment.as_elements());
         elements.extend_from_slice(account_root.as_elements());
@@ -220,6 +236,8 @@ impl BlockHeader {
         elements.extend_from_slice(tx_kernel_commitment.as_elements());
         elements.extend_from_slice(proof_commitment.as_elements());
         elements.extend([block_num.into(), version.into(), timestamp.into(), ZERO]);
+        elements.extend(fee_parameters.to_word());
+        elements.extend(EMPTY_WORD);
         Hasher::hash_elements(&elements)
     }
 }
@@ -240,6 +258,7 @@ impl Serializable for BlockHeader {
         self.tx_kernel_commitment.write_into(target);
         self.proof_commitment.write_into(target);
         self.timestamp.write_into(target);
+        self.fee_parameters.write_into(target);
     }
 }
 
@@ -256,6 +275,7 @@ impl Deserializable for BlockHeader {
         let tx_kernel_commitment = source.read()?;
         let proof_commitment = source.read()?;
         let timestamp = source.read()?;
+        let fee_parameters = source.read()?;
 
         Ok(Self::new(
             version,
@@ -269,6 +289,7 @@ impl Deserializable for BlockHeader {
             tx_kernel_commitment,
             proof_commitment,
             timestamp,
+            fee_parameters,
         ))
     }
 }
@@ -1,6 +1,9 @@
 mod header;
 pub use header::BlockHeader;
 
+mod fee;
+pub use fee::{ESTIMATED_EPILOGUE_CYCLES, FeeParameters};
+
 mod block_number;
 pub use block_number::BlockNumber;
 
@@ -7,6 +7,7 @@ use alloc::{
 use crate::{
     Digest, EMPTY_WORD, MAX_BATCHES_PER_BLOCK,
     account::{AccountId, delta::AccountUpdateDetails},
+    asset::FungibleAsset,
     batch::{BatchAccountUpdate, BatchId, InputOutputNoteTracker, OrderedBatches, ProvenBatch},
     block::{
         AccountUpdateWitness, AccountWitness, BlockHeader, BlockNumber, NullifierWitness,
@@ -55,6 +56,9 @@ pub struct ProposedBlock {
     ///
     /// As part of proving the block, this header will be added to the next partial blockchain.
     prev_block_header: BlockHeader,
+    /// The sum of the fees paid by all transactions in this block, in the native asset of the
+    /// previous block.
+    fee: FungibleAsset,
 }
 
 impl ProposedBlock {
@@ -118,6 +122,11 @@ impl ProposedBlock {
     /// - Account updates cannot be merged, i.e. if [`AccountUpdateDetails::merge`] fails on the
     ///   updates from two batches.
     ///
+    /// ## Fees
+    ///
+    /// - The fee of any batch is not paid in the native asset of the previous block, or the sum of
+    ///   the fees exceeds the maximum amount of a fungible asset.
+    ///
     /// ## Time
     ///
     /// - The given `timestamp` does not increase monotonically compared to the previous block
@@ -163,6 +172,11 @@ impl ProposedBlock {
             &batches,
         )?;
 
+        // Aggregate the fees paid by the batches.
+        // --------------------------------------------------------------------------------------------
+
+        let fee = compute_block_fee(&batches, block_inputs.prev_block_header())?;
+
         // Check for duplicates in the input and output notes and compute the input and output notes
         // of the block by erasing notes that are created and consumed within this block as well as
         // authenticating unauthenticated notes.
@@ -223,6 +237,7 @@ impl ProposedBlock {
             created_nullifiers: nullifier_witnesses,
             partial_blockchain,
             prev_block_header,
+            fee,
         })
     }
 
@@ -303,6 +318,13 @@ impl ProposedBlock {
         &self.output_note_batches
     }
 
+    /// Returns the sum of the fees paid by all transactions in this block.
+    ///
+    /// The fee is denominated in the native asset of the previous block.
+    pub fn fee(&self) -> FungibleAsset {
+        self.fee
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -341,6 +363,7 @@ impl Serializable for ProposedBlock {
         self.created_nullifiers.write_into(target);
         self.partial_blockchain.write_into(target);
         self.prev_block_header.write_into(target);
+        self.fee.write_into(target);
     }
 }
 
@@ -354,6 +377,7 @@ impl Deserializable for ProposedBlock {
             created_nullifiers: <BTreeMap<Nullifier, NullifierWitness>>::read_from(source)?,
             partial_blockchain: PartialBlockchain::read_from(source)?,
             prev_block_header: BlockHeader::read_from(source)?,
+            fee: FungibleAsset::read_from(source)?,
         };
 
         Ok(block)
@@ -530,6 +554,26 @@ fn compute_block_output_notes(
     block_output_note_batches
 }
 
+/// Returns the sum of the fees paid by the provided batches.
+///
+/// The native asset is the same for all blocks of the chain, so the fee of each batch must be
+/// paid in the native asset of the previous block.
+fn compute_block_fee(
+    batches: &[ProvenBatch],
+    prev_block_header: &BlockHeader,
+) -> Result<FungibleAsset, ProposedBlockError> {
+    let mut fee = prev_block_header.fee_parameters().empty_fee();
+
+    for batch in batches {
+        fee = fee.add(batch.fee()).map_err(|source| ProposedBlockError::InvalidBatchFee {
+            batch_id: batch.id(),
+            source,
+        })?;
+    }
+
+    Ok(fee)
+}
+
 /// Computes the output note of the given batch. This is essentially the batch's output notes minus
 /// all erased notes.
 ///
@@ -2,6 +2,7 @@ use alloc::vec::Vec;
 
 use crate::{
     Digest, MIN_PROOF_SECURITY_LEVEL,
+    asset::FungibleAsset,
     block::{BlockAccountUpdate, BlockHeader, BlockNoteIndex, BlockNoteTree, OutputNoteBatch},
     note::Nullifier,
     transaction::{OrderedTransactionHeaders, OutputNote},
@@ -46,6 +47,9 @@ pub struct ProvenBlock {
     /// The aggregated and flattened transaction headers of all batches in the order in which they
     /// appeared in the proposed block.
     transactions: OrderedTransactionHeaders,
+
+    /// The sum of the fees paid by all transactions in this block.
+    fee: FungibleAsset,
 }
 
 impl ProvenBlock {
@@ -61,6 +65,7 @@ impl ProvenBlock {
         output_note_batches: Vec<OutputNoteBatch>,
         created_nullifiers: Vec<Nullifier>,
         transactions: OrderedTransactionHeaders,
+        fee: FungibleAsset,
     ) -> Self {
         Self {
             header,
@@ -68,6 +73,7 @@ impl ProvenBlock {
             output_note_batches,
             created_nullifiers,
             transactions,
+            fee,
         }
     }
 
@@ -138,6 +144,13 @@ impl ProvenBlock {
     pub fn transactions(&self) -> &OrderedTransactionHeaders {
         &self.transactions
     }
+
+    /// Returns the sum of the fees paid by all transactions in this block.
+    ///
+    /// The fee is denominated in the native asset defined by the fee parameters of the block.
+    pub fn fee(&self) -> FungibleAsset {
+        self.fee
+    }
 }
 
 // SERIALIZATION
@@ -150,6 +163,7 @@ impl Serializable for ProvenBlock {
         self.output_note_batches.write_into(target);
         self.created_nullifiers.write_into(target);
         self.transactions.write_into(target);
+        self.fee.write_into(target);
     }
 }
 
@@ -161,6 +175,7 @@ impl Deserializable for ProvenBlock {
             output_note_batches: <Vec<OutputNoteBatch>>::read_from(source)?,
             created_nullifiers: <Vec<Nullifier>>::read_from(source)?,
             transactions: OrderedTransactionHeaders::read_from(source)?,
+            fee: FungibleAsset::read_from(source)?,
         };
 
         Ok(block)
@@ -115,6 +115,12 @@ pub enum AccountError {
         "digest of the seed has {actual} trailing zeroes but must have at least {expected} trailing zeroes"
     )]
     SeedDigestTooFewTrailingZeros { expected: u32, actual: u32 },
+    #[error("partial storage does not contain all entries of the storage map in slot {0}")]
+    PartialStorageMissingMapEntries(u8),
+    #[error("storage map proof with root {0} does not match the root of any storage map slot")]
+    StorageMapProofRootNotFound(Digest),
+    #[error("storage map proof does not open the leaf of key {0}")]
+    StorageMapProofKeyMismatch(Digest),
     #[error("storage slot at index {0} is not of type map")]
     StorageSlotNotMap(u8),
     #[error("storage slot at index {0} is not of type value")]
@@ -253,6 +259,8 @@ pub enum AccountDeltaError {
     },
     #[error("zero nonce is not allowed for non-empty account deltas")]
     ZeroNonceForNonEmptyDelta,
+    #[error("account {0} does not have updatable code and its code can not be changed")]
+    AccountCodeNotUpdatable(AccountId),
     #[error(
         "account nonce increment {current} plus the other nonce increment {increment} overflows a felt to {new}"
     )]
@@ -365,10 +373,16 @@ pub enum AssetVaultError {
     DuplicateNonFungibleAsset(NonFungibleAsset),
     #[error("fungible asset {0} does not exist in the vault")]
     FungibleAssetNotFound(FungibleAsset),
+    #[error("vault contains an invalid asset")]
+    InvalidAsset(#[source] AssetError),
     #[error("faucet id {0} is not a fungible faucet id")]
     NotAFungibleFaucetId(AccountId),
     #[error("non fungible asset {0} does not exist in the vault")]
     NonFungibleAssetNotFound(NonFungibleAsset),
+    #[error("partial vault does not contain all assets of the vault")]
+    PartialVaultMissingAssets,
+    #[error("vault proof has root {actual} but the vault root is {expected}")]
+    ProofRootMismatch { expected: Digest, actual: Digest },
     #[error("subtracting fungible asset amounts would underflow")]
     SubtractFungibleAssetBalanceError(#[source] AssetError),
 }
@@ -425,6 +439,15 @@ pub enum NoteError {
     PublicNoteRequired(NoteType),
 }
 
+// FEE ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum FeeError {
+    #[error("native asset ID {0} of the fee parameters is not the ID of a fungible faucet")]
+    NativeAssetIdNotFungible(AccountId),
+}
+
 // PARTIAL BLOCKCHAIN ERROR
 // ================================================================================================
 
@@ -530,6 +553,8 @@ pub enum TransactionOutputError {
     OutputNotesCommitmentInconsistent { expected: Digest, actual: Digest },
     #[error("transaction kernel output stack is invalid: {0}")]
     OutputStackInvalid(String),
+    #[error("fee asset on the transaction kernel output stack is invalid")]
+    FeeAssetInvalid(#[source] AssetError),
     #[error(
         "total number of output notes is {0} which exceeds the maximum of {MAX_OUTPUT_NOTES_PER_TX}"
     )]
@@ -685,6 +710,12 @@ pub enum ProposedBatchError {
         block_reference: Digest,
         transaction_id: TransactionId,
     },
+
+    #[error("failed to add the fee paid by transaction {transaction_id} to the fee of the batch")]
+    InvalidTransactionFee {
+        transaction_id: TransactionId,
+        source: AssetError,
+    },
 }
 
 // PROVEN BATCH ERROR
@@ -692,10 +723,9 @@ pub enum ProposedBatchError {
 
 #[derive(Debug, Error)]
 pub enum ProvenBatchError {
-    #[error("failed to verify transaction {transaction_id} in transaction batch")]
+    #[error("failed to verify {} transaction(s) in transaction batch", .failures.len())]
     TransactionVerificationFailed {
-        transaction_id: TransactionId,
-        source: Box<dyn Error + Send + Sync + 'static>,
+        failures: Vec<(TransactionId, Box<dyn Error + Send + Sync + 'static>)>,
     },
     #[error(
         "batch expiration block number {batch_expiration_block_num} is not greater than the reference block number {reference_block_num}"
@@ -704,6 +734,13 @@ pub enum ProvenBatchError {
         batch_expiration_block_num: BlockNumber,
         reference_block_num: BlockNumber,
     },
+    #[error(
+        "block {block_reference} referenced by transaction {transaction_id} is not in the partial blockchain"
+    )]
+    MissingTransactionBlockReference {
+        block_reference: Digest,
+        transaction_id: TransactionId,
+    },
 }
 
 // PROPOSED BLOCK ERROR
@@ -845,6 +882,9 @@ pub enum ProposedBlockError {
         account_id: AccountId,
         source: Box<AccountDeltaError>,
     },
+
+    #[error("failed to add the fee paid by batch {batch_id} to the fee of the block")]
+    InvalidBatchFee { batch_id: BatchId, source: AssetError },
 }
 
 // NULLIFIER TREE ERROR
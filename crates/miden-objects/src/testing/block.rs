@@ -6,9 +6,19 @@ use crate::Felt;
 use crate::{
     Digest,
     account::Account,
-    block::{AccountTree, BlockHeader, BlockNumber},
+    asset::FungibleAsset,
+    block::{AccountTree, BlockHeader, BlockNumber, FeeParameters},
 };
 
+impl FeeParameters {
+    /// Returns mocked fee parameters with the [mocked fungible asset
+    /// issuer](FungibleAsset::mock_issuer) as the native asset and a verification base fee of
+    /// zero, i.e., fees are disabled.
+    pub fn mock() -> Self {
+        FeeParameters::new(FungibleAsset::mock_issuer(), 0).expect("fee parameters should be valid")
+    }
+}
+
 impl BlockHeader {
     /// Creates a mock block. The account tree is formed from the provided `accounts`,
     /// and the chain commitment and note root are set to the provided `chain_commitment` and
@@ -90,6 +100,7 @@ impl BlockHeader {
             tx_kernel_commitment,
             proof_commitment,
             timestamp,
+            FeeParameters::mock(),
         )
     }
 }
@@ -1,12 +1,14 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 use core::cell::OnceCell;
 
 use super::{
-    Account, AccountDelta, AccountHeader, AccountId, AdviceInputs, BlockHeader, InputNote,
-    InputNotes, NoteId, OutputNotes, TransactionArgs, TransactionId, TransactionInputs,
-    TransactionOutputs, TransactionWitness,
+    AccountDelta, AccountHeader, AccountId, AdviceInputs, BlockHeader, InputNote, InputNotes,
+    NoteId, OutputNotes, TransactionArgs, TransactionId, TransactionInputs, TransactionOutputs,
+    TransactionWitness,
 };
 use crate::{
+    account::PartialAccount,
+    asset::FungibleAsset,
     block::BlockNumber,
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
@@ -79,7 +81,7 @@ impl ExecutedTransaction {
     }
 
     /// Returns the description of the account before the transaction was executed.
-    pub fn initial_account(&self) -> &Account {
+    pub fn initial_account(&self) -> &PartialAccount {
         self.tx_inputs.account()
     }
 
@@ -103,6 +105,11 @@ impl ExecutedTransaction {
         self.tx_outputs.expiration_block_num
     }
 
+    /// Returns the fee paid by the transaction in the native asset.
+    pub fn fee(&self) -> FungibleAsset {
+        self.tx_outputs.fee
+    }
+
     /// Returns a reference to the transaction arguments.
     pub fn tx_args(&self) -> &TransactionArgs {
         &self.tx_args
@@ -123,8 +130,15 @@ impl ExecutedTransaction {
         &self.tx_inputs
     }
 
-    /// Returns all the data requested by the VM from the advice provider while executing the
-    /// transaction program.
+    /// Returns the data requested by the VM from the advice provider while executing the
+    /// transaction program which cannot be derived from the transaction inputs (e.g., the
+    /// signatures generated during execution).
+    ///
+    /// Advice data prepared from the transaction inputs and the kernel (e.g., the advice stack,
+    /// account code and note data) is not part of the witness, as it is re-created from the
+    /// transaction inputs when the transaction is proven. Advice map entries and merkle store nodes
+    /// supplied via the [TransactionArgs] are part of the witness only if they were read during
+    /// execution.
     pub fn advice_witness(&self) -> &AdviceInputs {
         &self.advice_witness
     }
@@ -139,12 +153,19 @@ impl ExecutedTransaction {
     // --------------------------------------------------------------------------------------------
 
     /// Returns individual components of this transaction.
+    ///
+    /// The advice map and merkle store of the transaction arguments are not included in the
+    /// returned [TransactionWitness], as the entries of them which were read during execution are
+    /// part of the advice witness.
     pub fn into_parts(
         self,
     ) -> (AccountDelta, TransactionOutputs, TransactionWitness, TransactionMeasurements) {
+        let mut tx_args = self.tx_args;
+        tx_args.clear_advice_map_and_store();
+
         let tx_witness = TransactionWitness {
             tx_inputs: self.tx_inputs,
-            tx_args: self.tx_args,
+            tx_args,
             advice_witness: self.advice_witness,
         };
         (self.account_delta, self.tx_outputs, tx_witness, self.tx_measurements)
@@ -199,8 +220,8 @@ impl Deserializable for ExecutedTransaction {
 // TRANSACTION MEASUREMENTS
 // ================================================================================================
 
-/// Stores the resulting number of cycles for each transaction execution stage obtained from the
-/// `TransactionProgress` struct.
+/// Stores the resulting number of cycles for each transaction execution stage, as well as the
+/// number of account and note operations, obtained from the `TransactionProgress` struct.
 #[derive(Debug, Clone, PartialEq)]
 pub struct TransactionMeasurements {
     pub prologue: usize,
@@ -208,6 +229,31 @@ pub struct TransactionMeasurements {
     pub note_execution: Vec<(NoteId, usize)>,
     pub tx_script_processing: usize,
     pub epilogue: usize,
+    /// The number of cycles spent executing procedures of each foreign account, including the
+    /// cycles spent loading the account.
+    pub foreign_account_execution: BTreeMap<AccountId, usize>,
+    /// The number of cycles spent creating each output note and adding assets to it, indexed by
+    /// the index of the output note.
+    pub output_note_creation: Vec<usize>,
+    /// The number of items read from account storage value slots.
+    pub storage_reads: usize,
+    /// The number of items written to account storage value slots.
+    pub storage_writes: usize,
+    /// The number of entries read from account storage maps.
+    pub map_reads: usize,
+    /// The number of entries written to account storage maps.
+    pub map_writes: usize,
+    /// The number of assets added to the account vault.
+    pub vault_adds: usize,
+    /// The number of assets removed from the account vault.
+    pub vault_removes: usize,
+    /// The number of signatures pushed onto the advice stack in response to signature requests.
+    pub provided_signatures: usize,
+    /// The number of rows of the range checker trace of the transaction.
+    pub range_trace_length: usize,
+    /// The number of rows of the chiplets trace of the transaction, which contains the rows of the
+    /// hash, bitwise, memory and kernel ROM chiplets.
+    pub chiplets_trace_length: usize,
 }
 
 impl TransactionMeasurements {
@@ -216,11 +262,17 @@ impl TransactionMeasurements {
         self.prologue + self.notes_processing + self.tx_script_processing + self.epilogue
     }
 
-    /// Returns the trace length of the transaction which is the next power of 2 of the total cycles
-    /// spent executing the transaction.
+    /// Returns the trace length of the transaction which is the next power of 2 of the length of
+    /// the longest trace segment, i.e., the maximum of the total cycles spent executing the
+    /// transaction and the lengths of the range checker and chiplets traces.
+    ///
+    /// The chiplets trace often exceeds the number of cycles, e.g. for transactions which perform
+    /// many hash or memory operations.
     pub fn trace_length(&self) -> usize {
-        let total_cycles = self.total_cycles();
-        total_cycles.next_power_of_two()
+        self.total_cycles()
+            .max(self.range_trace_length)
+            .max(self.chiplets_trace_length)
+            .next_power_of_two()
     }
 }
 
@@ -231,6 +283,17 @@ impl Serializable for TransactionMeasurements {
         self.note_execution.write_into(target);
         self.tx_script_processing.write_into(target);
         self.epilogue.write_into(target);
+        self.foreign_account_execution.write_into(target);
+        self.output_note_creation.write_into(target);
+        self.storage_reads.write_into(target);
+        self.storage_writes.write_into(target);
+        self.map_reads.write_into(target);
+        self.map_writes.write_into(target);
+        self.vault_adds.write_into(target);
+        self.vault_removes.write_into(target);
+        self.provided_signatures.write_into(target);
+        self.range_trace_length.write_into(target);
+        self.chiplets_trace_length.write_into(target);
     }
 }
 
@@ -241,6 +304,17 @@ impl Deserializable for TransactionMeasurements {
         let note_execution = Vec::<(NoteId, usize)>::read_from(source)?;
         let tx_script_processing = usize::read_from(source)?;
         let epilogue = usize::read_from(source)?;
+        let foreign_account_execution = BTreeMap::<AccountId, usize>::read_from(source)?;
+        let output_note_creation = Vec::<usize>::read_from(source)?;
+        let storage_reads = usize::read_from(source)?;
+        let storage_writes = usize::read_from(source)?;
+        let map_reads = usize::read_from(source)?;
+        let map_writes = usize::read_from(source)?;
+        let vault_adds = usize::read_from(source)?;
+        let vault_removes = usize::read_from(source)?;
+        let provided_signatures = usize::read_from(source)?;
+        let range_trace_length = usize::read_from(source)?;
+        let chiplets_trace_length = usize::read_from(source)?;
 
         Ok(Self {
             prologue,
@@ -248,6 +322,17 @@ impl Deserializable for TransactionMeasurements {
             note_execution,
             tx_script_processing,
             epilogue,
+            foreign_account_execution,
+            output_note_creation,
+            storage_reads,
+            storage_writes,
+            map_reads,
+            map_writes,
+            vault_adds,
+            vault_removes,
+            provided_signatures,
+            range_trace_length,
+            chiplets_trace_length,
         })
     }
 }
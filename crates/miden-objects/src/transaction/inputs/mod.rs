@@ -3,7 +3,7 @@ use core::fmt::Debug;
 use super::PartialBlockchain;
 use crate::{
     TransactionInputError, Word,
-    account::{Account, AccountId},
+    account::{AccountId, PartialAccount},
     block::BlockHeader,
     note::{Note, NoteInclusionProof},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
@@ -19,9 +19,13 @@ pub use notes::{InputNote, InputNotes, ToInputNoteCommitments};
 // ================================================================================================
 
 /// Contains the data required to execute a transaction.
+///
+/// The account is provided as a [PartialAccount], which needs to contain only the storage map
+/// entries and assets accessed by the transaction. For new accounts, the partial account must
+/// contain the full state of the account.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransactionInputs {
-    account: Account,
+    account: PartialAccount,
     account_seed: Option<Word>,
     block_header: BlockHeader,
     block_chain: PartialBlockchain,
@@ -38,7 +42,7 @@ impl TransactionInputs {
     /// - For a new account, account seed is not provided or the provided seed is invalid.
     /// - For an existing account, account seed was provided.
     pub fn new(
-        account: Account,
+        account: PartialAccount,
         account_seed: Option<Word>,
         block_header: BlockHeader,
         block_chain: PartialBlockchain,
@@ -93,7 +97,7 @@ impl TransactionInputs {
     // --------------------------------------------------------------------------------------------
 
     /// Returns account against which the transaction is to be executed.
-    pub fn account(&self) -> &Account {
+    pub fn account(&self) -> &PartialAccount {
         &self.account
     }
 
@@ -124,7 +128,13 @@ impl TransactionInputs {
     /// Consumes these transaction inputs and returns their underlying components.
     pub fn into_parts(
         self,
-    ) -> (Account, Option<Word>, BlockHeader, PartialBlockchain, InputNotes<InputNote>) {
+    ) -> (
+        PartialAccount,
+        Option<Word>,
+        BlockHeader,
+        PartialBlockchain,
+        InputNotes<InputNote>,
+    ) {
         (
             self.account,
             self.account_seed,
@@ -147,7 +157,7 @@ impl Serializable for TransactionInputs {
 
 impl Deserializable for TransactionInputs {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let account = Account::read_from(source)?;
+        let account = PartialAccount::read_from(source)?;
         let account_seed = source.read()?;
         let block_header = BlockHeader::read_from(source)?;
         let block_chain = PartialBlockchain::read_from(source)?;
@@ -162,7 +172,7 @@ impl Deserializable for TransactionInputs {
 
 /// Validates that the provided seed is valid for this account.
 fn validate_account_seed(
-    account: &Account,
+    account: &PartialAccount,
     account_seed: Option<Word>,
 ) -> Result<(), TransactionInputError> {
     match (account.is_new(), account_seed) {
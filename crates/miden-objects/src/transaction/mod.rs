@@ -1,6 +1,6 @@
 use super::{
     Digest, Felt, Hasher, WORD_SIZE, Word, ZERO,
-    account::{Account, AccountDelta, AccountHeader, AccountId},
+    account::{AccountDelta, AccountHeader, AccountId},
     block::BlockHeader,
     note::{NoteId, Nullifier},
     vm::AdviceInputs,
@@ -28,4 +28,4 @@ pub use proven_tx::{
 pub use transaction_id::TransactionId;
 pub use tx_args::{TransactionArgs, TransactionScript};
 pub use tx_header::TransactionHeader;
-pub use tx_witness::TransactionWitness;
+pub use tx_witness::{TransactionWitness, TransactionWitnessSize};
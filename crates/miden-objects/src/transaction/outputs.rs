@@ -4,6 +4,7 @@ use core::fmt::Debug;
 use crate::{
     Digest, Felt, Hasher, MAX_OUTPUT_NOTES_PER_TX, TransactionOutputError, Word,
     account::AccountHeader,
+    asset::FungibleAsset,
     block::BlockNumber,
     note::{
         Note, NoteAssets, NoteHeader, NoteId, NoteMetadata, NoteRecipient, PartialNote,
@@ -26,6 +27,8 @@ pub struct TransactionOutputs {
     pub output_notes: OutputNotes,
     /// Defines up to which block the transaction is considered valid.
     pub expiration_block_num: BlockNumber,
+    /// The fee paid by the transaction in the native asset.
+    pub fee: FungibleAsset,
 }
 
 impl Serializable for TransactionOutputs {
@@ -34,6 +37,7 @@ impl Serializable for TransactionOutputs {
         self.account_delta_commitment.write_into(target);
         self.output_notes.write_into(target);
         self.expiration_block_num.write_into(target);
+        self.fee.write_into(target);
     }
 }
 
@@ -43,12 +47,14 @@ impl Deserializable for TransactionOutputs {
         let account_delta_commitment = Digest::read_from(source)?;
         let output_notes = OutputNotes::read_from(source)?;
         let expiration_block_num = BlockNumber::read_from(source)?;
+        let fee = FungibleAsset::read_from(source)?;
 
         Ok(Self {
             account,
             account_delta_commitment,
             output_notes,
             expiration_block_num,
+            fee,
         })
     }
 }
@@ -257,7 +257,7 @@ mod tests {
     use crate::{
         Digest, PartialBlockchainError,
         alloc::vec::Vec,
-        block::{BlockHeader, BlockNumber},
+        block::{BlockHeader, BlockNumber, FeeParameters},
         crypto::merkle::{Mmr, PartialMmr},
     };
 
@@ -411,6 +411,7 @@ mod tests {
             Digest::default(),
             Digest::default(),
             0,
+            FeeParameters::mock(),
         )
     }
 }
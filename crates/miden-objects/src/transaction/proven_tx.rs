@@ -4,6 +4,7 @@ use super::{InputNote, ToInputNoteCommitments};
 use crate::{
     ACCOUNT_UPDATE_MAX_SIZE, EMPTY_WORD, ProvenTransactionError,
     account::delta::AccountUpdateDetails,
+    asset::FungibleAsset,
     block::BlockNumber,
     note::NoteHeader,
     transaction::{
@@ -49,6 +50,9 @@ pub struct ProvenTransaction {
     /// The block number by which the transaction will expire, as defined by the executed scripts.
     expiration_block_num: BlockNumber,
 
+    /// The fee paid by the transaction in the native asset.
+    fee: FungibleAsset,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -104,6 +108,11 @@ impl ProvenTransaction {
         self.expiration_block_num
     }
 
+    /// Returns the fee paid by the transaction in the native asset.
+    pub fn fee(&self) -> FungibleAsset {
+        self.fee
+    }
+
     /// Returns an iterator over the nullifiers of all input notes in this transaction.
     ///
     /// This includes both authenticated and unauthenticated notes.
@@ -201,6 +210,7 @@ impl Serializable for ProvenTransaction {
         self.ref_block_num.write_into(target);
         self.ref_block_commitment.write_into(target);
         self.expiration_block_num.write_into(target);
+        self.fee.write_into(target);
         self.proof.write_into(target);
     }
 }
@@ -215,6 +225,7 @@ impl Deserializable for ProvenTransaction {
         let ref_block_num = BlockNumber::read_from(source)?;
         let ref_block_commitment = Digest::read_from(source)?;
         let expiration_block_num = BlockNumber::read_from(source)?;
+        let fee = FungibleAsset::read_from(source)?;
         let proof = ExecutionProof::read_from(source)?;
 
         let id = TransactionId::new(
@@ -232,6 +243,7 @@ impl Deserializable for ProvenTransaction {
             ref_block_num,
             ref_block_commitment,
             expiration_block_num,
+            fee,
             proof,
         };
 
@@ -277,6 +289,9 @@ pub struct ProvenTransactionBuilder {
     /// The block number by which the transaction will expire, as defined by the executed scripts.
     expiration_block_num: BlockNumber,
 
+    /// The fee paid by the transaction in the native asset.
+    fee: FungibleAsset,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -286,6 +301,7 @@ impl ProvenTransactionBuilder {
     // --------------------------------------------------------------------------------------------
 
     /// Returns a [ProvenTransactionBuilder] used to build a [ProvenTransaction].
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         account_id: AccountId,
         initial_account_commitment: Digest,
@@ -294,6 +310,7 @@ impl ProvenTransactionBuilder {
         ref_block_num: BlockNumber,
         ref_block_commitment: Digest,
         expiration_block_num: BlockNumber,
+        fee: FungibleAsset,
         proof: ExecutionProof,
     ) -> Self {
         Self {
@@ -307,6 +324,7 @@ impl ProvenTransactionBuilder {
             ref_block_num,
             ref_block_commitment,
             expiration_block_num,
+            fee,
             proof,
         }
     }
@@ -392,6 +410,7 @@ impl ProvenTransactionBuilder {
             ref_block_num: self.ref_block_num,
             ref_block_commitment: self.ref_block_commitment,
             expiration_block_num: self.expiration_block_num,
+            fee: self.fee,
             proof: self.proof,
         };
 
@@ -629,6 +648,7 @@ mod tests {
             AccountType, AccountVaultDelta, StorageMapDelta,
             delta::{AccountUpdateDetails, LexicographicWord},
         },
+        asset::FungibleAsset,
         block::BlockNumber,
         testing::account_id::{
             ACCOUNT_ID_PRIVATE_SENDER, ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
@@ -739,6 +759,7 @@ mod tests {
             ref_block_num,
             ref_block_commitment,
             expiration_block_num,
+            FungibleAsset::mock(0).unwrap_fungible(),
             proof,
         )
         .build()
@@ -10,6 +10,7 @@ use miden_crypto::merkle::InnerNodeInfo;
 use super::{AccountInputs, Digest, Felt, Word};
 use crate::{
     EMPTY_WORD, MastForest, MastNodeId, TransactionScriptError,
+    account::AccountCode,
     note::{NoteId, NoteRecipient},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     vm::{AdviceInputs, AdviceMap, Program},
@@ -32,6 +33,8 @@ use crate::{
 /// - Advice inputs: provides data needed by the runtime, like the details of public output notes.
 /// - Foreign account inputs: provides foreign account data that will be used during the foreign
 ///   procedure invocation (FPI).
+/// - New account code: the code which the native account may set via `account::set_code` during the
+///   transaction.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
@@ -39,6 +42,7 @@ pub struct TransactionArgs {
     note_args: BTreeMap<NoteId, Word>,
     advice_inputs: AdviceInputs,
     foreign_account_inputs: Vec<AccountInputs>,
+    new_account_code: Option<AccountCode>,
 }
 
 impl TransactionArgs {
@@ -54,6 +58,7 @@ impl TransactionArgs {
             note_args: Default::default(),
             advice_inputs: AdviceInputs::default().with_map(advice_map),
             foreign_account_inputs,
+            new_account_code: None,
         }
     }
 
@@ -93,6 +98,16 @@ impl TransactionArgs {
         self
     }
 
+    /// Returns new [TransactionArgs] instantiated with the provided new code of the native account.
+    ///
+    /// The code is required to execute transactions which change the code of the native account
+    /// via `account::set_code`, and it must have the commitment which is set.
+    #[must_use]
+    pub fn with_new_account_code(mut self, code: AccountCode) -> Self {
+        self.new_account_code = Some(code);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -127,6 +142,11 @@ impl TransactionArgs {
         &self.foreign_account_inputs
     }
 
+    /// Returns a reference to the new code of the native account, if any.
+    pub fn new_account_code(&self) -> Option<&AccountCode> {
+        self.new_account_code.as_ref()
+    }
+
     /// Collects and returns a set containing all code commitments from foreign accounts.
     pub fn foreign_account_code_commitments(&self) -> BTreeSet<Digest> {
         self.foreign_account_inputs()
@@ -187,6 +207,26 @@ impl TransactionArgs {
         self.advice_inputs.extend_merkle_store(iter)
     }
 
+    /// Adds the provided foreign account inputs to the transaction arguments.
+    ///
+    /// If inputs of the same foreign account were already added, they are replaced.
+    pub fn add_foreign_account_inputs(&mut self, foreign_account_inputs: AccountInputs) {
+        self.foreign_account_inputs
+            .retain(|inputs| inputs.id() != foreign_account_inputs.id());
+        self.foreign_account_inputs.push(foreign_account_inputs);
+    }
+
+    /// Removes all entries of the internal advice inputs' map and merkle store, retaining only the
+    /// advice stack.
+    ///
+    /// This is used to exclude the advice data from a
+    /// [`TransactionWitness`](super::TransactionWitness), as its advice witness contains the
+    /// subset of the data which was read during transaction execution.
+    pub fn clear_advice_map_and_store(&mut self) {
+        self.advice_inputs =
+            AdviceInputs::default().with_stack(self.advice_inputs.stack().iter().copied());
+    }
+
     /// Extends the advice inputs in self with the provided ones.
     #[cfg(feature = "testing")]
     pub fn extend_advice_inputs(&mut self, advice_inputs: AdviceInputs) {
@@ -201,6 +241,7 @@ impl Serializable for TransactionArgs {
         self.note_args.write_into(target);
         self.advice_inputs.write_into(target);
         self.foreign_account_inputs.write_into(target);
+        self.new_account_code.write_into(target);
     }
 }
 
@@ -211,6 +252,7 @@ impl Deserializable for TransactionArgs {
         let note_args = BTreeMap::<NoteId, Word>::read_from(source)?;
         let advice_inputs = AdviceInputs::read_from(source)?;
         let foreign_account_inputs = Vec::<AccountInputs>::read_from(source)?;
+        let new_account_code = Option::<AccountCode>::read_from(source)?;
 
         Ok(Self {
             tx_script,
@@ -218,6 +260,7 @@ impl Deserializable for TransactionArgs {
             note_args,
             advice_inputs,
             foreign_account_inputs,
+            new_account_code,
         })
     }
 }
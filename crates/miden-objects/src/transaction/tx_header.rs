@@ -3,6 +3,7 @@ use alloc::vec::Vec;
 use vm_processor::{DeserializationError, Digest};
 
 use crate::{
+    asset::FungibleAsset,
     note::NoteId,
     transaction::{
         AccountId, InputNoteCommitment, Nullifier, OutputNote, ProvenTransaction, TransactionId,
@@ -25,6 +26,7 @@ pub struct TransactionHeader {
     final_state_commitment: Digest,
     input_notes: Vec<Nullifier>,
     output_notes: Vec<NoteId>,
+    fee: FungibleAsset,
 }
 
 impl TransactionHeader {
@@ -44,6 +46,7 @@ impl TransactionHeader {
         final_state_commitment: Digest,
         input_notes: Vec<Nullifier>,
         output_notes: Vec<NoteId>,
+        fee: FungibleAsset,
     ) -> Self {
         Self {
             id,
@@ -52,6 +55,7 @@ impl TransactionHeader {
             final_state_commitment,
             input_notes,
             output_notes,
+            fee,
         }
     }
 
@@ -64,6 +68,7 @@ impl TransactionHeader {
         final_state_commitment: Digest,
         input_notes: Vec<Nullifier>,
         output_notes: Vec<NoteId>,
+        fee: FungibleAsset,
     ) -> Self {
         Self::new(
             id,
@@ -72,6 +77,7 @@ impl TransactionHeader {
             final_state_commitment,
             input_notes,
             output_notes,
+            fee,
         )
     }
 
@@ -115,6 +121,14 @@ impl TransactionHeader {
     pub fn output_notes(&self) -> &[NoteId] {
         &self.output_notes
     }
+
+    /// Returns the fee paid by the transaction in the native asset.
+    ///
+    /// Fees are paid in the native asset of the reference block of the transaction, so the fees of
+    /// the transactions in a batch or block can be summed up.
+    pub fn fee(&self) -> FungibleAsset {
+        self.fee
+    }
 }
 
 impl From<&ProvenTransaction> for TransactionHeader {
@@ -126,6 +140,7 @@ impl From<&ProvenTransaction> for TransactionHeader {
             tx.account_update().final_state_commitment(),
             tx.input_notes().iter().map(InputNoteCommitment::nullifier).collect(),
             tx.output_notes().iter().map(OutputNote::id).collect(),
+            tx.fee(),
         )
     }
 }
@@ -141,6 +156,7 @@ impl Serializable for TransactionHeader {
         self.final_state_commitment.write_into(target);
         self.input_notes.write_into(target);
         self.output_notes.write_into(target);
+        self.fee.write_into(target);
     }
 }
 
@@ -152,6 +168,7 @@ impl Deserializable for TransactionHeader {
         let final_state_commitment = <Digest>::read_from(source)?;
         let input_notes = <Vec<Nullifier>>::read_from(source)?;
         let output_notes = <Vec<NoteId>>::read_from(source)?;
+        let fee = <FungibleAsset>::read_from(source)?;
 
         Ok(Self::new(
             id,
@@ -160,6 +177,7 @@ impl Deserializable for TransactionHeader {
             final_state_commitment,
             input_notes,
             output_notes,
+            fee,
         ))
     }
 }
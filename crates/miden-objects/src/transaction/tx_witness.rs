@@ -16,13 +16,16 @@ use crate::utils::serde::{ByteReader, Deserializable, DeserializationError, Seri
 /// - Optional transaction arguments which may contain a transaction script, note arguments,
 ///   transaction script argument and any additional advice data to initialize the advice provider
 ///   with prior to transaction execution.
-/// - Advice witness which contains all data requested by the VM from the advice provider while
-///   executing the transaction program.
+/// - Advice witness which contains the data requested by the VM from the advice provider while
+///   executing the transaction program which cannot be derived from the transaction inputs and
+///   arguments.
 ///
-/// TODO: currently, the advice witness contains redundant and irrelevant data (e.g., tx inputs
-/// and tx outputs; account codes and a subset of that data in advice inputs).
-/// We should optimize it to contain only the minimum data required for executing/proving the
-/// transaction.
+/// The advice stack and the advice data describing the account, the input notes and the partial
+/// blockchain are re-created from the transaction inputs when the transaction is proven, so they
+/// are not part of the advice witness. Similarly, the advice map and merkle store of the
+/// transaction arguments are omitted, as the advice witness contains only the entries of them
+/// which were read during execution. The sizes of the individual parts of a witness can be
+/// inspected via [TransactionWitness::size_breakdown].
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TransactionWitness {
     pub tx_inputs: TransactionInputs,
@@ -30,6 +33,68 @@ pub struct TransactionWitness {
     pub advice_witness: AdviceInputs,
 }
 
+impl TransactionWitness {
+    /// Returns the sizes of the individual parts of this witness in its serialized form.
+    pub fn size_breakdown(&self) -> TransactionWitnessSize {
+        let tx_inputs = &self.tx_inputs;
+        let advice_stack = self.advice_witness.stack().to_bytes().len();
+        let advice_merkle_store = self.advice_witness.merkle_store().to_bytes().len();
+
+        TransactionWitnessSize {
+            account: tx_inputs.account().to_bytes().len()
+                + tx_inputs.account_seed().to_bytes().len(),
+            block_header: tx_inputs.block_header().to_bytes().len(),
+            partial_blockchain: tx_inputs.blockchain().to_bytes().len(),
+            input_notes: tx_inputs.input_notes().to_bytes().len(),
+            tx_args: self.tx_args.to_bytes().len(),
+            advice_stack,
+            advice_map: self.advice_witness.to_bytes().len() - advice_stack - advice_merkle_store,
+            advice_merkle_store,
+        }
+    }
+}
+
+// TRANSACTION WITNESS SIZE
+// ================================================================================================
+
+/// The sizes, in bytes, of the individual parts of a serialized [TransactionWitness].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TransactionWitnessSize {
+    /// The size of the initial state of the account, including the account seed.
+    pub account: usize,
+    /// The size of the reference block header.
+    pub block_header: usize,
+    /// The size of the partial blockchain.
+    pub partial_blockchain: usize,
+    /// The size of the input notes, including their inclusion proofs.
+    pub input_notes: usize,
+    /// The size of the transaction arguments.
+    pub tx_args: usize,
+    /// The size of the advice stack of the advice witness.
+    pub advice_stack: usize,
+    /// The size of the advice map of the advice witness.
+    pub advice_map: usize,
+    /// The size of the merkle store of the advice witness.
+    pub advice_merkle_store: usize,
+}
+
+impl TransactionWitnessSize {
+    /// Returns the size of the transaction inputs.
+    pub fn tx_inputs(&self) -> usize {
+        self.account + self.block_header + self.partial_blockchain + self.input_notes
+    }
+
+    /// Returns the size of the advice witness.
+    pub fn advice_witness(&self) -> usize {
+        self.advice_stack + self.advice_map + self.advice_merkle_store
+    }
+
+    /// Returns the total size of the serialized witness.
+    pub fn total(&self) -> usize {
+        self.tx_inputs() + self.tx_args + self.advice_witness()
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
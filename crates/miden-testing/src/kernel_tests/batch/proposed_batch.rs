@@ -7,12 +7,15 @@ use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
     BatchAccountUpdateError, ProposedBatchError,
     account::{Account, AccountId, AccountStorageMode},
+    asset::FungibleAsset,
     batch::ProposedBatch,
     block::BlockNumber,
     crypto::merkle::MerkleError,
     note::{Note, NoteType},
     testing::{
-        account_component::AccountMockComponent, account_id::AccountIdBuilder, note::NoteBuilder,
+        account_component::AccountMockComponent,
+        account_id::{ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1, AccountIdBuilder},
+        note::NoteBuilder,
     },
     transaction::{InputNote, InputNoteCommitment, OutputNote, PartialBlockchain},
 };
@@ -770,3 +773,57 @@ fn noop_tx_after_state_updating_tx_against_same_account() -> anyhow::Result<()>
 
     Ok(())
 }
+
+/// Tests that the fees paid by the transactions of a batch are aggregated into the batch fee.
+#[test]
+fn batch_fee_is_sum_of_transaction_fees() -> anyhow::Result<()> {
+    let TestSetup { chain, account1, account2 } = setup_chain();
+    let block1 = chain.block_header(1);
+
+    let tx1 =
+        MockProvenTxBuilder::with_account(account1.id(), Digest::default(), account1.commitment())
+            .ref_block_commitment(block1.commitment())
+            .fee(FungibleAsset::mock(10).unwrap_fungible())
+            .build()?;
+    let tx2 =
+        MockProvenTxBuilder::with_account(account2.id(), Digest::default(), account2.commitment())
+            .ref_block_commitment(block1.commitment())
+            .fee(FungibleAsset::mock(20).unwrap_fungible())
+            .build()?;
+
+    let batch = chain.propose_transaction_batch([tx1, tx2])?;
+    assert_eq!(batch.fee(), FungibleAsset::mock(30).unwrap_fungible());
+
+    let proven_batch = chain.prove_transaction_batch(batch)?;
+    assert_eq!(proven_batch.fee(), FungibleAsset::mock(30).unwrap_fungible());
+
+    Ok(())
+}
+
+/// Tests that a transaction paying its fee in an asset other than the native asset of the
+/// reference block is rejected.
+#[test]
+fn batch_rejects_fee_in_non_native_asset() -> anyhow::Result<()> {
+    let TestSetup { chain, account1, .. } = setup_chain();
+    let block1 = chain.block_header(1);
+
+    let non_native_faucet = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1)?;
+    let tx1 =
+        MockProvenTxBuilder::with_account(account1.id(), Digest::default(), account1.commitment())
+            .ref_block_commitment(block1.commitment())
+            .fee(FungibleAsset::new(non_native_faucet, 10)?)
+            .build()?;
+    let tx1_id = tx1.id();
+
+    let error = ProposedBatch::new(
+        [tx1].into_iter().map(Arc::new).collect(),
+        block1,
+        chain.latest_partial_blockchain(),
+        BTreeMap::default(),
+    )
+    .unwrap_err();
+
+    assert_matches!(error, ProposedBatchError::InvalidTransactionFee { transaction_id, .. } if transaction_id == tx1_id);
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use alloc::vec::Vec;
 use anyhow::Context;
 use miden_objects::{
     account::AccountId,
+    asset::FungibleAsset,
     block::BlockNumber,
     crypto::merkle::MerklePath,
     note::{Note, NoteInclusionProof, Nullifier},
@@ -19,6 +20,7 @@ pub struct MockProvenTxBuilder {
     final_account_commitment: Digest,
     ref_block_commitment: Option<Digest>,
     expiration_block_num: BlockNumber,
+    fee: FungibleAsset,
     output_notes: Option<Vec<OutputNote>>,
     input_notes: Option<Vec<InputNote>>,
     nullifiers: Option<Vec<Nullifier>>,
@@ -38,6 +40,7 @@ impl MockProvenTxBuilder {
             final_account_commitment,
             ref_block_commitment: None,
             expiration_block_num: BlockNumber::from(u32::MAX),
+            fee: FungibleAsset::mock(0).unwrap_fungible(),
             output_notes: None,
             input_notes: None,
             nullifiers: None,
@@ -75,6 +78,14 @@ impl MockProvenTxBuilder {
         self
     }
 
+    /// Sets the fee paid by the transaction.
+    #[must_use]
+    pub fn fee(mut self, fee: FungibleAsset) -> Self {
+        self.fee = fee;
+
+        self
+    }
+
     /// Adds notes to the transaction's output notes.
     #[must_use]
     pub fn output_notes(mut self, notes: Vec<OutputNote>) -> Self {
@@ -101,6 +112,7 @@ impl MockProvenTxBuilder {
             BlockNumber::from(0),
             self.ref_block_commitment.unwrap_or_default(),
             self.expiration_block_num,
+            self.fee,
             ExecutionProof::new(Proof::new_dummy(), Default::default()),
         )
         .add_input_notes(self.input_notes.unwrap_or_default())
@@ -2,10 +2,14 @@ use std::{collections::BTreeMap, vec::Vec};
 
 use anyhow::Context;
 use assert_matches::assert_matches;
+use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
-    account::{AccountId, delta::AccountUpdateDetails},
-    block::{BlockInputs, ProposedBlock},
-    testing::account_id::ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+    account::{AccountBuilder, AccountId, AccountStorageMode, delta::AccountUpdateDetails},
+    asset::FungibleAsset,
+    block::{BlockInputs, FeeParameters, ProposedBlock},
+    testing::{
+        account_component::AccountMockComponent, account_id::ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+    },
     transaction::{OutputNote, ProvenTransaction, TransactionHeader},
 };
 
@@ -14,7 +18,7 @@ use super::utils::{
     generate_fungible_asset, generate_tracked_note_with_asset, generate_tx_with_expiration,
     generate_tx_with_unauthenticated_notes, generate_untracked_note, setup_chain,
 };
-use crate::ProvenTransactionExt;
+use crate::{Auth, MockChain, ProvenTransactionExt};
 
 /// Tests that we can build empty blocks.
 #[test]
@@ -246,3 +250,49 @@ fn proposed_block_with_batch_at_expiration_limit() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Tests that the fees of the batches in a block are aggregated into the block fee, and that the
+/// fee is carried over to the proven block.
+#[test]
+fn proposed_block_aggregates_batch_fees() -> anyhow::Result<()> {
+    let fee_parameters = FeeParameters::new(FungibleAsset::mock_issuer(), 10)?;
+
+    let accounts = [[1; 32], [2; 32], [3; 32]]
+        .into_iter()
+        .map(|seed| {
+            AccountBuilder::new(seed)
+                .with_assets([FungibleAsset::mock(1_000)])
+                .storage_mode(AccountStorageMode::Public)
+                .with_auth_component(Auth::IncrNonce)
+                .with_component(AccountMockComponent::new_with_empty_slots(
+                    TransactionKernel::testing_assembler(),
+                )?)
+                .build_existing()
+                .context("failed to build account")
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let chain = MockChain::with_accounts_and_fee_parameters(&accounts, fee_parameters)?;
+
+    let [tx0, tx1, tx2] = accounts
+        .iter()
+        .map(|account| {
+            let executed_tx = chain.build_tx_context(account.id(), &[], &[])?.build()?.execute()?;
+            Ok(ProvenTransaction::from_executed_transaction_mocked(executed_tx))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .try_into()
+        .expect("we should have provided three accounts");
+    let expected_fee = tx0.fee().add(tx1.fee())?.add(tx2.fee())?;
+    assert!(expected_fee.amount() > 0);
+
+    let batch0 = chain.prove_transaction_batch(chain.propose_transaction_batch([tx0])?)?;
+    let batch1 = chain.prove_transaction_batch(chain.propose_transaction_batch([tx1, tx2])?)?;
+
+    let block = chain.propose_block([batch0, batch1])?;
+    assert_eq!(block.fee(), expected_fee);
+
+    let proven_block = chain.prove_block(block)?;
+    assert_eq!(proven_block.fee(), expected_fee);
+
+    Ok(())
+}
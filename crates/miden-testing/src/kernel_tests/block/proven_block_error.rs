@@ -10,6 +10,7 @@ use miden_objects::{
         Account, AccountBuilder, AccountComponent, AccountId, StorageSlot,
         delta::AccountUpdateDetails,
     },
+    asset::FungibleAsset,
     batch::ProvenBatch,
     block::{BlockInputs, BlockNumber, ProposedBlock},
     testing::account_component::{AccountMockComponent, IncrNonceAuthComponent},
@@ -405,6 +406,7 @@ fn proven_block_fails_on_creating_account_with_duplicate_account_id_prefix() ->
                 genesis_block.block_num(),
                 genesis_block.commitment(),
                 BlockNumber::from(u32::MAX),
+                FungibleAsset::mock(0).unwrap_fungible(),
                 ExecutionProof::new(Proof::new_dummy(), Default::default()),
             )
             .account_update_details(AccountUpdateDetails::Private)
@@ -4,22 +4,33 @@ use alloc::{
     sync::Arc,
     vec::Vec,
 };
+use core::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+use std::sync::Mutex;
 
 use anyhow::Context;
 use assert_matches::assert_matches;
 use miden_lib::{
+    errors::{ErrorCodeRegistry, note_script_errors::ERR_P2ID_TARGET_ACCT_MISMATCH},
     note::{create_p2id_note, create_p2ide_note},
-    transaction::TransactionKernel,
+    transaction::{
+        TransactionKernel,
+        memory::{ACCT_NONCE_IDX, NATIVE_ACCT_ID_AND_NONCE_PTR},
+    },
     utils::word_to_masm_push_string,
 };
 use miden_objects::{
     Felt, FieldElement, MIN_PROOF_SECURITY_LEVEL, Word,
     account::{
-        Account, AccountBuilder, AccountComponent, AccountId, AccountStorage,
-        delta::LexicographicWord,
+        Account, AccountBuilder, AccountComponent, AccountId, AccountStorage, AccountStorageMode,
+        PartialAccount, PartialStorage, StorageSlot, delta::LexicographicWord,
     },
     assembly::diagnostics::{IntoDiagnostic, NamedSource, WrapErr, miette},
-    asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
+    asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, PartialVault},
+    block::{BlockHeader, BlockNumber, FeeParameters},
+    crypto::merkle::{PartialMmr, SmtProof},
     note::{
         Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteHeader, NoteId, NoteInputs,
         NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
@@ -37,24 +48,35 @@ use miden_objects::{
             NON_FUNGIBLE_ASSET_DATA, NON_FUNGIBLE_ASSET_DATA_2,
         },
         note::{DEFAULT_NOTE_CODE, NoteBuilder},
-        storage::{STORAGE_INDEX_0, STORAGE_INDEX_2},
+        storage::{STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_LEAVES_2},
+    },
+    transaction::{
+        InputNotes, OutputNote, PartialBlockchain, ProvenTransaction, TransactionScript,
+        TransactionWitness,
     },
-    transaction::{OutputNote, ProvenTransaction, TransactionScript},
 };
 use miden_tx::{
-    LocalTransactionProver, NoteAccountExecution, NoteConsumptionChecker, ProvingOptions,
-    TransactionExecutor, TransactionExecutorError, TransactionHost, TransactionMastStore,
-    TransactionProver, TransactionVerifier, host::ScriptMastForestStore,
+    CachingDataStore, CalibrationPoint, DataStore, DataStoreError, EventAdvice, EventError,
+    EventHandler, EventHandlerRegistry, EventHandlerRegistryError, LocalTransactionProver,
+    NoteAccountExecution, NoteConsumptionChecker, ProvingCalibration, ProvingOptions,
+    TransactionDiff, TransactionExecutor, TransactionExecutorError, TransactionHost,
+    TransactionMastStore, TransactionProver, TransactionProverError, TransactionReplayError,
+    TransactionReplayer, TransactionVerifier, ViewCall, ViewCallDecodeError, ViewValue,
+    ViewValueType, auth::TransactionAuthenticator, host::ScriptMastForestStore,
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use vm_processor::{
-    AdviceInputs, Digest, ExecutionError, MemAdviceProvider, ONE,
+    AdviceInputs, Digest, ExecutionError, MastForest, MastForestStore, MemAdviceProvider, ONE,
+    ProcessState,
     crypto::RpoRandomCoin,
     utils::{Deserializable, Serializable},
 };
 
-use crate::{Auth, MockChain, TransactionContextBuilder, TxContextInput, utils::create_p2any_note};
+use crate::{
+    Auth, MockChain, TransactionContext, TransactionContextBuilder, TxContextInput,
+    utils::create_p2any_note,
+};
 
 mod batch;
 mod block;
@@ -102,7 +124,8 @@ fn transaction_executor_witness() -> miette::Result<()> {
         tx_inputs,
         tx_args,
         Some(executed_transaction.advice_witness().clone()),
-    );
+    )
+    .into_diagnostic()?;
 
     let mem_advice_provider = MemAdviceProvider::from(advice_inputs.into_inner());
 
@@ -111,12 +134,12 @@ fn transaction_executor_witness() -> miette::Result<()> {
     mast_store.load_account_code(tx_inputs.account().code());
 
     let mut host: TransactionHost<MemAdviceProvider> = TransactionHost::new(
-        &tx_inputs.account().into(),
+        tx_inputs.account(),
         mem_advice_provider,
         mast_store.as_ref(),
         scripts_mast_store,
         None,
-        BTreeSet::new(),
+        tx_args,
     )
     .unwrap();
     let result = vm_processor::execute(
@@ -805,6 +828,805 @@ fn prove_witness_and_verify() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that events emitted by user code are dispatched to the registered event handlers both
+/// when executing and when proving a transaction.
+#[test]
+fn execute_and_prove_with_event_handler() -> anyhow::Result<()> {
+    const DOUBLE_EVENT: u32 = 0x1234;
+
+    /// Pushes the double of the value at the top of the operand stack onto the advice stack.
+    struct DoublingHandler;
+
+    impl EventHandler for DoublingHandler {
+        fn on_event(
+            &self,
+            _event_id: u32,
+            process: ProcessState,
+            advice: &mut dyn EventAdvice,
+        ) -> Result<(), EventError> {
+            advice.push_stack(process.get_stack_item(0).double());
+            Ok(())
+        }
+    }
+
+    let account = Account::mock(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
+        Felt::ONE,
+        Auth::IncrNonce,
+        TransactionKernel::testing_assembler(),
+    );
+    // the note script is executed outside of the root context
+    let note = NoteBuilder::new(ACCOUNT_ID_SENDER.try_into()?, ChaCha20Rng::from_seed([3; 32]))
+        .code(format!(
+            "begin push.21 emit.{DOUBLE_EVENT} adv_push.1 push.42 assert_eq drop end"
+        ))
+        .build(&TransactionKernel::testing_assembler())?;
+    let tx_context = TransactionContextBuilder::new(account)
+        .extend_input_notes(vec![note.clone()])
+        .build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let execute = |executor: TransactionExecutor| {
+        executor.execute_transaction(
+            account_id,
+            block_ref,
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            tx_context.source_manager(),
+        )
+    };
+
+    let event_handlers = EventHandlerRegistry::new()
+        .with_handler(DOUBLE_EVENT..=DOUBLE_EVENT, Arc::new(DoublingHandler))?;
+
+    // without a handler, the event is rejected by the host
+    let result = execute(TransactionExecutor::new(&tx_context, None));
+    assert_matches!(result, Err(TransactionExecutorError::TransactionProgramExecutionFailed(_)));
+
+    let executed_transaction = execute(
+        TransactionExecutor::new(&tx_context, None).with_event_handlers(event_handlers.clone()),
+    )?;
+    let executed_transaction_id = executed_transaction.id();
+    let tx_witness = TransactionWitness::from(executed_transaction);
+
+    // the prover and the replayer re-execute the transaction, so they also need the handler
+    let result = LocalTransactionProver::default().prove(tx_witness.clone());
+    assert_matches!(result, Err(TransactionProverError::TransactionProgramExecutionFailed(failure)) => {
+        assert_eq!(failure.note_id(), Some(note.id()));
+    });
+    let result = TransactionReplayer::new().replay(&tx_witness);
+    assert_matches!(result, Err(TransactionReplayError::TransactionProgramExecutionFailed(failure)) => {
+        assert_eq!(failure.note_id(), Some(note.id()));
+    });
+
+    let proven_transaction = LocalTransactionProver::default()
+        .with_event_handlers(event_handlers.clone())
+        .prove(tx_witness)?;
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+    TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL).verify(&proven_transaction)?;
+
+    // handlers cannot be registered for overlapping ranges or for transaction kernel events
+    assert_matches!(
+        event_handlers
+            .clone()
+            .with_handler(0x1000..=DOUBLE_EVENT, Arc::new(DoublingHandler)),
+        Err(EventHandlerRegistryError::OverlappingRange { .. })
+    );
+    assert_matches!(
+        event_handlers.with_handler(0x2_0000..=0x2_0000, Arc::new(DoublingHandler)),
+        Err(EventHandlerRegistryError::ReservedEventIds { .. })
+    );
+
+    Ok(())
+}
+
+/// Tests that replaying the witness of a transaction reproduces the executed transaction, and that
+/// differences caused by nondeterministic user code are reported.
+#[test]
+fn transaction_replay() -> anyhow::Result<()> {
+    const COUNTER_EVENT: u32 = 0x4321;
+
+    /// Pushes an incrementing counter onto the advice stack.
+    struct CounterHandler(AtomicUsize);
+
+    impl EventHandler for CounterHandler {
+        fn on_event(
+            &self,
+            _event_id: u32,
+            _process: ProcessState,
+            advice: &mut dyn EventAdvice,
+        ) -> Result<(), EventError> {
+            let counter = self.0.fetch_add(1, Ordering::Relaxed) as u64;
+            advice.push_stack(Felt::new(counter));
+            Ok(())
+        }
+    }
+
+    let tx_script_src = format!(
+        "
+        use.miden::tx
+
+        begin
+            emit.{COUNTER_EVENT} adv_push.1
+            exec.tx::update_expiration_block_delta
+        end
+        "
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, TransactionKernel::testing_assembler())?;
+    let tx_context = TransactionContextBuilder::with_existing_mock_account()
+        .tx_script(tx_script)
+        .build()?;
+
+    let event_handlers = EventHandlerRegistry::new().with_handler(
+        COUNTER_EVENT..=COUNTER_EVENT,
+        Arc::new(CounterHandler(AtomicUsize::new(1))),
+    )?;
+    let executed_transaction = TransactionExecutor::new(&tx_context, None)
+        .with_event_handlers(event_handlers.clone())
+        .execute_transaction(
+            tx_context.account().id(),
+            tx_context.tx_inputs().block_header().block_num(),
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            tx_context.source_manager(),
+        )?;
+    let tx_witness = TransactionWitness::from(executed_transaction.clone());
+
+    // the handler provides a different value during the replay, so the expiration differs
+    let diffs = TransactionReplayer::new()
+        .with_event_handlers(event_handlers)
+        .replay_and_diff(&tx_witness, &executed_transaction)?;
+    let ref_block_num = tx_context.tx_inputs().block_header().block_num();
+    assert_eq!(
+        diffs,
+        vec![TransactionDiff::ExpirationBlockNum {
+            expected: ref_block_num + 1,
+            actual: ref_block_num + 2,
+        }]
+    );
+
+    // with a deterministic handler, the replay matches the executed transaction
+    let event_handlers = EventHandlerRegistry::new().with_handler(
+        COUNTER_EVENT..=COUNTER_EVENT,
+        Arc::new(CounterHandler(AtomicUsize::new(1))),
+    )?;
+    let replayed_transaction = TransactionReplayer::new()
+        .with_event_handlers(event_handlers)
+        .replay(&tx_witness)?;
+    assert!(replayed_transaction.diff(&executed_transaction).is_empty());
+    assert_eq!(replayed_transaction.account_delta(), executed_transaction.account_delta());
+
+    Ok(())
+}
+
+/// Tests that a replay in which nondeterministic user code executes a different number of cycles
+/// reports the difference in the fee charged for the transaction.
+#[test]
+fn transaction_replay_reports_fee_diff() -> anyhow::Result<()> {
+    const LOOP_COUNT_EVENT: u32 = 0x4322;
+
+    /// Pushes the number of loop iterations onto the advice stack, which is zero on the first
+    /// invocation and large on all subsequent invocations.
+    struct LoopCountHandler(AtomicUsize);
+
+    impl EventHandler for LoopCountHandler {
+        fn on_event(
+            &self,
+            _event_id: u32,
+            _process: ProcessState,
+            advice: &mut dyn EventAdvice,
+        ) -> Result<(), EventError> {
+            let loop_count = if self.0.fetch_add(1, Ordering::Relaxed) == 0 {
+                0
+            } else {
+                50_000
+            };
+            advice.push_stack(Felt::new(loop_count));
+            Ok(())
+        }
+    }
+
+    let fee_parameters = FeeParameters::new(FungibleAsset::mock_issuer(), 10)?;
+    let account = AccountBuilder::new([5; 32])
+        .with_assets([FungibleAsset::mock(1_000)])
+        .storage_mode(AccountStorageMode::Public)
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(AccountMockComponent::new_with_empty_slots(
+            TransactionKernel::testing_assembler(),
+        )?)
+        .build_existing()?;
+    let mock_chain = MockChain::with_accounts_and_fee_parameters(
+        core::slice::from_ref(&account),
+        fee_parameters,
+    )?;
+
+    let tx_script_src = format!(
+        "
+        begin
+            emit.{LOOP_COUNT_EVENT} adv_push.1
+            dup neq.0
+            while.true
+                sub.1 dup neq.0
+            end
+            drop
+        end
+        "
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, TransactionKernel::testing_assembler())?;
+    let tx_context = mock_chain
+        .build_tx_context(TxContextInput::AccountId(account.id()), &[], &[])?
+        .tx_script(tx_script)
+        .build()?;
+
+    let event_handlers = EventHandlerRegistry::new().with_handler(
+        LOOP_COUNT_EVENT..=LOOP_COUNT_EVENT,
+        Arc::new(LoopCountHandler(AtomicUsize::new(0))),
+    )?;
+    let executed_transaction = TransactionExecutor::new(&tx_context, None)
+        .with_event_handlers(event_handlers.clone())
+        .execute_transaction(
+            tx_context.account().id(),
+            tx_context.tx_inputs().block_header().block_num(),
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            tx_context.source_manager(),
+        )?;
+    let tx_witness = TransactionWitness::from(executed_transaction.clone());
+
+    // the replay executes the loop, so it charges a higher fee
+    let diffs = TransactionReplayer::new()
+        .with_event_handlers(event_handlers)
+        .replay_and_diff(&tx_witness, &executed_transaction)?;
+    assert!(diffs.iter().any(|diff| matches!(
+        diff,
+        TransactionDiff::Fee { expected, actual }
+            if *expected == executed_transaction.fee() && actual.amount() > expected.amount()
+    )));
+
+    Ok(())
+}
+
+/// Tests that the error codes of failed executions are decoded, and that the note and procedure in
+/// which the execution failed are reported.
+#[test]
+fn execution_failure_is_decoded() -> anyhow::Result<()> {
+    const COMPONENT_CODE: &str = r#"
+      export.withdraw
+        push.0 assert.err="withdrawal limit exceeded"
+      end"#;
+
+    let assembler = TransactionKernel::testing_assembler();
+    let component = AccountComponent::compile(
+        NamedSource::new("limit_component::limit_module", COMPONENT_CODE),
+        assembler.clone(),
+        vec![],
+    )?
+    .with_supports_all_types();
+    let account = AccountBuilder::new(ChaCha20Rng::from_seed([5; 32]).random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(component.clone())
+        .build_existing()?;
+
+    let note = NoteBuilder::new(ACCOUNT_ID_SENDER.try_into()?, ChaCha20Rng::from_seed([6; 32]))
+        .code("use.limit_component::limit_module begin call.limit_module::withdraw end")
+        .build(&assembler.clone().with_library(component.library()).unwrap())?;
+    let tx_context = TransactionContextBuilder::new(account)
+        .extend_input_notes(vec![note.clone()])
+        .build()?;
+
+    let execute = |executor: TransactionExecutor| {
+        executor.execute_transaction(
+            tx_context.account().id(),
+            tx_context.tx_inputs().block_header().block_num(),
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            assembler.source_manager(),
+        )
+    };
+
+    // the errors and procedures of user-defined components are only decoded if the component is
+    // registered
+    let result = execute(TransactionExecutor::new(&tx_context, None));
+    assert_matches!(result, Err(TransactionExecutorError::TransactionProgramExecutionFailed(failure)) => {
+        assert!(failure.error_code().is_some());
+        assert!(failure.decoded_error().is_none());
+        assert_eq!(failure.note_id(), Some(note.id()));
+        assert_eq!(failure.procedure(), None);
+    });
+
+    let result = execute(
+        TransactionExecutor::new(&tx_context, None)
+            .with_error_codes(ErrorCodeRegistry::new().with_account_component(&component)),
+    );
+    assert_matches!(result, Err(TransactionExecutorError::TransactionProgramExecutionFailed(failure)) => {
+        let decoded_error = failure.decoded_error().unwrap();
+        assert_eq!(decoded_error.name(), None);
+        assert_eq!(decoded_error.message(), "withdrawal limit exceeded");
+        assert_eq!(failure.note_id(), Some(note.id()));
+        assert_eq!(failure.procedure(), Some("limit_component::limit_module::withdraw"));
+    });
+
+    // the errors of the standard note scripts are always decoded by name
+    let p2id_note = create_p2id_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into()?,
+        vec![FungibleAsset::mock(10)],
+        NoteType::Public,
+        Default::default(),
+        &mut RpoRandomCoin::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )?;
+    let tx_context = TransactionContextBuilder::with_existing_mock_account()
+        .extend_input_notes(vec![p2id_note.clone()])
+        .build()?;
+    assert_matches!(tx_context.execute(), Err(TransactionExecutorError::TransactionProgramExecutionFailed(failure)) => {
+        let decoded_error = failure.decoded_error().unwrap();
+        assert_eq!(decoded_error.name(), Some("ERR_P2ID_TARGET_ACCT_MISMATCH"));
+        assert_eq!(decoded_error.message(), ERR_P2ID_TARGET_ACCT_MISMATCH.message());
+        assert_eq!(failure.note_id(), Some(p2id_note.id()));
+        assert!(format!("{failure}").contains("ERR_P2ID_TARGET_ACCT_MISMATCH"));
+        assert_eq!(failure.procedure(), Some("P2ID"));
+    });
+
+    // failures in kernel procedures are reported with the name of the kernel procedure
+    let tx_script = TransactionScript::compile(
+        "begin push.99 exec.::miden::account::get_item dropw end",
+        TransactionKernel::testing_assembler(),
+    )?;
+    let tx_context = TransactionContextBuilder::with_existing_mock_account()
+        .tx_script(tx_script)
+        .build()?;
+    assert_matches!(tx_context.execute(), Err(TransactionExecutorError::TransactionProgramExecutionFailed(failure)) => {
+        assert_eq!(failure.note_id(), None);
+        assert_eq!(failure.procedure(), Some("account_get_item"));
+    });
+
+    Ok(())
+}
+
+/// Tests that the cost estimate of a transaction is derived from the closest calibration point and
+/// scaled with the trace length of the transaction and the proving options.
+#[test]
+fn estimate_proving_cost() -> anyhow::Result<()> {
+    let account = Account::mock(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
+        Felt::ONE,
+        Auth::IncrNonce,
+        TransactionKernel::testing_assembler(),
+    );
+    let input_note =
+        create_p2any_note(ACCOUNT_ID_SENDER.try_into().unwrap(), &[FungibleAsset::mock(100)]);
+    let executed_transaction = TransactionContextBuilder::new(account)
+        .extend_input_notes(vec![input_note])
+        .build()?
+        .execute()?;
+    let measurements = executed_transaction.measurements();
+    let trace_length = measurements.trace_length();
+
+    let calibration = ProvingCalibration::new(
+        &ProvingOptions::default(),
+        [
+            CalibrationPoint {
+                trace_length: trace_length / 2,
+                proving_time: Duration::from_secs(1),
+                proof_size: 50_000,
+            },
+            CalibrationPoint {
+                trace_length: trace_length * 8,
+                proving_time: Duration::from_secs(100),
+                proof_size: 100_000,
+            },
+        ],
+    )?;
+    let calibration = ProvingCalibration::read_from_bytes(&calibration.to_bytes())?;
+
+    // the default options have a blowup factor of 8, so the evaluation domain of the closest
+    // calibration point is half the size of the evaluation domain of the transaction
+    let log_domain_size = (trace_length * 8).ilog2() as usize;
+    let prover = LocalTransactionProver::new(ProvingOptions::default())
+        .with_calibration(calibration.clone());
+    let estimate = prover.estimate_cost(measurements);
+    assert_eq!(estimate.trace_length(), trace_length);
+    assert_eq!(estimate.proving_time(), Duration::from_secs(2));
+    assert_eq!(estimate.proof_size(), 50_000 * log_domain_size / (log_domain_size - 1));
+
+    // the 128-bit options double the blowup factor and keep the number of queries
+    let prover = LocalTransactionProver::new(ProvingOptions::with_128_bit_security(false))
+        .with_calibration(calibration);
+    let estimate = prover.estimate_cost(measurements);
+    assert_eq!(estimate.proving_time(), Duration::from_secs(4));
+    assert_eq!(estimate.proof_size(), 50_000 * (log_domain_size + 1) / (log_domain_size - 1));
+
+    Ok(())
+}
+
+/// Tests that the trace length of a transaction accounts for the chiplets trace, which exceeds the
+/// number of cycles of a transaction performing many hash permutations.
+#[test]
+fn trace_length_includes_chiplets_trace() -> anyhow::Result<()> {
+    let account = Account::mock(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
+        Felt::ONE,
+        Auth::IncrNonce,
+        TransactionKernel::testing_assembler(),
+    );
+    // every permutation takes a single cycle but adds 8 rows to the hash chiplet
+    let tx_script = TransactionScript::compile(
+        "begin repeat.4096 hperm end end",
+        TransactionKernel::testing_assembler(),
+    )?;
+    let executed_transaction = TransactionContextBuilder::new(account)
+        .tx_script(tx_script)
+        .build()?
+        .execute()?;
+
+    let measurements = executed_transaction.measurements();
+    assert!(measurements.chiplets_trace_length > measurements.total_cycles());
+    assert!(measurements.range_trace_length > 0);
+    assert_eq!(
+        measurements.trace_length(),
+        measurements.chiplets_trace_length.next_power_of_two()
+    );
+    assert!(measurements.trace_length() > measurements.total_cycles().next_power_of_two());
+
+    Ok(())
+}
+
+/// A [DataStore] which provides the account without any storage map entries or assets, and serves
+/// them on demand from the full account of the wrapped transaction context.
+///
+/// Like a data store backed by a node, it only provides the authentication paths of the requested
+/// blocks.
+struct LazyDataStore<'a> {
+    tx_context: &'a TransactionContext,
+    serve_witnesses: bool,
+    num_transaction_inputs: AtomicUsize,
+    last_ref_blocks: Mutex<BTreeSet<BlockNumber>>,
+    num_map_witnesses: AtomicUsize,
+    num_vault_witnesses: AtomicUsize,
+    num_mast_lookups: AtomicUsize,
+}
+
+impl<'a> LazyDataStore<'a> {
+    fn new(tx_context: &'a TransactionContext, serve_witnesses: bool) -> Self {
+        Self {
+            tx_context,
+            serve_witnesses,
+            num_transaction_inputs: AtomicUsize::new(0),
+            last_ref_blocks: Mutex::new(BTreeSet::new()),
+            num_map_witnesses: AtomicUsize::new(0),
+            num_vault_witnesses: AtomicUsize::new(0),
+            num_mast_lookups: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl DataStore for LazyDataStore<'_> {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        ref_blocks: BTreeSet<BlockNumber>,
+    ) -> Result<(PartialAccount, Option<Word>, BlockHeader, PartialBlockchain), DataStoreError>
+    {
+        self.num_transaction_inputs.fetch_add(1, Ordering::Relaxed);
+        *self.last_ref_blocks.lock().unwrap() = ref_blocks.clone();
+        let (_, seed, header, mmr) =
+            self.tx_context.get_transaction_inputs(account_id, ref_blocks.clone())?;
+
+        let mut partial_mmr = PartialMmr::from_peaks(mmr.peaks());
+        let mut block_headers = Vec::new();
+        for block_header in mmr.block_headers().filter(|h| ref_blocks.contains(&h.block_num())) {
+            let block_pos = block_header.block_num().as_usize();
+            let proof = mmr.mmr().open(block_pos).unwrap().expect("block should be tracked");
+            partial_mmr
+                .track(block_pos, block_header.commitment(), &proof.merkle_path)
+                .unwrap();
+            block_headers.push(block_header.clone());
+        }
+        let mmr = PartialBlockchain::new(partial_mmr, block_headers).unwrap();
+
+        let account = self.tx_context.account();
+        let partial_account = PartialAccount::new(
+            account.id(),
+            account.nonce(),
+            account.code().clone(),
+            PartialStorage::new(account.storage().to_header(), vec![]),
+            PartialVault::new(account.vault().root(), vec![]),
+        );
+
+        Ok((partial_account, seed, header, mmr))
+    }
+
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Digest,
+        map_key: Digest,
+    ) -> Result<SmtProof, DataStoreError> {
+        let map = self.tx_context.account().storage().slots().iter().find_map(|slot| match slot {
+            StorageSlot::Map(map) if map.root() == map_root => Some(map),
+            _ => None,
+        });
+        let Some(map) = map.filter(|_| self.serve_witnesses) else {
+            return Err(DataStoreError::StorageMapEntryNotFound { account_id, map_root, map_key });
+        };
+
+        self.num_map_witnesses.fetch_add(1, Ordering::Relaxed);
+        Ok(map.open(&map_key))
+    }
+
+    fn get_vault_asset_witness(
+        &self,
+        account_id: AccountId,
+        vault_root: Digest,
+        vault_key: Digest,
+    ) -> Result<SmtProof, DataStoreError> {
+        let vault = self.tx_context.account().vault();
+        if !self.serve_witnesses || vault.root() != vault_root {
+            return Err(DataStoreError::VaultAssetNotFound { account_id, vault_root, vault_key });
+        }
+
+        self.num_vault_witnesses.fetch_add(1, Ordering::Relaxed);
+        Ok(vault.asset_tree().open(&vault_key))
+    }
+}
+
+impl MastForestStore for LazyDataStore<'_> {
+    fn get(&self, procedure_hash: &Digest) -> Option<Arc<MastForest>> {
+        self.num_mast_lookups.fetch_add(1, Ordering::Relaxed);
+        self.tx_context.get(procedure_hash)
+    }
+}
+
+/// Tests that storage map entries and assets which are not part of the partial account returned by
+/// the data store are loaded on demand, and that the resulting transaction can be proven.
+#[test]
+fn lazy_loading_of_account_data() -> anyhow::Result<()> {
+    const COUNTING_EVENT: u32 = 0x1234;
+
+    /// Counts the number of times the event was emitted.
+    #[derive(Default)]
+    struct CountingHandler(AtomicUsize);
+
+    impl EventHandler for CountingHandler {
+        fn on_event(
+            &self,
+            _event_id: u32,
+            _process: ProcessState,
+            _advice: &mut dyn EventAdvice,
+        ) -> Result<(), EventError> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }
+    }
+
+    let (map_key, map_value) = STORAGE_LEAVES_2[0];
+    let tx_script_src = format!(
+        "
+        use.test::account
+
+        begin
+            emit.{COUNTING_EVENT}
+            push.{map_key}
+            push.{STORAGE_INDEX_2}
+            call.account::get_map_item
+            push.{map_value} assert_eqw
+            dropw dropw dropw
+        end
+        ",
+        map_key = word_to_masm_push_string(&map_key),
+        map_value = word_to_masm_push_string(&map_value),
+    );
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )?;
+
+    // the vault of the mock account already contains an asset issued by the faucet of the note
+    // asset, so adding the asset requires the existing asset to be loaded
+    let input_note =
+        create_p2any_note(ACCOUNT_ID_SENDER.try_into().unwrap(), &[FungibleAsset::mock(100)]);
+    let tx_context = TransactionContextBuilder::with_existing_mock_account()
+        .extend_input_notes(vec![input_note])
+        .tx_script(tx_script)
+        .build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let counting_handler = Arc::new(CountingHandler::default());
+    let event_handlers = EventHandlerRegistry::new()
+        .with_handler(COUNTING_EVENT..=COUNTING_EVENT, counting_handler.clone())?;
+    let execute = |data_store: &LazyDataStore| {
+        TransactionExecutor::new(data_store, None)
+            .with_event_handlers(event_handlers.clone())
+            .execute_transaction(
+                account_id,
+                block_ref,
+                tx_context.input_notes().clone(),
+                tx_context.tx_args().clone(),
+                tx_context.source_manager(),
+            )
+    };
+
+    // without witnesses, execution fails at the first access to missing data, which happens when
+    // the prologue adds the note asset to the input vault
+    let result = execute(&LazyDataStore::new(&tx_context, false));
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::FetchAccountDataFailed(
+            DataStoreError::VaultAssetNotFound { .. }
+        ))
+    );
+
+    let data_store = LazyDataStore::new(&tx_context, true);
+    let executed_transaction = execute(&data_store)?;
+    assert_eq!(data_store.num_map_witnesses.load(Ordering::Relaxed), 1);
+    assert_eq!(data_store.num_vault_witnesses.load(Ordering::Relaxed), 1);
+
+    // the missing data is loaded during execution, so the transaction is executed only once
+    assert_eq!(counting_handler.0.load(Ordering::Relaxed), 1);
+
+    let initial_account = executed_transaction.initial_account();
+    assert_eq!(initial_account.commitment(), tx_context.account().commitment());
+    assert_eq!(initial_account.storage().storage_map_proofs().len(), 1);
+    assert_eq!(initial_account.vault().leaves().count(), 1);
+
+    // the loaded data is part of the transaction inputs, so the prover does not need the store
+    let executed_transaction_id = executed_transaction.id();
+    let proven_transaction = LocalTransactionProver::default()
+        .with_event_handlers(event_handlers)
+        .prove(executed_transaction.into())?;
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+    TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL).verify(&proven_transaction)?;
+
+    Ok(())
+}
+
+/// Tests that a [CachingDataStore] serves repeated executions against the same reference block
+/// from its caches, that account states are only cached when account caching is enabled, and that
+/// cached account states are fetched again after a new block.
+#[test]
+fn caching_data_store() -> anyhow::Result<()> {
+    let (map_key, _) = STORAGE_LEAVES_2[0];
+    let tx_script_src = format!(
+        "
+        use.test::account
+
+        begin
+            push.{map_key}
+            push.{STORAGE_INDEX_2}
+            call.account::get_map_item
+            dropw dropw dropw
+        end
+        ",
+        map_key = word_to_masm_push_string(&map_key),
+    );
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )?;
+    let tx_context = TransactionContextBuilder::with_existing_mock_account()
+        .tx_script(tx_script)
+        .build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let execute = |data_store: &dyn DataStore| {
+        TransactionExecutor::new(data_store, None).execute_transaction(
+            account_id,
+            block_ref,
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            tx_context.source_manager(),
+        )
+    };
+
+    // by default, the account state is fetched for every execution, while the block data,
+    // witnesses and MAST forests are served from the caches
+    let data_store = CachingDataStore::new(LazyDataStore::new(&tx_context, true));
+    let executed_transaction = execute(&data_store)?;
+    let inner = data_store.inner();
+    let num_mast_lookups = inner.num_mast_lookups.load(Ordering::Relaxed);
+    assert_eq!(inner.num_transaction_inputs.load(Ordering::Relaxed), 1);
+    assert_eq!(inner.num_map_witnesses.load(Ordering::Relaxed), 1);
+
+    let cached_transaction = execute(&data_store)?;
+    assert_eq!(cached_transaction.id(), executed_transaction.id());
+    assert_eq!(inner.num_transaction_inputs.load(Ordering::Relaxed), 2);
+    assert_eq!(inner.num_map_witnesses.load(Ordering::Relaxed), 1);
+    assert_eq!(inner.num_mast_lookups.load(Ordering::Relaxed), num_mast_lookups);
+
+    // with account caching enabled, the second execution is served entirely from the caches
+    let data_store =
+        CachingDataStore::new(LazyDataStore::new(&tx_context, true)).with_account_caching(1);
+    execute(&data_store)?;
+    let inner = data_store.inner();
+    let num_mast_lookups = inner.num_mast_lookups.load(Ordering::Relaxed);
+    assert_eq!(inner.num_transaction_inputs.load(Ordering::Relaxed), 1);
+
+    let cached_transaction = execute(&data_store)?;
+    assert_eq!(cached_transaction.id(), executed_transaction.id());
+    assert_eq!(inner.num_transaction_inputs.load(Ordering::Relaxed), 1);
+    assert_eq!(inner.num_map_witnesses.load(Ordering::Relaxed), 1);
+    assert_eq!(inner.num_mast_lookups.load(Ordering::Relaxed), num_mast_lookups);
+
+    // a new block invalidates the account state, but not the witnesses
+    data_store.on_new_block();
+    execute(&data_store)?;
+    assert_eq!(inner.num_transaction_inputs.load(Ordering::Relaxed), 2);
+    assert_eq!(inner.num_map_witnesses.load(Ordering::Relaxed), 1);
+
+    Ok(())
+}
+
+/// Tests that a [CachingDataStore] caches the headers and authentication paths of blocks
+/// individually and without account caching, such that transactions consuming notes from
+/// overlapping sets of blocks only request the blocks which are not cached yet.
+#[test]
+fn caching_data_store_caches_blocks_individually() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER)?;
+    let mut note_ids = Vec::new();
+    for amount in [10, 20, 30] {
+        let note = mock_chain.add_pending_p2id_note(
+            sender,
+            account.id(),
+            &[FungibleAsset::mock(amount)],
+            NoteType::Public,
+        )?;
+        note_ids.push(note.id());
+        mock_chain.prove_next_block()?;
+    }
+    mock_chain.prove_next_block()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &note_ids, &[])?.build()?;
+    let input_notes: Vec<_> = tx_context.input_notes().iter().cloned().collect();
+    let note_blocks: Vec<_> = input_notes
+        .iter()
+        .map(|note| note.location().expect("note should be authenticated").block_num())
+        .collect();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let authenticator =
+        tx_context.authenticator().map(|auth| auth as &dyn TransactionAuthenticator);
+    let execute = |data_store: &dyn DataStore, notes: &[usize]| -> anyhow::Result<()> {
+        let notes = InputNotes::new(notes.iter().map(|&idx| input_notes[idx].clone()).collect())?;
+        TransactionExecutor::new(data_store, authenticator).execute_transaction(
+            account.id(),
+            block_ref,
+            notes,
+            tx_context.tx_args().clone(),
+            tx_context.source_manager(),
+        )?;
+        Ok(())
+    };
+
+    let data_store = CachingDataStore::new(LazyDataStore::new(&tx_context, true));
+    let inner = data_store.inner();
+    let last_ref_blocks = || inner.last_ref_blocks.lock().unwrap().clone();
+
+    execute(&data_store, &[0, 1])?;
+    assert_eq!(last_ref_blocks(), BTreeSet::from([note_blocks[0], note_blocks[1], block_ref]));
+
+    // the block of the second note is served from the cache
+    execute(&data_store, &[1, 2])?;
+    assert_eq!(last_ref_blocks(), BTreeSet::from([note_blocks[2], block_ref]));
+
+    // all blocks are cached, so only the reference block is requested together with the account
+    execute(&data_store, &[0, 1, 2])?;
+    assert_eq!(last_ref_blocks(), BTreeSet::from([block_ref]));
+    assert_eq!(inner.num_transaction_inputs.load(Ordering::Relaxed), 3);
+
+    // a new block invalidates the cached block data
+    data_store.on_new_block();
+    execute(&data_store, &[0, 2])?;
+    assert_eq!(last_ref_blocks(), BTreeSet::from([note_blocks[0], note_blocks[2], block_ref]));
+
+    Ok(())
+}
+
 // TEST TRANSACTION SCRIPT
 // ================================================================================================
 
@@ -844,6 +1666,58 @@ fn test_tx_script_inputs() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that the witness of an executed transaction contains only the advice data which cannot be
+/// re-created from the transaction inputs, and that it is sufficient for proving the transaction.
+#[test]
+fn transaction_witness_is_minimal() -> anyhow::Result<()> {
+    let read_key = [Felt::new(9999), Felt::new(8888), Felt::new(9999), Felt::new(8888)];
+    let read_value = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+    let unread_key = [Felt::new(7777), Felt::new(6666), Felt::new(7777), Felt::new(6666)];
+    let tx_script_src = format!(
+        "
+        begin
+            push.{key} adv.push_mapval adv_loadw
+            push.{value} assert_eqw
+        end
+        ",
+        key = word_to_masm_push_string(&read_key),
+        value = word_to_masm_push_string(&read_value)
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, TransactionKernel::testing_assembler())?;
+
+    let tx_context = TransactionContextBuilder::with_existing_mock_account()
+        .tx_script(tx_script)
+        .extend_advice_map([
+            (read_key, read_value.to_vec()),
+            (unread_key, vec![Felt::new(1); 1000]),
+        ])
+        .build()?;
+    let executed_transaction = tx_context.execute()?;
+    let executed_transaction_id = executed_transaction.id();
+
+    // the advice data prepared from the transaction inputs is not part of the witness
+    let advice_witness = executed_transaction.advice_witness();
+    assert!(advice_witness.stack().is_empty());
+    let account_code_commitment = executed_transaction.initial_account().code().commitment();
+    assert!(advice_witness.mapped_values(&account_code_commitment).is_none());
+
+    // of the user-supplied advice data, only the entries which were read are retained
+    let tx_witness = TransactionWitness::from(executed_transaction);
+    assert_eq!(tx_witness.advice_witness.mapped_values(&read_key.into()), Some(&read_value[..]));
+    assert!(tx_witness.advice_witness.mapped_values(&unread_key.into()).is_none());
+    assert!(tx_witness.tx_args.advice_inputs().mapped_values(&read_key.into()).is_none());
+
+    let size = tx_witness.size_breakdown();
+    assert_eq!(size.total(), tx_witness.to_bytes().len());
+
+    let proven_transaction = LocalTransactionProver::default().prove(tx_witness)?;
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+    TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL).verify(&proven_transaction)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_tx_script_args() -> anyhow::Result<()> {
     let tx_script_arg = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
@@ -1027,6 +1901,81 @@ fn test_execute_program() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[allow(clippy::arc_with_non_send_sync)]
+#[test]
+fn test_execute_view_call() -> anyhow::Result<()> {
+    let advice_key: Word = [Felt::new(21), Felt::new(22), Felt::new(23), Felt::new(24)];
+
+    let source = format!(
+        "
+    use.std::sys
+
+    begin
+        # write two words to memory and insert them into the advice map
+        push.5.6.7.8 mem_storew.1000 dropw
+        push.9.10.11.12 mem_storew.1004 dropw
+        push.1008.1000 push.{advice_key} adv.insert_mem dropw drop drop
+
+        # return a u64 and a word on the stack
+        push.99 push.1.2.3.4
+        exec.sys::truncate_stack
+    end
+    ",
+        advice_key = word_to_masm_push_string(&advice_key)
+    );
+
+    let tx_script = TransactionScript::compile(source, TransactionKernel::testing_assembler())?;
+
+    let tx_context = TransactionContextBuilder::with_existing_mock_account().build()?;
+    let account = tx_context.account().clone();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let memory_range =
+        NATIVE_ACCT_ID_AND_NONCE_PTR..NATIVE_ACCT_ID_AND_NONCE_PTR + ACCT_NONCE_IDX as u32 + 1;
+    let view_call = ViewCall::new(tx_script)
+        .with_advice_map_entry(advice_key.into())
+        .with_memory_range(memory_range.clone());
+
+    let executor = TransactionExecutor::new(&tx_context, None);
+    let output = executor.execute_view_call(
+        account.id(),
+        block_ref,
+        view_call,
+        tx_context.source_manager(),
+    )?;
+
+    assert_eq!(
+        output.decode_stack(&[ViewValueType::Word, ViewValueType::U64])?,
+        [
+            ViewValue::Word([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]),
+            ViewValue::U64(99)
+        ]
+    );
+
+    assert_eq!(
+        output.decode_advice_map_entry(&advice_key.into(), &[ViewValueType::Words(2)])?,
+        [ViewValue::Words(vec![
+            [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)],
+            [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)],
+        ])]
+    );
+
+    let account_data = output.decode_memory_range(
+        &memory_range,
+        &[ViewValueType::AccountId, ViewValueType::Felt, ViewValueType::U64],
+    )?;
+    assert_eq!(account_data[0], ViewValue::AccountId(account.id()));
+    assert_eq!(account_data[2], ViewValue::U64(account.nonce().as_int()));
+
+    // requesting more values than available results in an error
+    assert_matches!(
+        output.decode_memory_range(&memory_range, &[ViewValueType::Words(2)]),
+        Err(ViewCallDecodeError::NotEnoughElements { expected: 8, remaining: 4 })
+    );
+
+    Ok(())
+}
+
 #[allow(clippy::arc_with_non_send_sync)]
 #[test]
 fn test_check_note_consumability() -> anyhow::Result<()> {
@@ -1183,9 +2132,10 @@ fn test_check_note_consumability() -> anyhow::Result<()> {
         error: Some(e)} => {
             assert_eq!(failed_note_id, failing_note_2.id());
             assert_eq!(successful_notes, [successful_note_2.id(),successful_note_1.id()].to_vec());
-            assert_matches!(e, TransactionExecutorError::TransactionProgramExecutionFailed(
-              ExecutionError::DivideByZero { .. }
-            ));
+            assert_matches!(e, TransactionExecutorError::TransactionProgramExecutionFailed(failure) => {
+                assert_eq!(failure.note_id(), Some(failing_note_2.id()));
+                assert_matches!(failure.error(), ExecutionError::DivideByZero { .. });
+            });
         }
     );
     Ok(())
@@ -849,7 +849,7 @@ fn create_procedure_metadata_test_account(
             panic!("should have received an execution error");
         };
 
-        exec_err
+        exec_err.into_error()
     });
 
     Ok(result)
@@ -2,13 +2,21 @@ use alloc::vec::Vec;
 use std::collections::BTreeMap;
 
 use anyhow::Context;
-use miden_lib::transaction::TransactionKernel;
+use assert_matches::assert_matches;
+use miden_lib::{
+    errors::tx_kernel_errors::{
+        ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE, ERR_ACCOUNT_PROC_NOT_AUTH_PROC,
+        ERR_ACCOUNT_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS,
+    },
+    transaction::TransactionKernel,
+};
 use miden_objects::{
     Digest, EMPTY_WORD, Felt, Word, ZERO,
     account::{
-        AccountBuilder, AccountId, AccountStorageMode, AccountType, StorageMap, StorageSlot,
-        delta::LexicographicWord,
+        Account, AccountBuilder, AccountCode, AccountComponent, AccountId, AccountStorageMode,
+        AccountType, StorageMap, StorageSlot, delta::LexicographicWord,
     },
+    assembly::diagnostics::NamedSource,
     asset::{Asset, FungibleAsset},
     note::{Note, NoteType},
     testing::{
@@ -18,10 +26,13 @@ use miden_objects::{
     },
     transaction::{OutputNote, TransactionScript},
 };
-use miden_tx::utils::word_to_masm_push_string;
+use miden_tx::{
+    LocalTransactionProver, TransactionExecutorError, TransactionProver,
+    utils::word_to_masm_push_string,
+};
 use rand::Rng;
 
-use crate::{Auth, MockChain, utils::create_p2any_note};
+use crate::{Auth, MockChain, TransactionContextBuilder, utils::create_p2any_note};
 
 // ACCOUNT DELTA TESTS
 //
@@ -556,6 +567,122 @@ fn non_fungible_asset_delta() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that setting new code on an account with updatable code results in a delta containing
+/// the new code, and that applying the delta to the initial account results in the final account.
+#[test]
+fn code_delta() -> anyhow::Result<()> {
+    let new_code = AccountBuilder::new([5; 32])
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(
+            AccountMockComponent::new_with_slots(TransactionKernel::testing_assembler(), vec![])
+                .unwrap(),
+        )
+        .build_existing()?
+        .code()
+        .clone();
+    let account =
+        setup_code_test(AccountType::RegularAccountUpdatableCode, [4; 32], new_code.commitment())?;
+
+    let executed_tx = TransactionContextBuilder::new(account.clone())
+        .new_account_code(new_code.clone())
+        .build()?
+        .execute()
+        .context("failed to execute transaction")?;
+
+    assert_eq!(executed_tx.account_delta().code(), Some(&new_code));
+    assert_eq!(executed_tx.final_account().code_commitment(), new_code.commitment());
+
+    let mut final_account = account.clone();
+    final_account.apply_delta(executed_tx.account_delta())?;
+    assert_eq!(final_account.commitment(), executed_tx.final_account().commitment());
+
+    // the new code is carried over to the prover through the transaction arguments
+    let proven_tx = LocalTransactionProver::default().prove(executed_tx.into())?;
+    assert_eq!(proven_tx.account_update().final_state_commitment(), final_account.commitment());
+
+    // the transaction fails if the new code is not provided to the transaction
+    let result = TransactionContextBuilder::new(account).build()?.execute();
+    assert_matches!(result, Err(TransactionExecutorError::TransactionProgramExecutionFailed(_)));
+
+    Ok(())
+}
+
+/// Tests that setting new code on an account with immutable code fails.
+#[test]
+fn code_delta_for_immutable_account_fails() -> anyhow::Result<()> {
+    let account = setup_code_test(
+        AccountType::RegularAccountImmutableCode,
+        [6; 32],
+        AccountCode::mock().commitment(),
+    )?;
+
+    let result = TransactionContextBuilder::new(account)
+        .new_account_code(AccountCode::mock())
+        .build()?
+        .execute();
+    assert_matches!(result, Err(TransactionExecutorError::TransactionProgramExecutionFailed(failure)) => {
+        assert_eq!(failure.error_code(), Some(ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE.code()));
+    });
+
+    Ok(())
+}
+
+/// Tests that setting new code whose procedures access more storage slots than the account has
+/// fails, since the account would be rejected by the prologue of every subsequent transaction.
+#[test]
+fn code_delta_with_out_of_bounds_storage_access_fails() -> anyhow::Result<()> {
+    // the procedures of the mock component access two storage slots
+    let new_code = AccountBuilder::new([5; 32])
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(
+            AccountMockComponent::new_with_slots(
+                TransactionKernel::testing_assembler(),
+                vec![StorageSlot::empty_value(), StorageSlot::empty_value()],
+            )
+            .unwrap(),
+        )
+        .build_existing()?
+        .code()
+        .clone();
+    // the account only has the storage slot of its authentication component
+    let account =
+        setup_code_test(AccountType::RegularAccountUpdatableCode, [8; 32], new_code.commitment())?;
+    assert_eq!(account.storage().slots().len(), 1);
+
+    let result = TransactionContextBuilder::new(account)
+        .new_account_code(new_code)
+        .build()?
+        .execute();
+    assert_matches!(result, Err(TransactionExecutorError::TransactionProgramExecutionFailed(failure)) => {
+        assert_eq!(failure.error_code(), Some(ERR_ACCOUNT_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS.code()));
+    });
+
+    Ok(())
+}
+
+/// Tests that setting new code from a procedure other than the authentication procedure of the
+/// account fails.
+#[test]
+fn code_delta_outside_of_auth_procedure_fails() -> anyhow::Result<()> {
+    let account = setup_code_test(
+        AccountType::RegularAccountUpdatableCode,
+        [7; 32],
+        AccountCode::mock().commitment(),
+    )?;
+    let tx_script = compile_code_update_script(&AccountCode::mock())?;
+
+    let result = TransactionContextBuilder::new(account)
+        .tx_script(tx_script)
+        .new_account_code(AccountCode::mock())
+        .build()?
+        .execute();
+    assert_matches!(result, Err(TransactionExecutorError::TransactionProgramExecutionFailed(failure)) => {
+        assert_eq!(failure.error_code(), Some(ERR_ACCOUNT_PROC_NOT_AUTH_PROC.code()));
+    });
+
+    Ok(())
+}
+
 // TEST HELPERS
 // ================================================================================================
 
@@ -602,6 +729,71 @@ fn setup_asset_test(assets: impl IntoIterator<Item = Asset>) -> TestSetup {
     TestSetup { mock_chain, account_id }
 }
 
+/// Creates an account whose authentication procedure sets the code with the provided commitment.
+fn setup_code_test(
+    account_type: AccountType,
+    seed: [u8; 32],
+    new_code_commitment: Digest,
+) -> anyhow::Result<Account> {
+    let account = AccountBuilder::new(seed)
+        .account_type(account_type)
+        .storage_mode(AccountStorageMode::Public)
+        .with_auth_component(code_updater_auth_component(new_code_commitment)?)
+        .with_component(code_updater_component()?)
+        .build_existing()?;
+
+    Ok(account)
+}
+
+fn code_updater_auth_component(new_code_commitment: Digest) -> anyhow::Result<AccountComponent> {
+    let component = AccountComponent::compile(
+        CODE_UPDATER_AUTH_COMPONENT_CODE,
+        TransactionKernel::testing_assembler(),
+        vec![StorageSlot::Value(new_code_commitment.into())],
+    )?
+    .with_supports_all_types();
+
+    Ok(component)
+}
+
+fn code_updater_component() -> anyhow::Result<AccountComponent> {
+    let component = AccountComponent::compile(
+        NamedSource::new("code_updater::code_updater", CODE_UPDATER_COMPONENT_CODE),
+        TransactionKernel::testing_assembler(),
+        vec![],
+    )?
+    .with_supports_all_types();
+
+    Ok(component)
+}
+
+fn compile_code_update_script(new_code: &AccountCode) -> anyhow::Result<TransactionScript> {
+    let code = format!(
+        "
+      use.code_updater::code_updater
+
+      begin
+          padw padw padw push.{new_code_commitment}
+          # => [CODE_COMMITMENT, pad(12)]
+
+          call.code_updater::set_code
+          # => [pad(16)]
+
+          dropw dropw dropw dropw
+      end
+      ",
+        new_code_commitment = word_to_masm_push_string(&new_code.commitment().into()),
+    );
+
+    TransactionScript::compile(
+        code,
+        TransactionKernel::testing_assembler()
+            .with_library(code_updater_component()?.library())
+            .unwrap(),
+    )
+    .context("failed to compile tx script")
+}
+
 fn compile_tx_script(code: impl AsRef<str>) -> anyhow::Result<TransactionScript> {
     let code = format!(
         "
@@ -722,3 +914,33 @@ const TEST_ACCOUNT_CONVENIENCE_WRAPPERS: &str = "
           # => [ASSET]
       end
 ";
+
+const CODE_UPDATER_COMPONENT_CODE: &str = "
+      use.miden::account
+
+      #! Inputs:  [CODE_COMMITMENT, pad(12)]
+      #! Outputs: [pad(16)]
+      export.set_code
+          exec.account::set_code
+          # => [pad(16)]
+      end
+";
+
+const CODE_UPDATER_AUTH_COMPONENT_CODE: &str = "
+      use.miden::account
+
+      #! Sets the code with the commitment stored in slot 0 and increments the nonce.
+      #!
+      #! Inputs:  [pad(16)]
+      #! Outputs: [pad(16)]
+      export.auth__set_code
+          push.0 exec.account::get_item
+          # => [CODE_COMMITMENT, pad(16)]
+
+          exec.account::set_code
+          # => [pad(16)]
+
+          push.1 exec.account::incr_nonce
+          # => [pad(16)]
+      end
+";
@@ -1,23 +1,28 @@
 use alloc::{string::ToString, vec::Vec};
 
 use miden_lib::{
+    account::faucets::BasicFungibleFaucet,
     errors::tx_kernel_errors::{
         ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE,
         ERR_EPILOGUE_EXECUTED_TRANSACTION_IS_EMPTY,
+        ERR_EPILOGUE_INSUFFICIENT_NATIVE_ASSET_BALANCE_TO_PAY_FEE,
         ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME, ERR_TX_INVALID_EXPIRATION_DELTA,
     },
     transaction::{
         TransactionKernel,
-        memory::{NOTE_MEM_SIZE, OUTPUT_NOTE_ASSET_COMMITMENT_OFFSET, OUTPUT_NOTE_SECTION_OFFSET},
+        memory::{
+            self, NOTE_MEM_SIZE, OUTPUT_NOTE_ASSET_COMMITMENT_OFFSET, OUTPUT_NOTE_SECTION_OFFSET,
+        },
     },
 };
 use miden_objects::{
-    FieldElement,
+    FieldElement, MIN_PROOF_SECURITY_LEVEL, Word,
     account::{
         Account, AccountBuilder, AccountComponent, AccountDelta, AccountStorageDelta,
-        AccountStorageMode, AccountVaultDelta,
+        AccountStorageMode, AccountType, AccountVaultDelta,
     },
-    asset::{Asset, AssetVault, FungibleAsset},
+    asset::{Asset, AssetVault, FungibleAsset, TokenSymbol},
+    block::{ESTIMATED_EPILOGUE_CYCLES, FeeParameters},
     note::{NoteTag, NoteType},
     testing::{
         account_component::{AccountMockComponent, IncrNonceAuthComponent},
@@ -29,9 +34,11 @@ use miden_objects::{
         constants::{CONSUMED_ASSET_1_AMOUNT, CONSUMED_ASSET_2_AMOUNT, CONSUMED_ASSET_3_AMOUNT},
         note::NoteBuilder,
     },
-    transaction::{OutputNote, OutputNotes},
+    transaction::{OutputNote, OutputNotes, TransactionHeader},
+};
+use miden_tx::{
+    LocalTransactionProver, TransactionExecutorError, TransactionProver, TransactionVerifier,
 };
-use miden_tx::TransactionExecutorError;
 use rand::rng;
 use vm_processor::{Felt, ONE, ProcessState};
 
@@ -83,7 +90,7 @@ fn test_epilogue() -> anyhow::Result<()> {
             exec.epilogue::finalize_transaction
 
             # truncate the stack
-            movupw.3 dropw movupw.3 dropw movup.9 drop
+            repeat.13 movup.13 drop end
         end
         "
     );
@@ -123,11 +130,16 @@ fn test_epilogue() -> anyhow::Result<()> {
     let account_update_commitment =
         miden_objects::Hasher::merge(&[final_account.commitment(), account_delta_commitment]);
 
+    // the mock block header does not charge fees, so the fee asset has an amount of zero
+    let native_asset_id = tx_context.tx_inputs().block_header().fee_parameters().native_asset_id();
+    let fee = FungibleAsset::new(native_asset_id, 0)?;
+
     let mut expected_stack = Vec::with_capacity(17);
     expected_stack.extend(output_notes.commitment().as_elements().iter().rev());
     expected_stack.extend(account_update_commitment.as_elements().iter().rev());
+    expected_stack.extend(Word::from(fee).iter().rev());
     expected_stack.push(Felt::from(u32::MAX)); // Value for tx expiration block number
-    expected_stack.extend((9..16).map(|_| ZERO));
+    expected_stack.extend((13..16).map(|_| ZERO));
 
     assert_eq!(
         *process.stack.build_stack_outputs()?,
@@ -182,7 +194,7 @@ fn test_compute_output_note_id() -> anyhow::Result<()> {
                 exec.epilogue::finalize_transaction
 
                 # truncate the stack
-                movupw.3 dropw movupw.3 dropw movup.9 drop
+                repeat.13 movup.13 drop end
             end
             "
         );
@@ -271,7 +283,7 @@ fn test_epilogue_asset_preservation_violation_too_few_input() -> anyhow::Result<
             exec.epilogue::finalize_transaction
             
             # truncate the stack
-            movupw.3 dropw movupw.3 dropw movup.9 drop
+            repeat.13 movup.13 drop end
         end
         "
     );
@@ -354,7 +366,7 @@ fn test_epilogue_asset_preservation_violation_too_many_fungible_input() -> anyho
             exec.epilogue::finalize_transaction
                         
             # truncate the stack
-            movupw.3 dropw movupw.3 dropw movup.9 drop
+            repeat.13 movup.13 drop end
         end
         "
     );
@@ -391,7 +403,7 @@ fn test_block_expiration_height_monotonically_decreases() -> anyhow::Result<()>
             exec.epilogue::finalize_transaction
                         
             # truncate the stack
-            movupw.3 dropw movupw.3 dropw movup.9 drop
+            repeat.13 movup.13 drop end
         end
         ";
 
@@ -411,7 +423,7 @@ fn test_block_expiration_height_monotonically_decreases() -> anyhow::Result<()>
         // (which can only decrease, not increase)
         let expected_expiry =
             v1.min(v2) + tx_context.tx_inputs().block_header().block_num().as_u64();
-        assert_eq!(process_state.get_stack_item(8).as_int(), expected_expiry);
+        assert_eq!(process_state.get_stack_item(12).as_int(), expected_expiry);
     }
 
     Ok(())
@@ -462,7 +474,7 @@ fn test_no_expiration_delta_set() -> anyhow::Result<()> {
         exec.epilogue::finalize_transaction
                     
         # truncate the stack
-        movupw.3 dropw movupw.3 dropw movup.9 drop
+        repeat.13 movup.13 drop end
     end
     ";
 
@@ -473,7 +485,7 @@ fn test_no_expiration_delta_set() -> anyhow::Result<()> {
     let process_state: ProcessState = process.into();
 
     // Default value should be equal to u32::max, set in the prologue
-    assert_eq!(process_state.get_stack_item(8).as_int() as u32, u32::MAX);
+    assert_eq!(process_state.get_stack_item(12).as_int() as u32, u32::MAX);
 
     Ok(())
 }
@@ -553,7 +565,7 @@ fn test_epilogue_increment_nonce_violation() -> anyhow::Result<()> {
             exec.epilogue::finalize_transaction
 
             # truncate the stack
-            movupw.3 dropw movupw.3 dropw movup.9 drop
+            repeat.13 movup.13 drop end
         end
         "
     );
@@ -576,7 +588,10 @@ fn test_epilogue_execute_empty_transaction() -> anyhow::Result<()> {
         panic!("unexpected error")
     };
 
-    assert_execution_error!(Err::<(), _>(err), ERR_EPILOGUE_EXECUTED_TRANSACTION_IS_EMPTY);
+    assert_execution_error!(
+        Err::<(), _>(err.into_error()),
+        ERR_EPILOGUE_EXECUTED_TRANSACTION_IS_EMPTY
+    );
 
     Ok(())
 }
@@ -638,3 +653,136 @@ fn test_epilogue_empty_transaction_with_empty_output_note() -> anyhow::Result<()
 
     Ok(())
 }
+
+#[test]
+fn test_epilogue_charges_fee() -> anyhow::Result<()> {
+    let fee_parameters = FeeParameters::new(FungibleAsset::mock_issuer(), 10)?;
+    let initial_balance = 1_000;
+
+    let mock_component =
+        AccountMockComponent::new_with_empty_slots(TransactionKernel::testing_assembler())?;
+    let account = AccountBuilder::new(Default::default())
+        .with_assets([FungibleAsset::mock(initial_balance)])
+        .storage_mode(AccountStorageMode::Public)
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(mock_component)
+        .build_existing()?;
+
+    let mock_chain = MockChain::with_accounts_and_fee_parameters(
+        core::slice::from_ref(&account),
+        fee_parameters,
+    )?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(TxContextInput::AccountId(account.id()), &[], &[])?
+        .build()?
+        .execute()?;
+
+    // the fee is paid in the native asset and is a multiple of the verification base fee
+    let fee = executed_transaction.fee();
+    assert_eq!(fee.faucet_id(), fee_parameters.native_asset_id());
+    assert!(fee.amount() > 0);
+    assert_eq!(fee.amount() % fee_parameters.verification_base_fee() as u64, 0);
+
+    // the fee covers at least the estimated cycles of the epilogue executed after the fee is
+    // computed
+    assert!(fee.amount() >= fee_parameters.compute_fee(ESTIMATED_EPILOGUE_CYCLES).amount());
+
+    // the fee is removed from the account vault as part of the account delta
+    assert_eq!(
+        executed_transaction
+            .account_delta()
+            .vault()
+            .fungible()
+            .amount(&fee_parameters.native_asset_id()),
+        Some(-(fee.amount() as i64))
+    );
+
+    // the fee is committed to by the proof and exposed on the transaction header
+    let proven_transaction =
+        LocalTransactionProver::default().prove(executed_transaction.into())?;
+    TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL).verify(&proven_transaction)?;
+    assert_eq!(proven_transaction.fee(), fee);
+    assert_eq!(TransactionHeader::from(&proven_transaction).fee(), fee);
+
+    Ok(())
+}
+
+/// Tests that the faucet issuing the native asset, which does not hold the native asset in its
+/// vault, pays the fee by minting it.
+#[test]
+fn test_epilogue_native_asset_faucet_mints_fee() -> anyhow::Result<()> {
+    let basic_faucet = BasicFungibleFaucet::new(TokenSymbol::new("FEE")?, 10, Felt::new(1_000))?;
+    let faucet = AccountBuilder::new([4; 32])
+        .storage_mode(AccountStorageMode::Public)
+        .account_type(AccountType::FungibleFaucet)
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(basic_faucet)
+        .build_existing()?;
+    let fee_parameters = FeeParameters::new(faucet.id(), 10)?;
+
+    let mock_chain = MockChain::with_accounts_and_fee_parameters(
+        core::slice::from_ref(&faucet),
+        fee_parameters,
+    )?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(TxContextInput::AccountId(faucet.id()), &[], &[])?
+        .build()?
+        .execute()?;
+
+    let fee = executed_transaction.fee();
+    assert_eq!(fee.faucet_id(), faucet.id());
+    assert!(fee.amount() > 0);
+
+    // the fee is minted, i.e. the total issuance of the faucet grows by the fee amount while its
+    // vault is left untouched
+    let account_delta = executed_transaction.account_delta();
+    assert!(account_delta.vault().is_empty());
+    assert_eq!(
+        account_delta.storage().values().get(&memory::FAUCET_STORAGE_DATA_SLOT),
+        Some(&[ZERO, ZERO, ZERO, Felt::new(fee.amount())])
+    );
+
+    let proven_transaction =
+        LocalTransactionProver::default().prove(executed_transaction.into())?;
+    TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL).verify(&proven_transaction)?;
+    assert_eq!(proven_transaction.fee(), fee);
+
+    Ok(())
+}
+
+#[test]
+fn test_epilogue_fee_exceeds_native_asset_balance() -> anyhow::Result<()> {
+    let fee_parameters = FeeParameters::new(FungibleAsset::mock_issuer(), 10)?;
+
+    let mock_component =
+        AccountMockComponent::new_with_empty_slots(TransactionKernel::testing_assembler())?;
+    let account = AccountBuilder::new(Default::default())
+        .with_assets([FungibleAsset::mock(1)])
+        .storage_mode(AccountStorageMode::Public)
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(mock_component)
+        .build_existing()?;
+
+    let mock_chain = MockChain::with_accounts_and_fee_parameters(
+        core::slice::from_ref(&account),
+        fee_parameters,
+    )?;
+
+    let err = mock_chain
+        .build_tx_context(TxContextInput::AccountId(account.id()), &[], &[])?
+        .build()?
+        .execute()
+        .expect_err("expected the fee payment to fail");
+    let TransactionExecutorError::TransactionProgramExecutionFailed(err) = err else {
+        panic!("unexpected error")
+    };
+
+    assert_execution_error!(
+        Err::<(), _>(err.into_error()),
+        ERR_EPILOGUE_INSUFFICIENT_NATIVE_ASSET_BALANCE_TO_PAY_FEE
+    );
+
+    Ok(())
+}
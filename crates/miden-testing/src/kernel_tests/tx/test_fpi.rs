@@ -1,5 +1,7 @@
-use alloc::{vec, vec::Vec};
+use alloc::{collections::BTreeSet, sync::Arc, vec, vec::Vec};
+use core::sync::atomic::{AtomicUsize, Ordering};
 
+use assert_matches::assert_matches;
 use miden_lib::{
     errors::tx_kernel_errors::{
         ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT, ERR_FOREIGN_ACCOUNT_INVALID_COMMITMENT,
@@ -16,22 +18,26 @@ use miden_lib::{
     },
 };
 use miden_objects::{
-    FieldElement,
+    Digest, FieldElement, MIN_PROOF_SECURITY_LEVEL,
     account::{
-        Account, AccountBuilder, AccountComponent, AccountProcedureInfo, AccountStorage,
+        Account, AccountBuilder, AccountComponent, AccountId, AccountProcedureInfo, AccountStorage,
         AccountStorageMode, PartialAccount, StorageSlot,
     },
+    block::{BlockHeader, BlockNumber},
     testing::{account_component::AccountMockComponent, storage::STORAGE_LEAVES_2},
-    transaction::{AccountInputs, TransactionScript},
+    transaction::{AccountInputs, PartialBlockchain, TransactionScript},
+};
+use miden_tx::{
+    DataStore, DataStoreError, LocalTransactionProver, TransactionExecutor,
+    TransactionExecutorError, TransactionMastStore, TransactionProver, TransactionVerifier,
 };
-use miden_tx::TransactionExecutorError;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
-use vm_processor::{AdviceInputs, Felt};
+use vm_processor::{AdviceInputs, Felt, MastForest, MastForestStore};
 
 use super::{Process, Word, ZERO};
 use crate::{
-    Auth, MockChain, assert_execution_error,
+    Auth, MockChain, TransactionContext, assert_execution_error,
     kernel_tests::tx::{read_root_mem_word, try_read_root_mem_word},
 };
 
@@ -643,6 +649,272 @@ fn test_fpi_execute_foreign_procedure() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that the cycles spent executing procedures of a foreign account are attributed to that
+/// account, and that the storage reads of the foreign procedures are counted.
+#[test]
+fn test_fpi_measurements() -> anyhow::Result<()> {
+    let foreign_account_code_source = "
+        use.miden::account
+
+        export.get_item_foreign
+            # make this foreign procedure unique to make sure that we invoke the procedure of the
+            # foreign account, not the native one
+            push.1 drop
+            exec.account::get_item
+
+            # truncate the stack
+            movup.6 movup.6 movup.6 drop drop drop
+        end
+    ";
+    let foreign_account_component = AccountComponent::compile(
+        foreign_account_code_source,
+        TransactionKernel::testing_assembler(),
+        vec![AccountStorage::mock_item_0().slot],
+    )?
+    .with_supports_all_types();
+
+    let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(foreign_account_component)
+        .build_existing()?;
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(AccountMockComponent::new_with_slots(
+            TransactionKernel::testing_assembler(),
+            vec![],
+        )?)
+        .storage_mode(AccountStorageMode::Public)
+        .build_existing()?;
+
+    let mut mock_chain =
+        MockChain::with_accounts(&[native_account.clone(), foreign_account.clone()])?;
+    mock_chain.prove_next_block()?;
+
+    // call the foreign procedure twice, reading the foreign storage item each time
+    let code = format!(
+        "
+        use.std::sys
+
+        use.miden::tx
+
+        begin
+            repeat.2
+                padw padw padw push.0.0 push.0
+                push.{get_item_foreign_hash}
+                push.{foreign_suffix}.{foreign_prefix}
+                exec.tx::execute_foreign_procedure
+                # => [STORAGE_VALUE]
+
+                push.1.2.3.4 assert_eqw
+            end
+
+            exec.sys::truncate_stack
+        end
+        ",
+        foreign_prefix = foreign_account.id().prefix().as_felt(),
+        foreign_suffix = foreign_account.id().suffix(),
+        get_item_foreign_hash = foreign_account.code().procedures()[1].mast_root(),
+    );
+    let tx_script = TransactionScript::compile(code, TransactionKernel::testing_assembler())?;
+
+    let foreign_account_inputs = mock_chain
+        .get_foreign_account_inputs(foreign_account.id())
+        .expect("failed to get foreign account inputs");
+    let executed_transaction = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])?
+        .foreign_accounts(vec![foreign_account_inputs])
+        .tx_script(tx_script)
+        .build()?
+        .execute()?;
+
+    // both calls are attributed to the single foreign account
+    let measurements = executed_transaction.measurements();
+    assert_eq!(measurements.foreign_account_execution.len(), 1);
+    assert!(measurements.foreign_account_execution[&foreign_account.id()] > 0);
+    assert_eq!(measurements.storage_reads, 2);
+    assert_eq!(measurements.storage_writes, 0);
+    assert_eq!(measurements.map_reads, 0);
+    assert_eq!(measurements.provided_signatures, 0);
+
+    Ok(())
+}
+
+/// A [DataStore] which provides the inputs of a foreign account on demand, instead of the inputs
+/// being passed to the transaction upfront.
+struct OnDemandForeignAccountStore<'a> {
+    tx_context: &'a TransactionContext,
+    foreign_account_inputs: AccountInputs,
+    mast_store: TransactionMastStore,
+    num_requests: AtomicUsize,
+}
+
+impl<'a> OnDemandForeignAccountStore<'a> {
+    fn new(tx_context: &'a TransactionContext, foreign_account_inputs: AccountInputs) -> Self {
+        let mast_store = TransactionMastStore::new();
+        mast_store.load_account_code(foreign_account_inputs.code());
+
+        Self {
+            tx_context,
+            foreign_account_inputs,
+            mast_store,
+            num_requests: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl DataStore for OnDemandForeignAccountStore<'_> {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        ref_blocks: BTreeSet<BlockNumber>,
+    ) -> Result<(PartialAccount, Option<Word>, BlockHeader, PartialBlockchain), DataStoreError>
+    {
+        self.tx_context.get_transaction_inputs(account_id, ref_blocks)
+    }
+
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+    ) -> Result<AccountInputs, DataStoreError> {
+        self.num_requests.fetch_add(1, Ordering::Relaxed);
+        if account_id != self.foreign_account_inputs.id() {
+            return Err(DataStoreError::ForeignAccountNotFound { account_id, block_num });
+        }
+
+        Ok(self.foreign_account_inputs.clone())
+    }
+}
+
+impl MastForestStore for OnDemandForeignAccountStore<'_> {
+    fn get(&self, procedure_hash: &Digest) -> Option<Arc<MastForest>> {
+        self.tx_context
+            .get(procedure_hash)
+            .or_else(|| self.mast_store.get(procedure_hash))
+    }
+}
+
+/// Tests that a foreign account which is not provided upfront is fetched from the data store when
+/// the transaction invokes one of its procedures, and that the resulting transaction can be proven.
+#[test]
+fn test_fpi_foreign_account_loaded_on_demand() -> anyhow::Result<()> {
+    let foreign_account_component = AccountComponent::compile(
+        "
+        use.miden::account
+
+        export.get_item_foreign
+            # make this foreign procedure unique to make sure that we invoke the procedure of the
+            # foreign account, not the native one
+            push.1 drop
+            exec.account::get_item
+
+            # truncate the stack
+            movup.6 movup.6 movup.6 drop drop drop
+        end
+        ",
+        TransactionKernel::testing_assembler(),
+        vec![AccountStorage::mock_item_0().slot],
+    )?
+    .with_supports_all_types();
+
+    let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(foreign_account_component)
+        .build_existing()?;
+
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(AccountMockComponent::new_with_slots(
+            TransactionKernel::testing_assembler(),
+            vec![],
+        )?)
+        .storage_mode(AccountStorageMode::Public)
+        .build_existing()?;
+
+    let mut mock_chain =
+        MockChain::with_accounts(&[native_account.clone(), foreign_account.clone()])?;
+    mock_chain.prove_next_block()?;
+
+    let code = format!(
+        "
+        use.std::sys
+
+        use.miden::tx
+
+        begin
+            # pad the stack for the `execute_foreign_procedure` execution
+            padw padw padw push.0.0
+            # => [pad(14)]
+
+            # push the index of desired storage item
+            push.0
+
+            # get the hash of the `get_item_foreign` account procedure
+            push.{get_item_foreign_hash}
+
+            # push the foreign account ID
+            push.{foreign_suffix}.{foreign_prefix}
+            # => [foreign_account_id_prefix, foreign_account_id_suffix, FOREIGN_PROC_ROOT, storage_item_index, pad(14)]
+
+            exec.tx::execute_foreign_procedure
+            # => [STORAGE_VALUE]
+
+            # assert the correctness of the obtained value
+            push.1.2.3.4 assert_eqw
+            # => []
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        foreign_prefix = foreign_account.id().prefix().as_felt(),
+        foreign_suffix = foreign_account.id().suffix(),
+        get_item_foreign_hash = foreign_account.code().procedures()[1].mast_root(),
+    );
+    let tx_script = TransactionScript::compile(code, TransactionKernel::testing_assembler())?;
+
+    let foreign_account_inputs = mock_chain.get_foreign_account_inputs(foreign_account.id())?;
+    let tx_context = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])?
+        .tx_script(tx_script)
+        .build()?;
+
+    let execute = |data_store: &dyn DataStore| {
+        TransactionExecutor::new(data_store, None).execute_transaction(
+            tx_context.account().id(),
+            tx_context.tx_inputs().block_header().block_num(),
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            tx_context.source_manager(),
+        )
+    };
+
+    // by default, foreign accounts must be provided upfront
+    assert_matches!(
+        execute(&tx_context),
+        Err(TransactionExecutorError::FetchAccountDataFailed(
+            DataStoreError::ForeignAccountNotFound { account_id, .. }
+        )) if account_id == foreign_account.id()
+    );
+
+    let data_store = OnDemandForeignAccountStore::new(&tx_context, foreign_account_inputs.clone());
+    let executed_transaction = execute(&data_store)?;
+    assert_eq!(data_store.num_requests.load(Ordering::Relaxed), 1);
+    assert_eq!(
+        executed_transaction.tx_args().foreign_account_inputs(),
+        [foreign_account_inputs]
+    );
+
+    // the fetched foreign account is part of the witness, so the prover does not need the store
+    let executed_transaction_id = executed_transaction.id();
+    let proven_transaction =
+        LocalTransactionProver::default().prove(executed_transaction.into())?;
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+    TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL).verify(&proven_transaction)?;
+
+    Ok(())
+}
+
 // NESTED FPI TESTS
 // ================================================================================================
 
@@ -1035,7 +1307,7 @@ fn test_nested_fpi_stack_overflow() {
                 panic!("unexpected error")
             };
 
-            assert_execution_error!(Err::<(), _>(err), ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED);
+            assert_execution_error!(Err::<(), _>(err.into_error()), ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED);
         })
         .expect("thread panic external")
         .join()
@@ -1154,7 +1426,10 @@ fn test_nested_fpi_native_account_invocation() -> anyhow::Result<()> {
         panic!("unexpected error: {err}")
     };
 
-    assert_execution_error!(Err::<(), _>(err), ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT);
+    assert_execution_error!(
+        Err::<(), _>(err.into_error()),
+        ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT
+    );
     Ok(())
 }
 
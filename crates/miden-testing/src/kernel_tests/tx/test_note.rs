@@ -912,7 +912,7 @@ pub fn test_timelock() -> anyhow::Result<()> {
     let TransactionExecutorError::TransactionProgramExecutionFailed(err) = err else {
         panic!("unexpected error")
     };
-    assert_execution_error!(Err::<(), _>(err), TIMESTAMP_ERROR);
+    assert_execution_error!(Err::<(), _>(err.into_error()), TIMESTAMP_ERROR);
 
     // Consume note where lock timestamp matches the block timestamp.
     // ----------------------------------------------------------------------------------------
@@ -1,4 +1,9 @@
 use alloc::{string::String, vec::Vec};
+use core::time::Duration;
+use std::{
+    sync::{Arc, Mutex},
+    time::Instant,
+};
 
 use anyhow::Context;
 use miden_lib::{
@@ -15,8 +20,8 @@ use miden_lib::{
     utils::word_to_masm_push_string,
 };
 use miden_objects::{
-    FieldElement,
-    account::{Account, AccountId},
+    FieldElement, MIN_TX_EXECUTION_CYCLES,
+    account::{Account, AccountDelta, AccountId},
     asset::{FungibleAsset, NonFungibleAsset},
     block::BlockNumber,
     note::{
@@ -31,10 +36,18 @@ use miden_objects::{
             ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, ACCOUNT_ID_SENDER,
         },
         constants::NON_FUNGIBLE_ASSET_DATA_2,
+        note::NoteBuilder,
     },
-    transaction::{InputNotes, OutputNote, OutputNotes, TransactionArgs},
+    transaction::{InputNotes, OutputNote, OutputNotes, TransactionArgs, TransactionScript},
+};
+use miden_tx::{
+    AuthenticationError, CancellationToken, ExecutionOptions, InterruptReason,
+    NoteConsumptionChecker, SigningRequest, TraceSpanKind, TransactionExecution,
+    TransactionExecutor, TransactionExecutorError, TransactionTrace,
+    auth::TransactionAuthenticator,
+    utils::{Deserializable, Serializable},
 };
-use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use rand::rng;
 
 use super::{Felt, ONE, ProcessState, Word, ZERO};
 use crate::{
@@ -121,6 +134,528 @@ fn test_future_input_note_fails() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that simulating a transaction against an account with RpoFalcon512 authentication answers
+/// the signature request with a placeholder signature, executes the transaction to completion and
+/// reports the same effects as the actual execution of the transaction.
+#[test]
+fn test_simulate_transaction_without_authenticator() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    let asset = FungibleAsset::mock(100);
+    let note = mock_chain.add_pending_p2id_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        account.id(),
+        &[asset],
+        NoteType::Public,
+    )?;
+    mock_chain.prove_next_block()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &[note.id()], &[])?.build()?;
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tx_executor = TransactionExecutor::new(&tx_context, None);
+    let simulated_tx = tx_executor.simulate_transaction(
+        account.id(),
+        block_ref,
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    )?;
+
+    assert_eq!(simulated_tx.simulated_signatures().len(), 1);
+    assert!(!simulated_tx.is_provable());
+    assert_eq!(simulated_tx.account_id(), account.id());
+
+    let executed_tx = tx_context.execute()?;
+    assert_eq!(simulated_tx.account_delta(), executed_tx.account_delta());
+    assert_eq!(simulated_tx.output_notes(), executed_tx.output_notes());
+    assert_eq!(simulated_tx.fee(), executed_tx.fee());
+
+    // only the cycles of the signature verification are missing from the measurements
+    let simulated = simulated_tx.measurements();
+    let executed = executed_tx.measurements();
+    assert_eq!(simulated.prologue, executed.prologue);
+    assert_eq!(simulated.notes_processing, executed.notes_processing);
+    assert_eq!(simulated.provided_signatures, executed.provided_signatures);
+    assert!(simulated.epilogue < executed.epilogue);
+
+    assert!(simulated_tx.into_executed_transaction().is_none());
+
+    Ok(())
+}
+
+/// Tests that simulating a transaction against an account which does not verify signatures produces
+/// a provable transaction.
+#[test]
+fn test_simulate_transaction_without_signature_verification() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::IncrNonce, vec![]);
+    mock_chain.prove_next_block()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &[], &[])?.build()?;
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tx_executor = TransactionExecutor::new(&tx_context, None);
+    let simulated_tx = tx_executor.simulate_transaction(
+        account.id(),
+        block_ref,
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    )?;
+
+    assert!(simulated_tx.simulated_signatures().is_empty());
+    assert!(simulated_tx.is_provable());
+
+    let executed_tx = tx_context.execute()?;
+    let simulated_tx = simulated_tx
+        .into_executed_transaction()
+        .expect("transaction should be provable");
+    assert_eq!(simulated_tx.final_account(), executed_tx.final_account());
+    assert_eq!(simulated_tx.account_delta(), executed_tx.account_delta());
+
+    Ok(())
+}
+
+/// Tests that a transaction can be paused at a signature request, signed out of band and resumed to
+/// produce the same transaction as the one executed with an authenticator.
+#[test]
+fn test_execute_transaction_with_deferred_signature() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    // a custom note is used so that the consumability check has to execute the transaction
+    let note = create_p2any_note(ACCOUNT_ID_SENDER.try_into()?, &[FungibleAsset::mock(100)]);
+    mock_chain.add_pending_note(OutputNote::Full(note.clone()));
+    mock_chain.prove_next_block()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &[note.id()], &[])?.build()?;
+    let authenticator = tx_context.authenticator().expect("account should have an authenticator");
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tx_executor = TransactionExecutor::new(&tx_context, None);
+    let execution = tx_executor.execute_until_signature(
+        account.id(),
+        block_ref,
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    )?;
+    let TransactionExecution::SignatureRequired(request) = execution else {
+        anyhow::bail!("expected execution to be paused at the signature request");
+    };
+    assert_eq!(request.account_id(), account.id());
+    assert_eq!(request.account_delta().nonce_delta(), ONE);
+
+    // make sure the request survives a round trip through its serialized form
+    let request = SigningRequest::read_from_bytes(&request.to_bytes())?;
+
+    let signature = authenticator.get_signature(
+        request.pub_key(),
+        request.message(),
+        request.account_delta(),
+    )?;
+    let execution =
+        tx_executor.resume_transaction(request.clone(), signature, tx_context.source_manager())?;
+    let TransactionExecution::Executed(executed_tx) = execution else {
+        anyhow::bail!("expected transaction to be executed after providing the signature");
+    };
+
+    assert_eq!(executed_tx.account_delta(), request.account_delta());
+    assert_eq!(executed_tx.final_account().nonce(), account.nonce() + ONE);
+
+    Ok(())
+}
+
+/// Tests that a chain of transactions executed against the same account produces transactions
+/// whose initial and final account commitments chain correctly.
+#[test]
+fn test_execute_transaction_chain() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    let sender = ACCOUNT_ID_SENDER.try_into()?;
+    let note0 = mock_chain.add_pending_p2id_note(
+        sender,
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    let note1 = mock_chain.add_pending_p2id_note(
+        sender,
+        account.id(),
+        &[FungibleAsset::mock(50)],
+        NoteType::Public,
+    )?;
+    mock_chain.prove_next_block()?;
+
+    let tx_context = mock_chain
+        .build_tx_context(account.id(), &[note0.id(), note1.id()], &[])?
+        .build()?;
+    let authenticator = tx_context.authenticator().map(|x| x as &dyn TransactionAuthenticator);
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let transactions = tx_context
+        .input_notes()
+        .iter()
+        .map(|note| (InputNotes::new_unchecked(vec![note.clone()]), TransactionArgs::default()))
+        .collect();
+
+    let tx_executor = TransactionExecutor::new(&tx_context, authenticator);
+    let executed_txs = tx_executor.execute_transaction_chain(
+        account.id(),
+        block_ref,
+        transactions,
+        tx_context.source_manager(),
+    )?;
+
+    assert_eq!(executed_txs.len(), 2);
+    assert_eq!(executed_txs[0].initial_account().commitment(), account.commitment());
+    assert_eq!(
+        executed_txs[1].initial_account().commitment(),
+        executed_txs[0].final_account().commitment()
+    );
+    assert_eq!(executed_txs[1].final_account().nonce(), account.nonce() + Felt::new(2));
+
+    let mut expected_account = account.clone();
+    for executed_tx in executed_txs.iter() {
+        expected_account.apply_delta(executed_tx.account_delta())?;
+    }
+    assert_eq!(executed_txs[1].final_account().commitment(), expected_account.commitment());
+
+    Ok(())
+}
+
+/// Tests that profiling a transaction attributes its cycles to the execution stages, to the
+/// procedures assembled with debug information and to the invoked kernel procedures.
+#[test]
+fn test_profile_transaction() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    mock_chain.prove_next_block()?;
+
+    let tx_script_src = "
+        proc.burn_cycles
+            repeat.50 push.1 drop end
+        end
+
+        begin
+            exec.burn_cycles
+            exec.::miden::account::get_nonce drop
+        end
+    ";
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        TransactionKernel::testing_assembler().with_debug_mode(true),
+    )?;
+
+    let tx_context = mock_chain
+        .build_tx_context(account.id(), &[], &[])?
+        .tx_script(tx_script)
+        .build()?;
+    let authenticator = tx_context.authenticator().map(|x| x as &dyn TransactionAuthenticator);
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tx_executor = TransactionExecutor::new(&tx_context, authenticator);
+    let (executed_tx, profile) = tx_executor.profile_transaction(
+        account.id(),
+        block_ref,
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    )?;
+
+    assert_eq!(
+        profile.stacks().map(|(_, cycles)| cycles).sum::<usize>(),
+        profile.total_cycles()
+    );
+
+    let stage_cycles = |stage: &str| {
+        profile
+            .stacks()
+            .filter(|(stack, _)| stack.starts_with(stage))
+            .map(|(_, cycles)| cycles)
+            .sum::<usize>()
+    };
+    assert_eq!(stage_cycles("transaction;prologue"), executed_tx.measurements().prologue);
+    assert_eq!(stage_cycles("transaction;epilogue"), executed_tx.measurements().epilogue);
+
+    let (burn_cycles_stack, burn_cycles) = profile
+        .stacks()
+        .find(|(stack, _)| stack.ends_with("burn_cycles"))
+        .context("profile should contain the burn_cycles procedure")?;
+    assert!(burn_cycles_stack.starts_with("transaction;tx_script_processing;"));
+    assert!(burn_cycles >= 100);
+
+    // kernel procedures are attributed to their names even though the kernel has no debug info
+    assert!(profile.stacks().any(|(stack, _)| {
+        stack.starts_with("transaction;tx_script_processing;")
+            && stack.ends_with(";account_get_nonce")
+    }));
+    assert!(
+        profile
+            .stacks()
+            .any(|(stack, _)| stack == "transaction;epilogue;account_incr_nonce")
+    );
+
+    let folded_stacks = profile.to_folded_stacks();
+    assert!(folded_stacks.contains(&format!("{burn_cycles_stack} {burn_cycles}\n")));
+
+    Ok(())
+}
+
+/// Tests that the trace exporter of the executor receives the stages, note executions and
+/// signature requests of an executed transaction as nested spans.
+#[test]
+fn test_export_transaction_trace() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = create_p2any_note(ACCOUNT_ID_SENDER.try_into()?, &[FungibleAsset::mock(100)]);
+    mock_chain.add_pending_note(OutputNote::Full(note.clone()));
+    mock_chain.prove_next_block()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &[note.id()], &[])?.build()?;
+    let authenticator = tx_context.authenticator().map(|x| x as &dyn TransactionAuthenticator);
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let traces = Arc::new(Mutex::new(Vec::new()));
+    let exporter = {
+        let traces = traces.clone();
+        move |trace: &TransactionTrace| traces.lock().unwrap().push(trace.clone())
+    };
+    let tx_executor = TransactionExecutor::new(&tx_context, authenticator)
+        .with_trace_exporter(Arc::new(exporter));
+    let executed_tx = tx_executor.execute_transaction(
+        account.id(),
+        block_ref,
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    )?;
+
+    let traces = traces.lock().unwrap();
+    assert_eq!(traces.len(), 1);
+    let spans = traces[0].spans();
+
+    assert_eq!(spans[0].kind(), TraceSpanKind::Transaction);
+    assert_eq!(spans[0].parent(), None);
+
+    let find_span = |name: &str| {
+        spans
+            .iter()
+            .position(|span| span.name() == name)
+            .with_context(|| format!("trace should contain the {name} span"))
+    };
+    let prologue = find_span("prologue")?;
+    assert_eq!(spans[prologue].parent(), Some(0));
+    assert_eq!(spans[prologue].len(), executed_tx.measurements().prologue);
+
+    let notes_processing = find_span("notes_processing")?;
+    let note_span = find_span(&note.id().to_hex())?;
+    assert_eq!(spans[note_span].kind(), TraceSpanKind::Note);
+    assert_eq!(spans[note_span].parent(), Some(notes_processing));
+    assert_eq!(spans[note_span].depth(), 2);
+
+    // the signature is requested by the auth procedure which is executed in the epilogue
+    let epilogue = find_span("epilogue")?;
+    let signature_request = spans
+        .iter()
+        .find(|span| span.kind() == TraceSpanKind::SignatureRequest)
+        .context("trace should contain the signature request")?;
+    assert_eq!(signature_request.parent(), Some(epilogue));
+    assert!(signature_request.is_empty());
+
+    let json_lines = traces[0].to_json_lines();
+    assert_eq!(json_lines.lines().count(), spans.len());
+    assert!(json_lines.starts_with(r#"{"id":0,"parent":null,"depth":0,"kind":"transaction""#));
+
+    let chrome_trace = traces[0].to_chrome_trace();
+    assert!(chrome_trace.starts_with(r#"{"traceEvents":[{"name":"transaction""#));
+    assert!(chrome_trace.contains(r#""cat":"signature_request","ts":"#));
+
+    Ok(())
+}
+
+/// Tests that transaction execution is interrupted when the cancellation token of the executor is
+/// cancelled, both before and during execution, and when the timeout of the executor expires.
+#[test]
+fn test_execution_interrupts() -> anyhow::Result<()> {
+    /// An authenticator which cancels the provided token when asked for a signature.
+    struct CancellingAuthenticator<'a> {
+        inner: &'a dyn TransactionAuthenticator,
+        token: CancellationToken,
+    }
+
+    impl TransactionAuthenticator for CancellingAuthenticator<'_> {
+        fn get_signature(
+            &self,
+            pub_key: Word,
+            message: Word,
+            account_delta: &AccountDelta,
+        ) -> Result<Vec<Felt>, AuthenticationError> {
+            self.token.cancel();
+            self.inner.get_signature(pub_key, message, account_delta)
+        }
+    }
+
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    // a custom note is used so that the consumability check has to execute the transaction
+    let note = create_p2any_note(ACCOUNT_ID_SENDER.try_into()?, &[FungibleAsset::mock(100)]);
+    mock_chain.add_pending_note(OutputNote::Full(note.clone()));
+    mock_chain.prove_next_block()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &[note.id()], &[])?.build()?;
+    let authenticator = tx_context.authenticator().map(|x| x as &dyn TransactionAuthenticator);
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let execute = |tx_executor: &TransactionExecutor| {
+        tx_executor.execute_transaction(
+            account.id(),
+            block_ref,
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            tx_context.source_manager(),
+        )
+    };
+
+    // a token cancelled before execution interrupts execution and note consumability checks
+    let token = CancellationToken::new();
+    token.cancel();
+    let tx_executor =
+        TransactionExecutor::new(&tx_context, authenticator).with_cancellation_token(token);
+    assert_matches::assert_matches!(
+        execute(&tx_executor),
+        Err(TransactionExecutorError::ExecutionInterrupted(InterruptReason::Cancelled))
+    );
+    let consumability = NoteConsumptionChecker::new(&tx_executor).check_notes_consumability(
+        account.id(),
+        block_ref,
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    );
+    assert_matches::assert_matches!(
+        consumability,
+        Err(TransactionExecutorError::ExecutionInterrupted(InterruptReason::Cancelled))
+    );
+
+    // a token cancelled during execution interrupts execution at the next kernel event
+    let token = CancellationToken::new();
+    let cancelling_authenticator = CancellingAuthenticator {
+        inner: authenticator.context("account should have an authenticator")?,
+        token: token.clone(),
+    };
+    let tx_executor = TransactionExecutor::new(&tx_context, Some(&cancelling_authenticator))
+        .with_cancellation_token(token.clone());
+    assert_matches::assert_matches!(
+        execute(&tx_executor),
+        Err(TransactionExecutorError::ExecutionInterrupted(InterruptReason::Cancelled))
+    );
+    assert!(token.is_cancelled());
+
+    // an expired timeout interrupts execution
+    let tx_executor =
+        TransactionExecutor::new(&tx_context, authenticator).with_timeout(Duration::ZERO);
+    assert_matches::assert_matches!(
+        execute(&tx_executor),
+        Err(TransactionExecutorError::ExecutionInterrupted(
+            InterruptReason::DeadlineExceeded
+        ))
+    );
+
+    // without interrupts, the same transaction executes successfully
+    let tx_executor = TransactionExecutor::new(&tx_context, authenticator)
+        .with_cancellation_token(CancellationToken::new())
+        .with_timeout(Duration::from_secs(600));
+    execute(&tx_executor)?;
+
+    Ok(())
+}
+
+/// Tests that the timeout of the executor interrupts a note script which loops forever without
+/// emitting any events, i.e., without ever calling into the host.
+#[test]
+fn test_timeout_interrupts_infinite_loop() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::IncrNonce, vec![]);
+    let note = NoteBuilder::new(ACCOUNT_ID_SENDER.try_into()?, rng())
+        .code("begin push.1 while.true push.1 end end")
+        .build(&TransactionKernel::testing_assembler())?;
+    mock_chain.add_pending_note(OutputNote::Full(note.clone()));
+    mock_chain.prove_next_block()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &[note.id()], &[])?.build()?;
+    let tx_executor =
+        TransactionExecutor::new(&tx_context, None).with_timeout(Duration::from_millis(100));
+    let result = tx_executor.execute_transaction(
+        account.id(),
+        tx_context.tx_inputs().block_header().block_num(),
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    );
+    assert_matches::assert_matches!(
+        result,
+        Err(TransactionExecutorError::ExecutionInterrupted(
+            InterruptReason::DeadlineExceeded
+        ))
+    );
+
+    Ok(())
+}
+
+/// Tests that, with the number of cycles per second of the VM measured on this machine, a note
+/// script which loops forever without emitting any events is interrupted close to the deadline.
+#[test]
+fn test_timeout_interrupts_infinite_loop_close_to_deadline() -> anyhow::Result<()> {
+    const CALIBRATION_CYCLES: u32 = 1 << 20;
+    const TIMEOUT: Duration = Duration::from_millis(500);
+
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::IncrNonce, vec![]);
+    let note = NoteBuilder::new(ACCOUNT_ID_SENDER.try_into()?, rng())
+        .code("begin push.1 while.true push.1 end end")
+        .build(&TransactionKernel::testing_assembler())?;
+    mock_chain.add_pending_note(OutputNote::Full(note.clone()));
+    mock_chain.prove_next_block()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &[note.id()], &[])?.build()?;
+    let execute = |tx_executor: &TransactionExecutor| {
+        let start = Instant::now();
+        let result = tx_executor.execute_transaction(
+            account.id(),
+            tx_context.tx_inputs().block_header().block_num(),
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            tx_context.source_manager(),
+        );
+        (result, start.elapsed())
+    };
+
+    // measure the number of cycles per second by running the loop until the cycle limit
+    let exec_options =
+        ExecutionOptions::new(Some(CALIBRATION_CYCLES), MIN_TX_EXECUTION_CYCLES, false, false)?;
+    let tx_executor = TransactionExecutor::with_options(&tx_context, None, exec_options)?;
+    let (result, elapsed) = execute(&tx_executor);
+    assert!(result.is_err());
+    let cycles_per_second = (f64::from(CALIBRATION_CYCLES) / elapsed.as_secs_f64()) as u32;
+
+    let tx_executor = TransactionExecutor::new(&tx_context, None)
+        .with_timeout(TIMEOUT)
+        .with_cycles_per_second(cycles_per_second);
+    let (result, elapsed) = execute(&tx_executor);
+    assert_matches::assert_matches!(
+        result,
+        Err(TransactionExecutorError::ExecutionInterrupted(
+            InterruptReason::DeadlineExceeded
+        ))
+    );
+    assert!(
+        elapsed >= TIMEOUT / 2 && elapsed <= TIMEOUT * 2,
+        "execution was interrupted after {elapsed:?} instead of close to {TIMEOUT:?}"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_create_note() -> anyhow::Result<()> {
     let tx_context = TransactionContextBuilder::with_existing_mock_account().build()?;
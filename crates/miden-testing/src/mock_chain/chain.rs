@@ -22,7 +22,8 @@ use miden_objects::{
     batch::{ProposedBatch, ProvenBatch},
     block::{
         AccountTree, AccountWitness, BlockAccountUpdate, BlockHeader, BlockInputs, BlockNoteTree,
-        BlockNumber, Blockchain, NullifierTree, NullifierWitness, ProposedBlock, ProvenBlock,
+        BlockNumber, Blockchain, FeeParameters, NullifierTree, NullifierWitness, ProposedBlock,
+        ProvenBlock,
     },
     crypto::merkle::SmtProof,
     note::{Note, NoteHeader, NoteId, NoteInclusionProof, NoteType, Nullifier},
@@ -215,9 +216,23 @@ impl MockChain {
     }
 
     /// Creates a new `MockChain` with a genesis block containing the provided accounts.
+    ///
+    /// The genesis block uses [`FeeParameters::mock`], i.e. transactions do not pay fees.
     pub fn with_accounts(accounts: &[Account]) -> anyhow::Result<Self> {
-        let (genesis_block, account_tree) = create_genesis_state(accounts.iter().cloned())
-            .context("failed to build account from builder")?;
+        Self::with_accounts_and_fee_parameters(accounts, FeeParameters::mock())
+    }
+
+    /// Creates a new `MockChain` with a genesis block containing the provided accounts and the
+    /// provided fee parameters.
+    ///
+    /// The fee parameters are carried over to all subsequent blocks of the chain.
+    pub fn with_accounts_and_fee_parameters(
+        accounts: &[Account],
+        fee_parameters: FeeParameters,
+    ) -> anyhow::Result<Self> {
+        let (genesis_block, account_tree) =
+            create_genesis_state(accounts.iter().cloned(), fee_parameters)
+                .context("failed to build account from builder")?;
 
         let mut chain = MockChain {
             chain: Blockchain::default(),
@@ -462,6 +477,7 @@ impl MockChain {
             input_notes,
             output_notes,
             batch_expiration_block_num,
+            fee,
         ) = proposed_batch.into_parts();
 
         // SAFETY: This satisfies the requirements of the ordered tx headers.
@@ -481,6 +497,7 @@ impl MockChain {
             input_notes,
             output_notes,
             batch_expiration_block_num,
+            fee,
             tx_headers,
         )?)
     }
@@ -587,7 +604,9 @@ impl MockChain {
                 MockAccount::new(account, seed.cloned(), authenticator.cloned())
             },
             TxContextInput::ExecutedTransaction(executed_transaction) => {
-                let mut initial_account = executed_transaction.initial_account().clone();
+                let mut initial_account =
+                    Account::try_from(executed_transaction.initial_account().clone())
+                        .context("previous transaction should contain the full account state")?;
                 initial_account
                     .apply_delta(executed_transaction.account_delta())
                     .context("could not apply delta from previous transaction")?;
@@ -668,7 +687,7 @@ impl MockChain {
         let input_notes = InputNotes::new(input_notes)?;
 
         Ok(TransactionInputs::new(
-            account,
+            account.into(),
             account_seed,
             block.header().clone(),
             mmr,
@@ -821,7 +840,7 @@ impl MockChain {
         &mut self,
         transaction: &ExecutedTransaction,
     ) -> anyhow::Result<Account> {
-        let mut account = transaction.initial_account().clone();
+        let mut account = Account::try_from(transaction.initial_account().clone())?;
         account.apply_delta(transaction.account_delta())?;
 
         // This essentially transforms an executed tx into a proven tx with a dummy proof.
@@ -1306,6 +1325,7 @@ impl MockChain {
             block_header.tx_kernel_commitment(),
             block_header.proof_commitment(),
             block_header.timestamp(),
+            *block_header.fee_parameters(),
         );
         proven_block.set_block_header(updated_header);
 
@@ -1368,6 +1388,7 @@ impl MockChain {
 /// account tree with those accounts.
 fn create_genesis_state(
     accounts: impl IntoIterator<Item = Account>,
+    fee_parameters: FeeParameters,
 ) -> anyhow::Result<(ProvenBlock, AccountTree)> {
     let block_account_updates: Vec<BlockAccountUpdate> = accounts
         .into_iter()
@@ -1415,6 +1436,7 @@ fn create_genesis_state(
         tx_kernel_commitment,
         proof_commitment,
         timestamp,
+        fee_parameters,
     );
 
     Ok((
@@ -1424,6 +1446,7 @@ fn create_genesis_state(
             output_note_batches,
             created_nullifiers,
             transactions,
+            fee_parameters.empty_fee(),
         ),
         account_tree,
     ))
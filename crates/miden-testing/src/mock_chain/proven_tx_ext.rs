@@ -1,5 +1,5 @@
 use miden_objects::{
-    account::delta::AccountUpdateDetails,
+    account::{Account, delta::AccountUpdateDetails},
     transaction::{ExecutedTransaction, ProvenTransaction, ProvenTransactionBuilder},
     vm::ExecutionProof,
 };
@@ -18,9 +18,10 @@ impl ProvenTransactionExt for ProvenTransaction {
         let account_delta = executed_tx.account_delta().clone();
         let initial_account = executed_tx.initial_account().clone();
 
-        let account_update_details = if initial_account.is_onchain() {
+        let account_update_details = if initial_account.id().is_onchain() {
             if initial_account.is_new() {
-                let mut account = initial_account;
+                let mut account = Account::try_from(initial_account)
+                    .expect("new account should contain the full account state");
                 account.apply_delta(&account_delta).expect("account delta should be applicable");
 
                 AccountUpdateDetails::New(account)
@@ -39,6 +40,7 @@ impl ProvenTransactionExt for ProvenTransaction {
             block_reference.block_num(),
             block_reference.commitment(),
             executed_tx.expiration_block_num(),
+            executed_tx.fee(),
             ExecutionProof::new(Proof::new_dummy(), Default::default()),
         )
         .add_input_notes(executed_tx.input_notes())
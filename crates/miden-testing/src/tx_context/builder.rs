@@ -7,7 +7,7 @@ use anyhow::Context;
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
     EMPTY_WORD, FieldElement,
-    account::Account,
+    account::{Account, AccountCode},
     assembly::Assembler,
     note::{Note, NoteId},
     testing::{
@@ -71,6 +71,7 @@ pub struct TransactionContextBuilder {
     tx_script_arg: Word,
     note_args: BTreeMap<NoteId, Word>,
     transaction_inputs: Option<TransactionInputs>,
+    new_account_code: Option<AccountCode>,
 }
 
 impl TransactionContextBuilder {
@@ -86,6 +87,7 @@ impl TransactionContextBuilder {
             authenticator: None,
             advice_inputs: Default::default(),
             transaction_inputs: None,
+            new_account_code: None,
             note_args: BTreeMap::new(),
             foreign_account_inputs: vec![],
         }
@@ -126,6 +128,7 @@ impl TransactionContextBuilder {
             tx_script: None,
             tx_script_arg: EMPTY_WORD,
             transaction_inputs: None,
+            new_account_code: None,
             note_args: BTreeMap::new(),
             foreign_account_inputs: vec![],
         }
@@ -235,6 +238,12 @@ impl TransactionContextBuilder {
         self
     }
 
+    /// Set the code which replaces the code of the account if the transaction updates it
+    pub fn new_account_code(mut self, code: AccountCode) -> Self {
+        self.new_account_code = Some(code);
+        self
+    }
+
     /// Extend the note arguments map with the provided one.
     pub fn extend_note_args(mut self, note_args: BTreeMap<NoteId, Word>) -> Self {
         self.note_args.extend(note_args);
@@ -288,6 +297,9 @@ impl TransactionContextBuilder {
             },
         };
 
+        let account = Account::try_from(tx_inputs.account().clone())
+            .context("transaction inputs should contain the full state of the account")?;
+
         let tx_args = TransactionArgs::new(AdviceMap::default(), self.foreign_account_inputs)
             .with_note_args(self.note_args);
 
@@ -297,6 +309,10 @@ impl TransactionContextBuilder {
             tx_args
         };
 
+        if let Some(code) = self.new_account_code {
+            tx_args = tx_args.with_new_account_code(code);
+        }
+
         tx_args.extend_advice_inputs(self.advice_inputs.clone());
         tx_args.extend_output_note_recipients(self.expected_output_notes.clone());
 
@@ -312,6 +328,7 @@ impl TransactionContextBuilder {
         };
 
         Ok(TransactionContext {
+            account,
             expected_output_notes: self.expected_output_notes,
             tx_args,
             tx_inputs,
@@ -4,7 +4,7 @@ use alloc::{borrow::ToOwned, collections::BTreeSet, rc::Rc, sync::Arc, vec::Vec}
 
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
-    account::{Account, AccountId},
+    account::{Account, AccountId, PartialAccount},
     assembly::{Assembler, SourceManager},
     block::{BlockHeader, BlockNumber},
     note::Note,
@@ -33,6 +33,7 @@ use crate::{MockHost, executor::CodeExecutor, tx_context::builder::MockAuthentic
 /// It implements [`DataStore`], so transactions may be executed with
 /// [TransactionExecutor](miden_tx::TransactionExecutor)
 pub struct TransactionContext {
+    pub(super) account: Account,
     pub(super) expected_output_notes: Vec<Note>,
     pub(super) tx_args: TransactionArgs,
     pub(super) tx_inputs: TransactionInputs,
@@ -61,6 +62,7 @@ impl TransactionContext {
     /// # Panics
     ///
     /// - If the provided `code` is not a valid program.
+    /// - If the reference block does not support the built-in transaction kernel.
     pub fn execute_code_with_assembler(
         &self,
         code: &str,
@@ -70,7 +72,8 @@ impl TransactionContext {
             &self.tx_inputs,
             &self.tx_args,
             Some(self.advice_inputs.clone()),
-        );
+        )
+        .expect("reference block should support the built-in transaction kernel");
 
         let test_lib = TransactionKernel::kernel_as_library();
 
@@ -134,7 +137,7 @@ impl TransactionContext {
     }
 
     pub fn account(&self) -> &Account {
-        self.tx_inputs.account()
+        &self.account
     }
 
     pub fn expected_output_notes(&self) -> &[Note] {
@@ -174,7 +177,8 @@ impl DataStore for TransactionContext {
         &self,
         account_id: AccountId,
         _ref_blocks: BTreeSet<BlockNumber>,
-    ) -> Result<(Account, Option<Word>, BlockHeader, PartialBlockchain), DataStoreError> {
+    ) -> Result<(PartialAccount, Option<Word>, BlockHeader, PartialBlockchain), DataStoreError>
+    {
         assert_eq!(account_id, self.account().id());
         let (account, seed, header, mmr, _) = self.tx_inputs.clone().into_parts();
 
@@ -1 +1,5 @@
+mod multisig;
+mod policy;
+mod rpo_falcon_code_update;
 mod rpo_falcon_procedure_acl;
+mod socket_signer;
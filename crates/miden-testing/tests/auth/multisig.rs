@@ -0,0 +1,276 @@
+use std::collections::BTreeSet;
+
+use miden_lib::{
+    account::{auth::RpoFalcon512Multisig, wallets::BasicWallet},
+    errors::note_script_errors::ERR_MULTISIG_THRESHOLD_NOT_MET,
+};
+use miden_objects::{
+    Digest, Word,
+    account::{Account, AccountBuilder, AccountStorageMode, AccountType, AuthSecretKey},
+    asset::FungibleAsset,
+    crypto::dsa::rpo_falcon512::{PublicKey, SecretKey},
+    note::NoteType,
+    testing::account_id::ACCOUNT_ID_SENDER,
+    transaction::ExecutedTransaction,
+};
+use miden_testing::{MockChain, TransactionContext};
+use miden_tx::{
+    TransactionExecution, TransactionExecutor, TransactionExecutorError,
+    auth::{BasicAuthenticator, MultisigAuthenticator},
+};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+use crate::{assert_transaction_executor_error, prove_and_verify_transaction};
+
+/// Returns the public keys of the approvers and an authenticator for each of them.
+fn approvers(num_approvers: usize) -> Vec<(PublicKey, BasicAuthenticator<ChaCha20Rng>)> {
+    let mut rng = ChaCha20Rng::from_seed([7; 32]);
+    (0..num_approvers)
+        .map(|_| {
+            let sec_key = SecretKey::with_rng(&mut rng);
+            let pub_key = sec_key.public_key();
+            let authenticator = BasicAuthenticator::new_with_rng(
+                &[(pub_key.into(), AuthSecretKey::RpoFalcon512(sec_key))],
+                ChaCha20Rng::from_rng(&mut rng),
+            );
+            (pub_key, authenticator)
+        })
+        .collect()
+}
+
+/// Returns a [TransactionContext] for a transaction consuming a P2ID note against a new account
+/// with a `threshold`-of-n multisig auth component for the provided approvers.
+fn multisig_tx_context(
+    threshold: u32,
+    pub_keys: &[PublicKey],
+) -> anyhow::Result<(Account, TransactionContext)> {
+    let multisig = RpoFalcon512Multisig::new(threshold, pub_keys.to_vec())?;
+    let account = AccountBuilder::new([3; 32])
+        .with_auth_component(multisig)
+        .with_component(BasicWallet)
+        .account_type(AccountType::RegularAccountUpdatableCode)
+        .storage_mode(AccountStorageMode::Public)
+        .build_existing()?;
+
+    let mut mock_chain = MockChain::new();
+    mock_chain.add_pending_account(account.clone());
+    let note = mock_chain.add_pending_p2id_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    mock_chain.prove_next_block()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &[note.id()], &[])?.build()?;
+    Ok((account, tx_context))
+}
+
+/// Executes the transaction of the provided context using the provided authenticator.
+fn execute(
+    tx_context: &TransactionContext,
+    authenticator: &MultisigAuthenticator,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    TransactionExecutor::new(tx_context, Some(authenticator)).execute_transaction(
+        tx_context.account().id(),
+        tx_context.tx_inputs().block_header().block_num(),
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    )
+}
+
+/// Tests that a transaction against a 2-of-3 multisig account succeeds if two of the keyholders
+/// sign it, and fails if only one of them does.
+#[test]
+fn multisig_requires_threshold_of_signatures() -> anyhow::Result<()> {
+    let (pub_keys, authenticators): (Vec<_>, Vec<_>) = approvers(3).into_iter().unzip();
+    let (account, tx_context) = multisig_tx_context(2, &pub_keys)?;
+
+    // the first keyholder refuses to sign, so the signatures of the other two are used
+    let authenticator = MultisigAuthenticator::new()
+        .with_keyholder(pub_keys[0].into(), ())
+        .with_keyholder(pub_keys[1].into(), authenticators[1].clone())
+        .with_keyholder(pub_keys[2].into(), authenticators[2].clone());
+
+    let executed_transaction = execute(&tx_context, &authenticator)?;
+    assert_eq!(executed_transaction.final_account().nonce(), account.nonce() + 1u32.into());
+    prove_and_verify_transaction(executed_transaction)?;
+
+    // a single signature does not meet the threshold
+    let authenticator = MultisigAuthenticator::new()
+        .with_keyholder(pub_keys[0].into(), ())
+        .with_keyholder(pub_keys[2].into(), authenticators[2].clone());
+    assert_transaction_executor_error!(
+        execute(&tx_context, &authenticator),
+        ERR_MULTISIG_THRESHOLD_NOT_MET
+    );
+
+    Ok(())
+}
+
+/// Tests that a transaction against a 3-of-3 multisig account requires the signatures of all
+/// keyholders.
+#[test]
+fn multisig_with_threshold_equal_to_number_of_approvers() -> anyhow::Result<()> {
+    let (pub_keys, authenticators): (Vec<_>, Vec<_>) = approvers(3).into_iter().unzip();
+    let (account, tx_context) = multisig_tx_context(3, &pub_keys)?;
+
+    let authenticator = pub_keys.iter().zip(authenticators.iter()).fold(
+        MultisigAuthenticator::new(),
+        |authenticator, (pub_key, keyholder)| {
+            authenticator.with_keyholder((*pub_key).into(), keyholder.clone())
+        },
+    );
+    let executed_transaction = execute(&tx_context, &authenticator)?;
+    assert_eq!(executed_transaction.final_account().nonce(), account.nonce() + 1u32.into());
+
+    // the last keyholder refuses to sign
+    let authenticator = MultisigAuthenticator::new()
+        .with_keyholder(pub_keys[0].into(), authenticators[0].clone())
+        .with_keyholder(pub_keys[1].into(), authenticators[1].clone())
+        .with_keyholder(pub_keys[2].into(), ());
+    assert_transaction_executor_error!(
+        execute(&tx_context, &authenticator),
+        ERR_MULTISIG_THRESHOLD_NOT_MET
+    );
+
+    Ok(())
+}
+
+/// Tests that signatures of keys which are not approvers of a 2-of-3 multisig account do not count
+/// towards the threshold, so the kernel rejects a transaction resumed with one approver signature
+/// and one signature of an outsider.
+#[test]
+fn multisig_rejects_signatures_of_non_approvers() -> anyhow::Result<()> {
+    let (pub_keys, authenticators): (Vec<_>, Vec<_>) = approvers(4).into_iter().unzip();
+    let (account, tx_context) = multisig_tx_context(2, &pub_keys[..3])?;
+
+    let executor = TransactionExecutor::new(&tx_context, None);
+    let execution = executor.execute_until_signature(
+        account.id(),
+        tx_context.tx_inputs().block_header().block_num(),
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    )?;
+    let TransactionExecution::SignatureRequired(request) = execution else {
+        anyhow::bail!("expected the transaction to be paused at a signature request");
+    };
+
+    // the outsider signs the same message as the first approver
+    let authenticator = MultisigAuthenticator::new()
+        .with_keyholder(pub_keys[0].into(), authenticators[0].clone())
+        .with_keyholder(pub_keys[3].into(), authenticators[3].clone());
+    let signatures =
+        authenticator.collect_signatures(2, request.message(), request.account_delta())?;
+    assert_eq!(signatures.len(), 2);
+
+    let result = executor.resume_transaction_with_signatures(
+        *request,
+        signatures,
+        tx_context.source_manager(),
+    );
+    assert_transaction_executor_error!(result, ERR_MULTISIG_THRESHOLD_NOT_MET);
+
+    Ok(())
+}
+
+/// Tests that the signatures of a 2-of-3 multisig account can be collected out of band after
+/// pausing the transaction at the first signature request, and that the transaction can be
+/// resumed with the collected signatures.
+#[test]
+fn multisig_with_deferred_signatures() -> anyhow::Result<()> {
+    let (pub_keys, authenticators): (Vec<_>, Vec<_>) = approvers(3).into_iter().unzip();
+    let (account, tx_context) = multisig_tx_context(2, &pub_keys)?;
+
+    let executor = TransactionExecutor::new(&tx_context, None);
+    let execution = executor.execute_until_signature(
+        account.id(),
+        tx_context.tx_inputs().block_header().block_num(),
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    )?;
+    let TransactionExecution::SignatureRequired(request) = execution else {
+        anyhow::bail!("expected the transaction to be paused at a signature request");
+    };
+    assert_eq!(request.pub_key(), Word::from(pub_keys[0]));
+
+    // the first keyholder refuses to sign
+    let authenticator = MultisigAuthenticator::new()
+        .with_keyholder(pub_keys[0].into(), ())
+        .with_keyholder(pub_keys[1].into(), authenticators[1].clone())
+        .with_keyholder(pub_keys[2].into(), authenticators[2].clone());
+
+    // too few signatures cannot be collected
+    assert!(
+        authenticator
+            .collect_signatures(3, request.message(), request.account_delta())
+            .is_err()
+    );
+
+    let signatures =
+        authenticator.collect_signatures(2, request.message(), request.account_delta())?;
+    let signers: BTreeSet<_> =
+        signatures.iter().map(|(pub_key, _)| Digest::from(*pub_key)).collect();
+    assert_eq!(
+        signers,
+        BTreeSet::from([Word::from(pub_keys[1]).into(), Word::from(pub_keys[2]).into()])
+    );
+
+    // resuming with a single signature does not meet the threshold
+    let result = executor.resume_transaction_with_signatures(
+        (*request).clone(),
+        signatures[..1].to_vec(),
+        tx_context.source_manager(),
+    );
+    assert_transaction_executor_error!(result, ERR_MULTISIG_THRESHOLD_NOT_MET);
+
+    let executed_transaction = executor.resume_transaction_with_signatures(
+        *request,
+        signatures,
+        tx_context.source_manager(),
+    )?;
+    assert_eq!(executed_transaction.final_account().nonce(), account.nonce() + 1u32.into());
+    prove_and_verify_transaction(executed_transaction)?;
+
+    Ok(())
+}
+
+/// Tests that simulating a transaction against a 2-of-3 multisig account answers the signature
+/// requests of the first two keyholders and reports the same effects as the executed transaction.
+#[test]
+fn multisig_simulation_answers_threshold_signature_requests() -> anyhow::Result<()> {
+    let (pub_keys, authenticators): (Vec<_>, Vec<_>) = approvers(3).into_iter().unzip();
+    let (account, tx_context) = multisig_tx_context(2, &pub_keys)?;
+
+    let simulated_tx = TransactionExecutor::new(&tx_context, None).simulate_transaction(
+        account.id(),
+        tx_context.tx_inputs().block_header().block_num(),
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    )?;
+
+    let requested_keys: Vec<Word> = simulated_tx
+        .simulated_signatures()
+        .iter()
+        .map(|(pub_key, _)| *pub_key)
+        .collect();
+    // once the threshold is met, the remaining approvers are not visited
+    let approver_keys: Vec<Word> = pub_keys[..2].iter().map(|pub_key| (*pub_key).into()).collect();
+    assert_eq!(requested_keys, approver_keys);
+    assert!(!simulated_tx.is_provable());
+
+    let authenticator = MultisigAuthenticator::new()
+        .with_keyholder(pub_keys[0].into(), authenticators[0].clone())
+        .with_keyholder(pub_keys[1].into(), authenticators[1].clone())
+        .with_keyholder(pub_keys[2].into(), ());
+    let executed_transaction = execute(&tx_context, &authenticator)?;
+    assert_eq!(simulated_tx.account_delta(), executed_transaction.account_delta());
+    assert_eq!(simulated_tx.fee(), executed_transaction.fee());
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use assert_matches::assert_matches;
+use miden_lib::{
+    account::interface::AccountInterface,
+    transaction::{TransactionKernel, TransactionKernelError},
+};
+use miden_objects::{
+    Word,
+    asset::FungibleAsset,
+    note::{
+        Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient, NoteScript,
+        NoteTag, NoteType,
+    },
+    transaction::{ExecutedTransaction, OutputNote},
+};
+use miden_testing::{Auth, MockChain, TransactionContext};
+use miden_tx::{
+    AuthenticationError, TransactionExecutor, TransactionExecutorError,
+    auth::{MaxFungibleOutflow, MaxNonceDelta, PolicyAuthenticator, TransactionAuthenticator},
+};
+use vm_processor::ExecutionError;
+
+/// Returns a [TransactionContext] for a transaction sending a note with 60 units of the mock
+/// fungible asset from a basic wallet holding 100 units.
+fn send_note_tx_context() -> anyhow::Result<TransactionContext> {
+    let mut mock_chain = MockChain::new();
+    let sender =
+        mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![FungibleAsset::mock(100)]);
+
+    let metadata = NoteMetadata::new(
+        sender.id(),
+        NoteType::Public,
+        NoteTag::from_account_id(sender.id()),
+        NoteExecutionHint::always(),
+        Default::default(),
+    )?;
+    let note_script = NoteScript::compile("begin nop end", TransactionKernel::testing_assembler())?;
+    let recipient = NoteRecipient::new(Word::default(), note_script, NoteInputs::default());
+    let note = Note::new(NoteAssets::new(vec![FungibleAsset::mock(60)])?, metadata, recipient);
+
+    let tx_script = AccountInterface::from(&sender).build_send_notes_script(
+        &[note.clone().into()],
+        None,
+        false,
+    )?;
+
+    let tx_context = mock_chain
+        .build_tx_context(sender.id(), &[], &[])?
+        .tx_script(tx_script)
+        .extend_expected_output_notes(vec![OutputNote::Full(note)])
+        .build()?;
+    Ok(tx_context)
+}
+
+/// Executes the transaction of the provided context using the provided authenticator.
+fn execute(
+    tx_context: &TransactionContext,
+    authenticator: &dyn TransactionAuthenticator,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    TransactionExecutor::new(tx_context, Some(authenticator)).execute_transaction(
+        tx_context.account().id(),
+        tx_context.tx_inputs().block_header().block_num(),
+        tx_context.input_notes().clone(),
+        tx_context.tx_args().clone(),
+        tx_context.source_manager(),
+    )
+}
+
+/// Asserts that the provided result is an execution failure caused by the authenticator
+/// rejecting the signature request, and returns the reason of the rejection.
+fn rejection_reason(result: Result<ExecutedTransaction, TransactionExecutorError>) -> String {
+    assert_matches!(result, Err(TransactionExecutorError::TransactionProgramExecutionFailed(failure)) => {
+        assert_matches!(failure.into_error(), ExecutionError::EventError { error, .. } => {
+            let kernel_error = error.downcast_ref::<TransactionKernelError>().unwrap();
+            assert_matches!(kernel_error, TransactionKernelError::SignatureGenerationFailed(source) => {
+                assert_matches!(
+                    source.downcast_ref::<AuthenticationError>(),
+                    Some(AuthenticationError::RejectedSignature(reason)) => reason.clone()
+                )
+            })
+        })
+    })
+}
+
+/// Tests that the reason for which a policy rejects a transaction is reported by the executor.
+#[test]
+fn policy_rejection_is_reported_by_executor() -> anyhow::Result<()> {
+    let tx_context = send_note_tx_context()?;
+    let signer = tx_context.authenticator().expect("wallet should have an authenticator");
+    let faucet_id = FungibleAsset::mock_issuer();
+
+    let authenticator = PolicyAuthenticator::new(signer)
+        .with_rule(MaxFungibleOutflow::new().with_limit(faucet_id, 50));
+    let reason = rejection_reason(execute(&tx_context, &authenticator));
+    assert!(reason.contains("outflow of 60 units"), "unexpected reason: {reason}");
+    assert!(reason.contains("exceeds the limit of 50"), "unexpected reason: {reason}");
+
+    let authenticator = PolicyAuthenticator::new(signer).with_rule(MaxNonceDelta(0));
+    let reason = rejection_reason(execute(&tx_context, &authenticator));
+    assert!(reason.contains("nonce delta of 1"), "unexpected reason: {reason}");
+
+    // a transaction which satisfies all rules is signed
+    let authenticator = PolicyAuthenticator::new(signer)
+        .with_rule(MaxFungibleOutflow::new().with_limit(faucet_id, 100))
+        .with_rule(MaxNonceDelta(1));
+    execute(&tx_context, &authenticator)?;
+
+    Ok(())
+}
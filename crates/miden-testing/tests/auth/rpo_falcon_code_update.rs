@@ -0,0 +1,99 @@
+use miden_lib::{
+    account::{auth::RpoFalcon512CodeUpdate, wallets::BasicWallet},
+    transaction::TransactionKernel,
+};
+use miden_objects::{
+    account::{
+        Account, AccountBuilder, AccountComponent, AccountStorageMode, AccountType, AuthSecretKey,
+    },
+    crypto::dsa::rpo_falcon512::{PublicKey, SecretKey},
+    testing::account_component::AccountMockComponent,
+};
+use miden_testing::MockChain;
+use miden_tx::{TransactionExecutorError, auth::BasicAuthenticator};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+/// Tests that the [`RpoFalcon512CodeUpdate`] component replaces the account code with the code
+/// provided to the transaction, and leaves it unchanged if no new code is provided.
+#[test]
+fn test_rpo_falcon_code_update() -> anyhow::Result<()> {
+    let (pub_key, authenticator) = setup_authenticator();
+    let account = build_account(pub_key, [10; 32], BasicWallet)?;
+    // the new code replaces the basic wallet with a mock component that does not use any storage
+    let mock_component =
+        AccountMockComponent::new_with_slots(TransactionKernel::testing_assembler(), vec![])?;
+    let new_code = build_account(pub_key, [11; 32], mock_component)?.code().clone();
+    assert_ne!(account.code().commitment(), new_code.commitment());
+
+    let mut mock_chain = MockChain::new();
+    mock_chain.add_pending_account(account.clone());
+    mock_chain.prove_next_block()?;
+
+    // Test 1: Transaction without new code leaves the code unchanged
+    let executed_tx = mock_chain
+        .build_tx_context(account.id(), &[], &[])?
+        .authenticator(Some(authenticator.clone()))
+        .build()?
+        .execute()?;
+
+    assert_eq!(executed_tx.account_delta().code(), None);
+    assert_eq!(executed_tx.final_account().code_commitment(), account.code().commitment());
+
+    // Test 2: Transaction with new code replaces the code
+    let executed_tx = mock_chain
+        .build_tx_context(account.id(), &[], &[])?
+        .authenticator(Some(authenticator))
+        .new_account_code(new_code.clone())
+        .build()?
+        .execute()?;
+
+    assert_eq!(executed_tx.account_delta().code(), Some(&new_code));
+    assert_eq!(executed_tx.final_account().code_commitment(), new_code.commitment());
+
+    // Test 3: Transaction with new code but without authenticator fails
+    let result = mock_chain
+        .build_tx_context(account.id(), &[], &[])?
+        .new_account_code(new_code)
+        .build()?
+        .execute();
+
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(_))
+    ));
+
+    Ok(())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn setup_authenticator() -> (PublicKey, BasicAuthenticator<ChaCha20Rng>) {
+    let mut rng = ChaCha20Rng::from_seed(Default::default());
+    let sec_key = SecretKey::with_rng(&mut rng);
+    let pub_key = sec_key.public_key();
+    let authenticator = BasicAuthenticator::<ChaCha20Rng>::new_with_rng(
+        &[(pub_key.into(), AuthSecretKey::RpoFalcon512(sec_key))],
+        rng,
+    );
+
+    (pub_key, authenticator)
+}
+
+/// Builds an account with updatable code which consists of the [`RpoFalcon512CodeUpdate`]
+/// component and the provided component.
+fn build_account(
+    pub_key: PublicKey,
+    seed: [u8; 32],
+    component: impl Into<AccountComponent>,
+) -> anyhow::Result<Account> {
+    let account = AccountBuilder::new(seed)
+        .with_auth_component(RpoFalcon512CodeUpdate::new(pub_key))
+        .with_component(component)
+        .account_type(AccountType::RegularAccountUpdatableCode)
+        .storage_mode(AccountStorageMode::Public)
+        .build_existing()?;
+
+    Ok(account)
+}
@@ -141,9 +141,9 @@ fn test_rpo_falcon_procedure_acl() -> anyhow::Result<()> {
     let executed_tx_no_auth = tx_context_no_auth.execute();
 
     assert_matches!(executed_tx_no_auth, Err(TransactionExecutorError::TransactionProgramExecutionFailed(
-        execution_error
+        failure
     )) => {
-        assert_matches!(execution_error, ExecutionError::EventError { error, .. } => {
+        assert_matches!(failure.into_error(), ExecutionError::EventError { error, .. } => {
             let kernel_error = error.downcast_ref::<TransactionKernelError>().unwrap();
             assert_matches!(kernel_error, TransactionKernelError::FailedSignatureGeneration(msg) => {
                 assert_eq!(
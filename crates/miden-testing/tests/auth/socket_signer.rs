@@ -0,0 +1,101 @@
+use std::{
+    net::{TcpListener, TcpStream},
+    thread,
+    time::Duration,
+};
+
+use assert_matches::assert_matches;
+use miden_objects::{
+    account::{AccountDelta, AccountStorageDelta, AccountVaultDelta},
+    asset::FungibleAsset,
+    note::NoteType,
+    testing::account_id::ACCOUNT_ID_SENDER,
+};
+use miden_testing::{Auth, MockChain};
+use miden_tx::{
+    AuthenticationError, TransactionExecutor,
+    auth::{
+        BasicAuthenticator, SignerAddress, SignerServer, SocketAuthenticator,
+        TransactionAuthenticator,
+    },
+};
+use rand::rngs::StdRng;
+use vm_processor::{Word, ZERO};
+
+use crate::prove_and_verify_transaction;
+
+/// Tests that a transaction can be signed by a signer server over a TCP connection, and that
+/// requests for keys unknown to the signer are rejected.
+#[test]
+fn socket_authenticator_signs_transaction() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain.add_pending_p2id_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    mock_chain.prove_next_block()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &[note.id()], &[])?.build()?;
+
+    // the keys are held only by the signer server
+    let execute = |authenticator: &SocketAuthenticator| {
+        TransactionExecutor::new(&tx_context, Some(authenticator)).execute_transaction(
+            account.id(),
+            tx_context.tx_inputs().block_header().block_num(),
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            tx_context.source_manager(),
+        )
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = SignerAddress::Tcp(listener.local_addr()?);
+    let server = SignerServer::new(tx_context.authenticator().cloned().unwrap());
+    thread::spawn(move || server.serve_tcp(listener));
+
+    let authenticator = SocketAuthenticator::new(address).with_timeout(Duration::from_secs(10));
+    let executed_transaction = execute(&authenticator)?;
+    let account_delta = executed_transaction.account_delta().clone();
+    prove_and_verify_transaction(executed_transaction)?;
+
+    let result = authenticator.get_signature(Word::default(), Word::default(), &account_delta);
+    assert_matches!(result, Err(AuthenticationError::UnknownPublicKey(_)));
+
+    // execution fails if the signer cannot be reached
+    let unreachable_address = TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+    let result = execute(&SocketAuthenticator::new(SignerAddress::Tcp(unreachable_address)));
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+/// Tests that a client which connects to the signer server but never sends a request does not
+/// block the server from serving other clients.
+#[test]
+fn signer_server_closes_idle_connections() -> anyhow::Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+    let server = SignerServer::new(BasicAuthenticator::<StdRng>::new(&[]))
+        .with_timeout(Duration::from_millis(200));
+    thread::spawn(move || server.serve_tcp(listener));
+
+    // the idle client keeps its connection open for longer than the server timeout
+    let idle_client = TcpStream::connect(address)?;
+
+    let account_delta = AccountDelta::new(
+        ACCOUNT_ID_SENDER.try_into()?,
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ZERO,
+    )?;
+    let authenticator =
+        SocketAuthenticator::new(SignerAddress::Tcp(address)).with_timeout(Duration::from_secs(10));
+    let result = authenticator.get_signature(Word::default(), Word::default(), &account_delta);
+    assert_matches!(result, Err(AuthenticationError::UnknownPublicKey(_)));
+
+    drop(idle_client);
+    Ok(())
+}
@@ -1,6 +1,7 @@
 extern crate alloc;
 
 mod auth;
+mod prover;
 mod scripts;
 mod wallet;
 
@@ -26,16 +27,19 @@ use vm_processor::utils::Deserializable;
 #[macro_export]
 macro_rules! assert_transaction_executor_error {
     ($execution_result:expr, $expected_err:expr) => {
-        match $execution_result {
-            Err(miden_tx::TransactionExecutorError::TransactionProgramExecutionFailed(
-                vm_processor::ExecutionError::FailedAssertion {
-                    label: _,
-                    source_file: _,
-                    clk: _,
-                    err_code,
-                    err_msg,
-                },
-            )) => {
+        match $execution_result.map_err(|err| match err {
+            miden_tx::TransactionExecutorError::TransactionProgramExecutionFailed(failure) => {
+                Ok(failure.into_error())
+            },
+            err => Err(err),
+        }) {
+            Err(Ok(vm_processor::ExecutionError::FailedAssertion {
+                label: _,
+                source_file: _,
+                clk: _,
+                err_code,
+                err_msg,
+            })) => {
                 if let Some(ref msg) = err_msg {
                   assert_eq!(msg.as_ref(), $expected_err.message(), "error messages did not match");
                 }
@@ -46,7 +50,8 @@ macro_rules! assert_transaction_executor_error {
                   err_code, err_msg.as_ref().map(|string| string.as_ref()).unwrap_or("<no message>"), $expected_err);
             },
             Ok(_) => panic!("Execution was unexpectedly successful"),
-            Err(err) => panic!("Execution error was not as expected: {err}"),
+            Err(Ok(err)) => panic!("Execution error was not as expected: {err}"),
+            Err(Err(err)) => panic!("Execution error was not as expected: {err}"),
         }
     };
 }
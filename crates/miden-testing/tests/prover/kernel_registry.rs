@@ -0,0 +1,58 @@
+use assert_matches::assert_matches;
+use miden_lib::transaction::{TransactionKernel, TransactionKernelRegistry};
+use miden_objects::{
+    Digest, MIN_PROOF_SECURITY_LEVEL, asset::FungibleAsset, note::NoteType,
+    testing::account_id::ACCOUNT_ID_SENDER,
+};
+use miden_testing::{Auth, MockChain};
+use miden_tx::{
+    LocalTransactionProver, ProvingOptions, TransactionProver, TransactionVerifier,
+    TransactionVerifierError,
+};
+
+/// Tests that a transaction referencing a block created before an upgrade of the kernel can be
+/// proven and verified using a registry which also contains the upgraded kernel.
+#[test]
+fn prove_and_verify_with_upgraded_kernel_registry() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain.add_pending_p2id_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    mock_chain.prove_next_block()?;
+
+    // register the built-in kernel a second time to simulate an upgrade of the kernel
+    let latest = TransactionKernelRegistry::default().latest().clone();
+    let kernel_registry = TransactionKernelRegistry::default().with_kernel(
+        latest.library().clone(),
+        latest.main().clone(),
+        latest.tx_script_main().clone(),
+        latest.procedures().to_vec(),
+    );
+    let pre_upgrade_commitment = TransactionKernel::kernel_commitment();
+    assert_eq!(kernel_registry.select(pre_upgrade_commitment).unwrap().version(), 0);
+
+    let executed_transaction = mock_chain
+        .build_tx_context(account.id(), &[note.id()], &[])?
+        .build()?
+        .execute()?;
+    let proven_transaction = LocalTransactionProver::new(ProvingOptions::default())
+        .with_kernel_registry(kernel_registry.clone())
+        .prove(executed_transaction.into())?;
+
+    let verifier =
+        TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL).with_kernel_registry(kernel_registry);
+    verifier.verify(&proven_transaction)?;
+    verifier.verify_with_kernel_commitment(&proven_transaction, pre_upgrade_commitment)?;
+
+    let err = verifier
+        .verify_with_kernel_commitment(&proven_transaction, Digest::default())
+        .unwrap_err();
+    assert_matches!(err, TransactionVerifierError::UnsupportedTransactionKernel(commitment)
+        if commitment == Digest::default());
+
+    Ok(())
+}
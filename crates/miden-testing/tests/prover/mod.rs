@@ -0,0 +1,4 @@
+mod kernel_registry;
+mod proving_pool;
+mod remote_prover;
+mod verify_many;
@@ -0,0 +1,142 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+};
+
+use assert_matches::assert_matches;
+use miden_objects::{
+    MIN_PROOF_SECURITY_LEVEL,
+    asset::FungibleAsset,
+    note::NoteType,
+    testing::account_id::ACCOUNT_ID_SENDER,
+    transaction::{ExecutedTransaction, TransactionWitness},
+};
+use miden_testing::{Auth, MockChain};
+use miden_tx::{
+    LocalTransactionProver, ProvingJobStatus, ProvingOptions, ProvingPool, TransactionProverError,
+    TransactionVerifier,
+};
+use vm_processor::AdviceInputs;
+
+/// Tests that a proving pool proves transactions in parallel, reports the status of every job and
+/// returns the results in submission order.
+#[test]
+fn proving_pool_proves_transactions() -> anyhow::Result<()> {
+    let executed_transaction = execute_transaction()?;
+    let executed_transaction_id = executed_transaction.id();
+
+    // without the advice witness, the signature of the transaction is missing and so executing
+    // the transaction program fails
+    let valid_witness = TransactionWitness::from(executed_transaction);
+    let invalid_witness = TransactionWitness {
+        advice_witness: AdviceInputs::default(),
+        ..valid_witness.clone()
+    };
+
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let listener_updates = updates.clone();
+    let pool = ProvingPool::new(LocalTransactionProver::new(ProvingOptions::default()))
+        .with_num_workers(NonZeroUsize::new(2).unwrap())
+        .with_progress_listener(Arc::new(move |job, status| {
+            listener_updates.lock().unwrap().push((job, status));
+        }));
+
+    let results = pool.prove_all([invalid_witness, valid_witness]);
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_err());
+    let proven_transaction = results[1].as_ref().unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+    TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL).verify(proven_transaction)?;
+
+    let updates = updates.lock().unwrap();
+    let job_statuses = |job| {
+        updates
+            .iter()
+            .filter(|(update_job, _)| *update_job == job)
+            .map(|(_, status)| *status)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        job_statuses(0),
+        [ProvingJobStatus::Queued, ProvingJobStatus::Executing, ProvingJobStatus::Failed]
+    );
+    assert_eq!(
+        job_statuses(1),
+        [
+            ProvingJobStatus::Queued,
+            ProvingJobStatus::Executing,
+            ProvingJobStatus::Proving,
+            ProvingJobStatus::Done
+        ]
+    );
+
+    Ok(())
+}
+
+/// Tests that a job which panics is reported as failed without affecting the other jobs of the
+/// pool.
+#[test]
+fn proving_pool_reports_panicking_job_as_failed() -> anyhow::Result<()> {
+    let executed_transaction = execute_transaction()?;
+    let executed_transaction_id = executed_transaction.id();
+    let witness = TransactionWitness::from(executed_transaction);
+
+    // the listener panics once the first job starts proving, which unwinds through the prover
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let listener_updates = updates.clone();
+    let pool = ProvingPool::new(LocalTransactionProver::new(ProvingOptions::default()))
+        .with_num_workers(NonZeroUsize::new(1).unwrap())
+        .with_progress_listener(Arc::new(move |job, status| {
+            listener_updates.lock().unwrap().push((job, status));
+            if job == 0 && status == ProvingJobStatus::Proving {
+                panic!("listener panicked");
+            }
+        }));
+
+    let results = pool.prove_all([witness.clone(), witness]);
+    assert_eq!(results.len(), 2);
+    assert_matches!(
+        &results[0],
+        Err(TransactionProverError::ProvingPanicked(message)) if message == "listener panicked"
+    );
+    assert_eq!(results[1].as_ref().unwrap().id(), executed_transaction_id);
+
+    let updates = updates.lock().unwrap();
+    assert_eq!(
+        updates
+            .iter()
+            .filter(|(job, _)| *job == 0)
+            .map(|(_, status)| *status)
+            .collect::<Vec<_>>(),
+        [
+            ProvingJobStatus::Queued,
+            ProvingJobStatus::Executing,
+            ProvingJobStatus::Proving,
+            ProvingJobStatus::Failed
+        ]
+    );
+    assert_eq!(updates.last(), Some(&(1, ProvingJobStatus::Done)));
+
+    Ok(())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes a transaction consuming a P2ID note against a new wallet.
+fn execute_transaction() -> anyhow::Result<ExecutedTransaction> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain.add_pending_p2id_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    mock_chain.prove_next_block()?;
+
+    Ok(mock_chain
+        .build_tx_context(account.id(), &[note.id()], &[])?
+        .build()?
+        .execute()?)
+}
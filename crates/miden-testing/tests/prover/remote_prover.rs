@@ -0,0 +1,165 @@
+use std::{
+    net::{TcpListener, TcpStream},
+    thread,
+    time::Duration,
+};
+
+use assert_matches::assert_matches;
+use miden_objects::{
+    MIN_PROOF_SECURITY_LEVEL,
+    asset::FungibleAsset,
+    note::NoteType,
+    testing::account_id::ACCOUNT_ID_SENDER,
+    transaction::{ExecutedTransaction, ProvenTransaction, TransactionWitness},
+};
+use miden_testing::{Auth, MockChain, ProvenTransactionExt};
+use miden_tx::{
+    LocalTransactionProver, ProverServer, ProvingOptions, RemoteTransactionProver, ServiceAddress,
+    TransactionProver, TransactionProverError, TransactionVerifier,
+};
+
+/// A prover which responds to every request with the same proven transaction.
+struct FixedProver(ProvenTransaction);
+
+impl TransactionProver for FixedProver {
+    fn prove(
+        &self,
+        _tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Returns transactions executed against two different wallets.
+fn execute_wallet_transactions() -> anyhow::Result<[ExecutedTransaction; 2]> {
+    let mut mock_chain = MockChain::new();
+    let accounts = [
+        mock_chain.add_pending_existing_wallet(Auth::IncrNonce, vec![]),
+        mock_chain.add_pending_existing_wallet(Auth::IncrNonce, vec![]),
+    ];
+    mock_chain.prove_next_block()?;
+
+    let execute = |account_id| -> anyhow::Result<ExecutedTransaction> {
+        Ok(mock_chain.build_tx_context(account_id, &[], &[])?.build()?.execute()?)
+    };
+    Ok([execute(accounts[0].id())?, execute(accounts[1].id())?])
+}
+
+/// Spawns a prover server responding with the provided proven transaction on a new TCP listener,
+/// and returns the address of the listener.
+fn spawn_fixed_prover_server(
+    proven_transaction: ProvenTransaction,
+    timeout: Duration,
+) -> anyhow::Result<ServiceAddress> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+    let server = ProverServer::new(FixedProver(proven_transaction)).with_timeout(timeout);
+    thread::spawn(move || server.serve_tcp(listener));
+
+    Ok(ServiceAddress::Tcp(address))
+}
+
+/// Tests that a transaction can be proven by a prover server over a TCP connection, and that
+/// connection failures are retried.
+#[test]
+fn remote_prover_proves_transaction() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain.add_pending_p2id_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    mock_chain.prove_next_block()?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(account.id(), &[note.id()], &[])?
+        .build()?
+        .execute()?;
+    let executed_transaction_id = executed_transaction.id();
+
+    // the server starts listening only after the first attempts of the client have failed
+    let address = TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(200));
+        let listener = TcpListener::bind(address)?;
+        ProverServer::new(LocalTransactionProver::new(ProvingOptions::default()))
+            .serve_tcp(listener)
+    });
+
+    let prover = RemoteTransactionProver::new(ServiceAddress::Tcp(address))
+        .with_timeout(Duration::from_secs(600))
+        .with_retries(50, Duration::from_millis(100));
+    let proven_transaction = prover.prove(executed_transaction.into())?;
+
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+    TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL).verify(&proven_transaction)?;
+
+    Ok(())
+}
+
+/// Tests that proving fails once all attempts to reach the prover have failed.
+#[test]
+fn remote_prover_fails_if_prover_is_unreachable() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::IncrNonce, vec![]);
+    mock_chain.prove_next_block()?;
+
+    let executed_transaction =
+        mock_chain.build_tx_context(account.id(), &[], &[])?.build()?.execute()?;
+
+    let unreachable_address = TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+    let prover = RemoteTransactionProver::new(ServiceAddress::Tcp(unreachable_address))
+        .with_retries(2, Duration::from_millis(10));
+
+    let result = prover.prove(executed_transaction.into());
+    assert_matches!(
+        result,
+        Err(TransactionProverError::RemoteProverConnectionFailed { attempts: 3, .. })
+    );
+
+    Ok(())
+}
+
+/// Tests that a client which connects to the prover server but never sends a request does not
+/// block the server from serving other clients.
+#[test]
+fn prover_server_closes_idle_connections() -> anyhow::Result<()> {
+    let [executed_transaction, _] = execute_wallet_transactions()?;
+    let proven_transaction =
+        ProvenTransaction::from_executed_transaction_mocked(executed_transaction.clone());
+    let address =
+        spawn_fixed_prover_server(proven_transaction.clone(), Duration::from_millis(200))?;
+
+    // the idle client keeps its connection open for longer than the server timeout
+    let ServiceAddress::Tcp(tcp_address) = address else {
+        unreachable!("address should be a TCP address");
+    };
+    let idle_client = TcpStream::connect(tcp_address)?;
+
+    let prover = RemoteTransactionProver::new(address).with_timeout(Duration::from_secs(10));
+    let response = prover.prove(executed_transaction.into())?;
+    assert_eq!(response, proven_transaction);
+
+    drop(idle_client);
+    Ok(())
+}
+
+/// Tests that a proven transaction which does not match the submitted witness is rejected.
+#[test]
+fn remote_prover_rejects_mismatching_response() -> anyhow::Result<()> {
+    let [executed_transaction, other_transaction] = execute_wallet_transactions()?;
+    let other_transaction = ProvenTransaction::from_executed_transaction_mocked(other_transaction);
+    let address = spawn_fixed_prover_server(other_transaction.clone(), Duration::from_secs(10))?;
+
+    let prover = RemoteTransactionProver::new(address).with_timeout(Duration::from_secs(10));
+    let result = prover.prove(executed_transaction.into());
+    assert_matches!(
+        result,
+        Err(TransactionProverError::RemoteProverResponseMismatch { transaction_id, field: "account ID" })
+            if transaction_id == other_transaction.id()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use assert_matches::assert_matches;
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    Digest, MIN_PROOF_SECURITY_LEVEL,
+    asset::FungibleAsset,
+    note::NoteType,
+    testing::account_id::ACCOUNT_ID_SENDER,
+    transaction::{ProvenTransaction, ProvenTransactionBuilder},
+};
+use miden_testing::{Auth, MockChain};
+use miden_tx::{
+    LocalTransactionProver, ProvingOptions, TransactionProver, TransactionVerifier,
+    TransactionVerifierError,
+};
+
+/// Tests that verifying many transactions succeeds if all proofs are valid, and otherwise reports
+/// every failure in the order in which the transactions were provided.
+#[test]
+fn verify_many_reports_all_failures() -> anyhow::Result<()> {
+    let proven_transaction = prove_transaction()?;
+    let tx_kernel_commitment = TransactionKernel::kernel_commitment();
+    let transactions = [
+        (proven_transaction.clone(), tx_kernel_commitment),
+        (proven_transaction, tx_kernel_commitment),
+    ];
+
+    TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL)
+        .verify_many(&transactions)
+        .expect("all transactions should verify");
+
+    // no proof can satisfy the maximum security level, so every transaction fails to verify
+    let failures = TransactionVerifier::new(u32::MAX).verify_many(&transactions).unwrap_err();
+    assert_eq!(failures.len(), 2);
+    for ((tx_id, err), (transaction, _)) in failures.iter().zip(&transactions) {
+        assert_eq!(*tx_id, transaction.id());
+        assert_matches!(
+            err,
+            TransactionVerifierError::InsufficientProofSecurityLevel { expected_minimum, .. }
+                if *expected_minimum == u32::MAX
+        );
+    }
+
+    Ok(())
+}
+
+/// Tests that verifying a mix of valid and tampered transactions reports exactly the tampered
+/// transactions.
+#[test]
+fn verify_many_reports_tampered_transactions() -> anyhow::Result<()> {
+    let proven_transaction = prove_transaction()?;
+    let tampered_transaction0 = with_final_state_commitment(&proven_transaction, [1, 2, 3, 4u32])?;
+    let tampered_transaction1 = with_final_state_commitment(&proven_transaction, [5, 6, 7, 8u32])?;
+    let transactions = [
+        proven_transaction.clone(),
+        tampered_transaction0.clone(),
+        proven_transaction.clone(),
+        tampered_transaction1.clone(),
+    ]
+    .map(|tx| (tx, TransactionKernel::kernel_commitment()));
+
+    let failures = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL)
+        .verify_many(&transactions)
+        .unwrap_err();
+    assert_eq!(
+        failures.iter().map(|(tx_id, _)| *tx_id).collect::<Vec<_>>(),
+        [tampered_transaction0.id(), tampered_transaction1.id()]
+    );
+    for (_, err) in failures {
+        assert_matches!(err, TransactionVerifierError::TransactionVerificationFailed(_));
+    }
+
+    Ok(())
+}
+
+/// Tests that a transaction whose reference block does not support any registered kernel is
+/// reported as failed.
+#[test]
+fn verify_many_reports_unsupported_transaction_kernel() -> anyhow::Result<()> {
+    let proven_transaction = prove_transaction()?;
+    let unknown_commitment = Digest::from([1, 2, 3, 4u32]);
+
+    let failures = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL)
+        .verify_many(&[(proven_transaction, unknown_commitment)])
+        .unwrap_err();
+    assert_matches!(
+        failures.as_slice(),
+        [(_, TransactionVerifierError::UnsupportedTransactionKernel(commitment))]
+            if *commitment == unknown_commitment
+    );
+
+    Ok(())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Proves a transaction consuming a P2ID note against a new wallet.
+fn prove_transaction() -> anyhow::Result<ProvenTransaction> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain.add_pending_p2id_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    mock_chain.prove_next_block()?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(account.id(), &[note.id()], &[])?
+        .build()?
+        .execute()?;
+
+    Ok(
+        LocalTransactionProver::new(ProvingOptions::default())
+            .prove(executed_transaction.into())?,
+    )
+}
+
+/// Returns a copy of the provided transaction with the provided final account state commitment,
+/// which no longer matches the proof of the transaction.
+fn with_final_state_commitment(
+    transaction: &ProvenTransaction,
+    final_state_commitment: [u32; 4],
+) -> anyhow::Result<ProvenTransaction> {
+    let account_update = transaction.account_update();
+    let tampered_transaction = ProvenTransactionBuilder::new(
+        transaction.account_id(),
+        account_update.initial_state_commitment(),
+        Digest::from(final_state_commitment),
+        account_update.account_delta_commitment(),
+        transaction.ref_block_num(),
+        transaction.ref_block_commitment(),
+        transaction.expiration_block_num(),
+        transaction.fee(),
+        transaction.proof().clone(),
+    )
+    .account_update_details(account_update.details().clone())
+    .add_input_notes(transaction.input_notes().iter().cloned())
+    .add_output_notes(transaction.output_notes().iter().cloned())
+    .build()?;
+
+    Ok(tampered_transaction)
+}
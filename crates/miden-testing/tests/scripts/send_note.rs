@@ -58,6 +58,54 @@ fn test_send_note_script_basic_wallet() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that the measurements of a transaction sending a note from a basic wallet count the
+/// creation of the note, the removal of the asset from the vault and the signature of the
+/// transaction.
+#[test]
+fn test_send_note_script_measurements() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let sender_basic_wallet_account =
+        mock_chain.add_pending_existing_wallet(Auth::BasicAuth, vec![FungibleAsset::mock(100)]);
+
+    let metadata = NoteMetadata::new(
+        sender_basic_wallet_account.id(),
+        NoteType::Public,
+        NoteTag::from_account_id(sender_basic_wallet_account.id()),
+        NoteExecutionHint::always(),
+        Default::default(),
+    )?;
+    let assets = NoteAssets::new(vec![FungibleAsset::mock(10)])?;
+    let note_script = NoteScript::compile("begin nop end", TransactionKernel::testing_assembler())?;
+    let serial_num =
+        RpoRandomCoin::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]).draw_word();
+    let recipient = NoteRecipient::new(serial_num, note_script, NoteInputs::default());
+    let note = Note::new(assets, metadata, recipient);
+
+    let send_note_transaction_script = AccountInterface::from(&sender_basic_wallet_account)
+        .build_send_notes_script(&[note.clone().into()], None, false)?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(sender_basic_wallet_account.id(), &[], &[])?
+        .tx_script(send_note_transaction_script)
+        .extend_expected_output_notes(vec![OutputNote::Full(note)])
+        .build()?
+        .execute()?;
+
+    // the asset is moved from the vault to the note, and the transaction is signed by the key
+    // read from the storage of the auth component
+    let measurements = executed_transaction.measurements();
+    assert_eq!(measurements.output_note_creation.len(), 1);
+    assert!(measurements.output_note_creation[0] > 0);
+    assert_eq!(measurements.vault_adds, 0);
+    assert_eq!(measurements.vault_removes, 1);
+    assert_eq!(measurements.storage_reads, 1);
+    assert_eq!(measurements.storage_writes, 0);
+    assert_eq!(measurements.provided_signatures, 1);
+    assert!(measurements.foreign_account_execution.is_empty());
+
+    Ok(())
+}
+
 /// Tests the execution of the generated send_note transaction script in case the sending account
 /// has the [`BasicFungibleFaucet`][faucet] interface.
 ///
@@ -1,4 +1,4 @@
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 
 use miden_objects::{
     ProvenBatchError,
@@ -27,31 +27,56 @@ impl LocalBatchProver {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - a proof of any transaction in the batch fails to verify.
+    /// - the reference block of any transaction is neither the reference block of the batch nor
+    ///   contained in the partial blockchain of the batch.
+    /// - the proofs of any transactions in the batch fail to verify against the transaction kernel
+    ///   supported by their reference block. The error contains all transactions which failed to
+    ///   verify, not just the first one.
     pub fn prove(&self, proposed_batch: ProposedBatch) -> Result<ProvenBatch, ProvenBatchError> {
         let tx_headers = proposed_batch.transaction_headers();
         let (
             transactions,
             block_header,
-            _block_chain,
+            partial_blockchain,
             _authenticatable_unauthenticated_notes,
             id,
             updated_accounts,
             input_notes,
             output_notes,
             batch_expiration_block_num,
+            fee,
         ) = proposed_batch.into_parts();
 
+        // verify every transaction against the kernel supported by its reference block, which is
+        // either the reference block of the batch or contained in the partial blockchain
+        let transactions = transactions
+            .into_iter()
+            .map(|tx| {
+                let tx_ref_block = if tx.ref_block_num() == block_header.block_num() {
+                    &block_header
+                } else {
+                    partial_blockchain.get_block(tx.ref_block_num()).ok_or_else(|| {
+                        ProvenBatchError::MissingTransactionBlockReference {
+                            block_reference: tx.ref_block_commitment(),
+                            transaction_id: tx.id(),
+                        }
+                    })?
+                };
+                let tx_kernel_commitment = tx_ref_block.tx_kernel_commitment();
+                Ok((tx, tx_kernel_commitment))
+            })
+            .collect::<Result<Vec<_>, ProvenBatchError>>()?;
+
         let verifier = TransactionVerifier::new(self.proof_security_level);
 
-        for tx in transactions {
-            verifier.verify(&tx).map_err(|source| {
-                ProvenBatchError::TransactionVerificationFailed {
-                    transaction_id: tx.id(),
-                    source: Box::new(source),
-                }
-            })?;
-        }
+        verifier.verify_many(&transactions).map_err(|failures| {
+            ProvenBatchError::TransactionVerificationFailed {
+                failures: failures
+                    .into_iter()
+                    .map(|(tx_id, source)| (tx_id, Box::new(source) as Box<_>))
+                    .collect(),
+            }
+        })?;
 
         ProvenBatch::new(
             id,
@@ -61,6 +86,7 @@ impl LocalBatchProver {
             input_notes,
             output_notes,
             batch_expiration_block_num,
+            fee,
             tx_headers,
         )
     }
@@ -1,4 +1,17 @@
 mod tx_authenticator;
 pub use tx_authenticator::{BasicAuthenticator, TransactionAuthenticator};
 
+mod multisig_authenticator;
+pub use multisig_authenticator::MultisigAuthenticator;
+
+mod policy_authenticator;
+pub use policy_authenticator::{
+    ForbiddenStorageSlots, MaxFungibleOutflow, MaxNonceDelta, PolicyAuthenticator, PolicyRule,
+};
+
+#[cfg(feature = "std")]
+pub mod socket_signer;
+#[cfg(feature = "std")]
+pub use socket_signer::{SignerAddress, SignerServer, SocketAuthenticator};
+
 pub mod signatures;
@@ -0,0 +1,117 @@
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+
+use miden_objects::account::AccountDelta;
+use vm_processor::{Digest, Felt, Word};
+
+use super::TransactionAuthenticator;
+use crate::errors::AuthenticationError;
+
+// MULTISIG AUTHENTICATOR
+// ================================================================================================
+
+/// A [TransactionAuthenticator] which collects the signatures required by a k-of-n multisig
+/// account from the individual keyholders.
+///
+/// Each keyholder is represented by its own authenticator (e.g., a
+/// [`BasicAuthenticator`](super::BasicAuthenticator) holding the keyholder's key or a
+/// `SocketAuthenticator` forwarding to the keyholder's signer), and is identified by the
+/// commitment to its public key.
+///
+/// During execution, the
+/// [`RpoFalcon512Multisig`](miden_lib::account::auth::RpoFalcon512Multisig) component requests a
+/// signature from each approver in turn until the threshold is reached, and this authenticator
+/// forwards each request to the respective keyholder. Keyholders which refuse to sign are skipped
+/// by the component, so the transaction succeeds as long as enough keyholders sign it.
+///
+/// When signatures are collected out of band (e.g., after
+/// [`TransactionExecutor::execute_until_signature`](crate::TransactionExecutor::execute_until_signature)),
+/// [MultisigAuthenticator::collect_signatures] can be used to obtain the signatures of the first
+/// `threshold` keyholders which agree to sign, which can then be passed to
+/// [`TransactionExecutor::resume_transaction_with_signatures`](crate::TransactionExecutor::resume_transaction_with_signatures).
+#[derive(Default)]
+pub struct MultisigAuthenticator {
+    keyholders: BTreeMap<Digest, Box<dyn TransactionAuthenticator>>,
+}
+
+impl MultisigAuthenticator {
+    /// Returns a new [MultisigAuthenticator] without any keyholders.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a keyholder with the provided public key commitment, which signs using the provided
+    /// authenticator.
+    ///
+    /// If a keyholder with the same public key was added before, it is replaced.
+    pub fn with_keyholder(
+        mut self,
+        pub_key: Word,
+        authenticator: impl TransactionAuthenticator + 'static,
+    ) -> Self {
+        self.keyholders.insert(pub_key.into(), Box::new(authenticator));
+        self
+    }
+
+    /// Returns the number of keyholders.
+    pub fn num_keyholders(&self) -> usize {
+        self.keyholders.len()
+    }
+
+    /// Requests a signature over the message from the keyholders until `threshold` of them have
+    /// signed, and returns the public keys of the signing keyholders together with their
+    /// signatures.
+    ///
+    /// # Errors
+    /// Returns [AuthenticationError::RejectedSignature] if fewer than `threshold` keyholders
+    /// provided a signature.
+    pub fn collect_signatures(
+        &self,
+        threshold: usize,
+        message: Word,
+        account_delta: &AccountDelta,
+    ) -> Result<Vec<(Word, Vec<Felt>)>, AuthenticationError> {
+        let mut signatures = Vec::with_capacity(threshold);
+        for (pub_key, authenticator) in &self.keyholders {
+            if signatures.len() == threshold {
+                break;
+            }
+
+            let pub_key = Word::from(*pub_key);
+            if let Ok(signature) = authenticator.get_signature(pub_key, message, account_delta) {
+                signatures.push((pub_key, signature));
+            }
+        }
+
+        if signatures.len() < threshold {
+            return Err(AuthenticationError::RejectedSignature(format!(
+                "only {} of the required {threshold} keyholders provided a signature",
+                signatures.len()
+            )));
+        }
+
+        Ok(signatures)
+    }
+}
+
+impl TransactionAuthenticator for MultisigAuthenticator {
+    /// Requests a signature from the keyholder with the provided public key.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No keyholder with the provided public key was added to this authenticator.
+    /// - The keyholder fails to provide the signature.
+    fn get_signature(
+        &self,
+        pub_key: Word,
+        message: Word,
+        account_delta: &AccountDelta,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        match self.keyholders.get(&Digest::from(pub_key)) {
+            Some(authenticator) => authenticator.get_signature(pub_key, message, account_delta),
+            None => Err(AuthenticationError::UnknownPublicKey(format!(
+                "public key {} is not held by any keyholder of the multisig authenticator",
+                Digest::from(pub_key)
+            ))),
+        }
+    }
+}
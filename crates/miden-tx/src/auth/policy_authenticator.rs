@@ -0,0 +1,255 @@
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+
+use miden_objects::account::{AccountDelta, AccountId};
+use vm_processor::{Felt, Word};
+
+use super::TransactionAuthenticator;
+use crate::errors::AuthenticationError;
+
+// POLICY RULE
+// ================================================================================================
+
+/// A rule which must be satisfied by the changes made to an account for a [PolicyAuthenticator]
+/// to sign a transaction.
+pub trait PolicyRule: Send + Sync {
+    /// Checks the provided account delta against this rule.
+    ///
+    /// # Errors
+    /// Returns a description of the violation if the delta does not satisfy the rule.
+    fn check(&self, account_delta: &AccountDelta) -> Result<(), String>;
+}
+
+/// Limits the amounts of fungible assets which may be removed from the account vault.
+///
+/// The limits apply to the net change of the vault, i.e., assets added to the vault by the same
+/// transaction (e.g., by consuming a note) offset the assets removed from it. Assets issued by
+/// faucets without a limit are not restricted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MaxFungibleOutflow {
+    limits: BTreeMap<AccountId, u64>,
+}
+
+impl MaxFungibleOutflow {
+    /// Returns a new [MaxFungibleOutflow] rule without any limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the amount of the fungible asset issued by the specified faucet which may be
+    /// removed from the account vault to `max_amount`.
+    pub fn with_limit(mut self, faucet_id: AccountId, max_amount: u64) -> Self {
+        self.limits.insert(faucet_id, max_amount);
+        self
+    }
+}
+
+impl PolicyRule for MaxFungibleOutflow {
+    fn check(&self, account_delta: &AccountDelta) -> Result<(), String> {
+        for (faucet_id, max_amount) in &self.limits {
+            let outflow = match account_delta.vault().fungible().amount(faucet_id) {
+                Some(amount) if amount < 0 => amount.unsigned_abs(),
+                _ => continue,
+            };
+
+            if outflow > *max_amount {
+                return Err(format!(
+                    "outflow of {outflow} units of the asset issued by faucet {faucet_id} \
+                     exceeds the limit of {max_amount}"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Forbids any changes to the specified storage slots, including changes to the entries of
+/// storage maps.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForbiddenStorageSlots {
+    slots: BTreeSet<u8>,
+}
+
+impl ForbiddenStorageSlots {
+    /// Returns a new [ForbiddenStorageSlots] rule forbidding changes to the provided slots.
+    pub fn new(slots: impl IntoIterator<Item = u8>) -> Self {
+        Self { slots: slots.into_iter().collect() }
+    }
+}
+
+impl PolicyRule for ForbiddenStorageSlots {
+    fn check(&self, account_delta: &AccountDelta) -> Result<(), String> {
+        let storage_delta = account_delta.storage();
+        let updated_slots = storage_delta.values().keys().chain(storage_delta.maps().keys());
+
+        for slot_index in updated_slots {
+            if self.slots.contains(slot_index) {
+                return Err(format!("storage slot {slot_index} must not be modified"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Limits the amount by which the nonce of the account may be incremented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxNonceDelta(pub u64);
+
+impl PolicyRule for MaxNonceDelta {
+    fn check(&self, account_delta: &AccountDelta) -> Result<(), String> {
+        let nonce_delta = account_delta.nonce_delta().as_int();
+        if nonce_delta > self.0 {
+            return Err(format!("nonce delta of {nonce_delta} exceeds the maximum of {}", self.0));
+        }
+
+        Ok(())
+    }
+}
+
+// POLICY AUTHENTICATOR
+// ================================================================================================
+
+/// A [TransactionAuthenticator] which signs only transactions whose account delta satisfies a set
+/// of [PolicyRule]s.
+///
+/// Before forwarding a signature request to the wrapped authenticator, the `account_delta`
+/// describing the changes made to the account is checked against all rules. If any rule is
+/// violated, the request is rejected with [AuthenticationError::RejectedSignature] describing the
+/// violation.
+///
+/// The account delta is provided by the host executing the transaction. Thus, the rules protect
+/// against signing unintended transactions only if the host itself is trusted, e.g., when the
+/// signer and the executor run in the same process.
+pub struct PolicyAuthenticator<A> {
+    inner: A,
+    rules: Vec<Box<dyn PolicyRule>>,
+}
+
+impl<A: TransactionAuthenticator> PolicyAuthenticator<A> {
+    /// Returns a new [PolicyAuthenticator] without any rules which signs using the provided
+    /// authenticator.
+    pub fn new(inner: A) -> Self {
+        Self { inner, rules: Vec::new() }
+    }
+
+    /// Adds the provided rule to the rules enforced by this authenticator.
+    pub fn with_rule(mut self, rule: impl PolicyRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Returns a reference to the wrapped authenticator.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+}
+
+impl<A: TransactionAuthenticator> TransactionAuthenticator for PolicyAuthenticator<A> {
+    /// Checks the account delta against all rules and requests a signature from the wrapped
+    /// authenticator if all of them are satisfied.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the rules is violated by the account delta.
+    /// - The wrapped authenticator fails to provide the signature.
+    fn get_signature(
+        &self,
+        pub_key: Word,
+        message: Word,
+        account_delta: &AccountDelta,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        for rule in &self.rules {
+            rule.check(account_delta).map_err(|violation| {
+                AuthenticationError::RejectedSignature(format!(
+                    "account delta violates the signing policy: {violation}"
+                ))
+            })?;
+        }
+
+        self.inner.get_signature(pub_key, message, account_delta)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use miden_objects::{
+        ONE,
+        account::{AccountStorageDelta, AccountVaultDelta},
+        asset::FungibleAsset,
+        testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    };
+
+    use super::*;
+
+    /// An authenticator which signs every request with an empty signature.
+    struct AcceptAll;
+
+    impl TransactionAuthenticator for AcceptAll {
+        fn get_signature(
+            &self,
+            _pub_key: Word,
+            _message: Word,
+            _account_delta: &AccountDelta,
+        ) -> Result<Vec<Felt>, AuthenticationError> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn delta(
+        storage: AccountStorageDelta,
+        vault: AccountVaultDelta,
+        nonce_delta: u64,
+    ) -> AccountDelta {
+        let account_id = ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap();
+        AccountDelta::new(account_id, storage, vault, Felt::new(nonce_delta)).unwrap()
+    }
+
+    #[test]
+    fn policy_rules_are_enforced() {
+        let faucet_id = FungibleAsset::mock_issuer();
+        let authenticator = PolicyAuthenticator::new(AcceptAll)
+            .with_rule(MaxFungibleOutflow::new().with_limit(faucet_id, 100))
+            .with_rule(ForbiddenStorageSlots::new([0]))
+            .with_rule(MaxNonceDelta(1));
+        let sign = |delta: &AccountDelta| {
+            authenticator.get_signature(Word::default(), Word::default(), delta)
+        };
+
+        // outflows within the limit, net of inflows, are accepted
+        let vault =
+            AccountVaultDelta::from_iters([FungibleAsset::mock(50)], [FungibleAsset::mock(150)]);
+        assert!(sign(&delta(AccountStorageDelta::new(), vault, 1)).is_ok());
+
+        let vault = AccountVaultDelta::from_iters([], [FungibleAsset::mock(101)]);
+        assert_matches!(
+            sign(&delta(AccountStorageDelta::new(), vault, 1)),
+            Err(AuthenticationError::RejectedSignature(msg)) if msg.contains("exceeds the limit of 100")
+        );
+
+        let mut storage = AccountStorageDelta::new();
+        storage.set_map_item(0, Default::default(), [ONE; 4]);
+        assert_matches!(
+            sign(&delta(storage, AccountVaultDelta::default(), 1)),
+            Err(AuthenticationError::RejectedSignature(msg)) if msg.contains("storage slot 0")
+        );
+
+        let mut storage = AccountStorageDelta::new();
+        storage.set_item(1, [ONE; 4]);
+        assert!(sign(&delta(storage, AccountVaultDelta::default(), 1)).is_ok());
+
+        assert_matches!(
+            sign(&delta(AccountStorageDelta::new(), AccountVaultDelta::default(), 2)),
+            Err(AuthenticationError::RejectedSignature(msg)) if msg.contains("nonce delta of 2")
+        );
+    }
+}
@@ -0,0 +1,349 @@
+//! An authenticator which forwards signature requests to a signer running in a separate process.
+//!
+//! # Protocol
+//!
+//! The [SocketAuthenticator] and the signer communicate over a Unix domain socket or a TCP
+//! connection by exchanging frames. Each frame consists of the length of its payload in bytes,
+//! encoded as a little-endian `u32`, followed by the payload itself. A frame may contain at most
+//! [MAX_FRAME_LENGTH] bytes of payload.
+//!
+//! The payloads are encoded using the [`Serializable`] encoding of the Miden types: field elements
+//! are encoded as little-endian `u64`s, words as four consecutive field elements, and vectors and
+//! strings are prefixed with their length encoded as a variable-length integer.
+//!
+//! A request payload contains:
+//! - the protocol version ([PROTOCOL_VERSION]) as a `u8`.
+//! - the public key commitment of the key to sign with as a word.
+//! - the message to sign as a word.
+//! - the [AccountDelta] of the transaction up to the point of the signature request.
+//!
+//! A response payload contains the protocol version as a `u8`, followed by a status byte and a
+//! body depending on the status:
+//! - [STATUS_OK]: the signature was created; the body contains the signature as a vector of field
+//!   elements.
+//! - [STATUS_REJECTED]: the signer rejected the request; the body contains the reason as a string.
+//! - [STATUS_UNKNOWN_PUBLIC_KEY]: the signer does not manage the requested key; the body contains a
+//!   message as a string.
+//! - [STATUS_OTHER]: the signer failed for another reason; the body contains a message as a string.
+//!
+//! A connection may be used for several requests, each of which is answered by exactly one
+//! response before the next request is read. Either side may close the connection between
+//! requests.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::time::Duration;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::{
+    io::{self, ErrorKind, Read, Write},
+    net::TcpListener,
+};
+
+use miden_objects::{
+    account::AccountDelta,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+use vm_processor::{Felt, Word};
+
+use super::TransactionAuthenticator;
+use crate::{
+    errors::AuthenticationError,
+    transport::{ServiceAddress, read_frame, write_frame},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The version of the signer protocol implemented by this module.
+pub const PROTOCOL_VERSION: u8 = 1;
+
+/// The maximum length of the payload of a single frame in bytes.
+pub const MAX_FRAME_LENGTH: usize = 1 << 20;
+
+/// The default timeout for each read from or write to a connection served by a [SignerServer].
+pub const DEFAULT_SERVER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The response status of a created signature.
+pub const STATUS_OK: u8 = 0;
+
+/// The response status of a request which the signer rejected.
+pub const STATUS_REJECTED: u8 = 1;
+
+/// The response status of a request for a key which the signer does not manage.
+pub const STATUS_UNKNOWN_PUBLIC_KEY: u8 = 2;
+
+/// The response status of a request for which the signer failed for another reason.
+pub const STATUS_OTHER: u8 = 3;
+
+// SIGNER ADDRESS
+// ================================================================================================
+
+/// The address of a signer process.
+///
+/// Addresses are parsed from strings of the form `unix:<path>` for Unix domain sockets and
+/// `<host>:<port>` (e.g., `127.0.0.1:7000`) for TCP connections, which should usually use a
+/// loopback address.
+pub type SignerAddress = ServiceAddress;
+
+// SOCKET AUTHENTICATOR
+// ================================================================================================
+
+/// A [TransactionAuthenticator] which forwards signature requests to a signer process.
+///
+/// The secret keys are held only by the signer process, which can be served via [SignerServer].
+/// A new connection is opened for every signature request, so the signer may be restarted at any
+/// time between requests.
+#[derive(Debug, Clone)]
+pub struct SocketAuthenticator {
+    address: SignerAddress,
+    timeout: Option<Duration>,
+}
+
+impl SocketAuthenticator {
+    /// Returns a new [SocketAuthenticator] which connects to the signer at the provided address.
+    pub fn new(address: SignerAddress) -> Self {
+        Self { address, timeout: None }
+    }
+
+    /// Sets the timeout for connecting to the signer and for each read from or write to the
+    /// connection.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the address of the signer.
+    pub fn address(&self) -> &SignerAddress {
+        &self.address
+    }
+}
+
+impl TransactionAuthenticator for SocketAuthenticator {
+    /// Requests a signature over the message from the signer.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The connection to the signer failed or the signer sent a malformed response.
+    /// - The signer rejected the request or does not manage the requested key.
+    fn get_signature(
+        &self,
+        pub_key: Word,
+        message: Word,
+        account_delta: &AccountDelta,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        let io_error = |err| {
+            AuthenticationError::other_with_source(
+                format!("failed to communicate with signer at {}", self.address),
+                err,
+            )
+        };
+
+        let mut stream = self.address.connect(self.timeout).map_err(io_error)?;
+
+        let mut request = Vec::new();
+        request.write_u8(PROTOCOL_VERSION);
+        pub_key.write_into(&mut request);
+        message.write_into(&mut request);
+        account_delta.write_into(&mut request);
+        write_frame(&mut stream, &request, MAX_FRAME_LENGTH).map_err(io_error)?;
+
+        let response = read_frame(&mut stream, MAX_FRAME_LENGTH)
+            .map_err(io_error)?
+            .ok_or_else(|| io_error(ErrorKind::UnexpectedEof.into()))?;
+
+        SignatureResponse::read_from_bytes(&response)
+            .map_err(|err| {
+                AuthenticationError::other_with_source("signer sent a malformed response", err)
+            })?
+            .0
+    }
+}
+
+// SIGNER SERVER
+// ================================================================================================
+
+/// A signer which serves signature requests from [SocketAuthenticator]s using the wrapped
+/// [TransactionAuthenticator].
+///
+/// This is the reference implementation of the signer side of the protocol. Backed by a
+/// [`BasicAuthenticator`](super::BasicAuthenticator), it allows the secret keys to be held in a
+/// separate process from the one executing transactions.
+///
+/// Connections are served one at a time. To prevent a client which stops sending or receiving
+/// data from blocking all other clients, a connection is closed once a read from or a write to it
+/// takes longer than the server timeout (see [SignerServer::with_timeout]).
+pub struct SignerServer<A> {
+    authenticator: A,
+    timeout: Duration,
+}
+
+impl<A: TransactionAuthenticator> SignerServer<A> {
+    /// Returns a new [SignerServer] which signs requests using the provided authenticator.
+    ///
+    /// The server timeout is set to [DEFAULT_SERVER_TIMEOUT].
+    pub fn new(authenticator: A) -> Self {
+        Self {
+            authenticator,
+            timeout: DEFAULT_SERVER_TIMEOUT,
+        }
+    }
+
+    /// Sets the timeout for each read from or write to a served connection.
+    ///
+    /// Since a read blocks until the client sends its next request, this is also the maximum
+    /// time a client may keep an idle connection open.
+    ///
+    /// # Panics
+    /// Panics if the timeout is zero.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        assert!(!timeout.is_zero(), "server timeout must not be zero");
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the timeout for each read from or write to a served connection.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Serves connections accepted by the provided TCP listener until accepting a connection
+    /// fails.
+    ///
+    /// Connections which fail, violate the protocol or time out are closed without affecting the
+    /// server.
+    pub fn serve_tcp(&self, listener: TcpListener) -> io::Result<()> {
+        loop {
+            let (stream, _) = listener.accept()?;
+            let _ = stream
+                .set_read_timeout(Some(self.timeout))
+                .and_then(|_| stream.set_write_timeout(Some(self.timeout)))
+                .and_then(|_| self.handle_connection(stream));
+        }
+    }
+
+    /// Serves connections accepted by the provided Unix domain socket listener until accepting a
+    /// connection fails.
+    ///
+    /// Connections which fail, violate the protocol or time out are closed without affecting the
+    /// server.
+    #[cfg(unix)]
+    pub fn serve_unix(&self, listener: UnixListener) -> io::Result<()> {
+        loop {
+            let (stream, _) = listener.accept()?;
+            let _ = stream
+                .set_read_timeout(Some(self.timeout))
+                .and_then(|_| stream.set_write_timeout(Some(self.timeout)))
+                .and_then(|_| self.handle_connection(stream));
+        }
+    }
+
+    /// Serves requests received over the provided stream until it is closed by the client.
+    ///
+    /// # Errors
+    /// Returns an error if reading from or writing to the stream fails, or if the client sent a
+    /// malformed request.
+    pub fn handle_connection<S: Read + Write>(&self, mut stream: S) -> io::Result<()> {
+        while let Some(request) = read_frame(&mut stream, MAX_FRAME_LENGTH)? {
+            let response = match SignatureRequest::read_from_bytes(&request) {
+                Ok(request) => SignatureResponse(self.authenticator.get_signature(
+                    request.pub_key,
+                    request.message,
+                    &request.account_delta,
+                )),
+                Err(err) => return Err(io::Error::new(ErrorKind::InvalidData, err)),
+            };
+            write_frame(&mut stream, &response.to_bytes(), MAX_FRAME_LENGTH)?;
+        }
+
+        Ok(())
+    }
+}
+
+// MESSAGES
+// ================================================================================================
+
+/// A request for a signature, as sent by a [SocketAuthenticator].
+struct SignatureRequest {
+    pub_key: Word,
+    message: Word,
+    account_delta: AccountDelta,
+}
+
+impl Deserializable for SignatureRequest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        read_protocol_version(source)?;
+        let pub_key = Word::read_from(source)?;
+        let message = Word::read_from(source)?;
+        let account_delta = AccountDelta::read_from(source)?;
+
+        Ok(Self { pub_key, message, account_delta })
+    }
+}
+
+/// The response to a [SignatureRequest], as sent by a [SignerServer].
+struct SignatureResponse(Result<Vec<Felt>, AuthenticationError>);
+
+impl Serializable for SignatureResponse {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(PROTOCOL_VERSION);
+        match &self.0 {
+            Ok(signature) => {
+                target.write_u8(STATUS_OK);
+                signature.write_into(target);
+            },
+            Err(AuthenticationError::RejectedSignature(reason)) => {
+                target.write_u8(STATUS_REJECTED);
+                reason.write_into(target);
+            },
+            Err(AuthenticationError::UnknownPublicKey(message)) => {
+                target.write_u8(STATUS_UNKNOWN_PUBLIC_KEY);
+                message.write_into(target);
+            },
+            Err(err @ AuthenticationError::Other { .. }) => {
+                target.write_u8(STATUS_OTHER);
+                err.to_string().write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for SignatureResponse {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        read_protocol_version(source)?;
+        let result = match source.read_u8()? {
+            STATUS_OK => Ok(Vec::<Felt>::read_from(source)?),
+            STATUS_REJECTED => {
+                Err(AuthenticationError::RejectedSignature(String::read_from(source)?))
+            },
+            STATUS_UNKNOWN_PUBLIC_KEY => {
+                Err(AuthenticationError::UnknownPublicKey(String::read_from(source)?))
+            },
+            STATUS_OTHER => Err(AuthenticationError::other(String::read_from(source)?)),
+            status => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "unknown response status {status}"
+                )));
+            },
+        };
+
+        Ok(Self(result))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads the protocol version from the source and checks that it is supported.
+fn read_protocol_version<R: ByteReader>(source: &mut R) -> Result<(), DeserializationError> {
+    let version = source.read_u8()?;
+    if version != PROTOCOL_VERSION {
+        return Err(DeserializationError::InvalidValue(format!(
+            "unsupported signer protocol version {version}, expected {PROTOCOL_VERSION}"
+        )));
+    }
+
+    Ok(())
+}
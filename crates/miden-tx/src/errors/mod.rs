@@ -1,10 +1,12 @@
 use alloc::{boxed::Box, string::String};
-use core::error::Error;
+use core::{error::Error, ops::Range};
 
+use miden_lib::errors::DecodedError;
 use miden_objects::{
-    AccountError, Digest, Felt, ProvenTransactionError, TransactionInputError,
-    TransactionOutputError, account::AccountId, assembly::diagnostics::reporting::PrintDiagnostic,
-    block::BlockNumber, crypto::merkle::SmtProofError, note::NoteId,
+    AccountError, AccountIdError, AssetVaultError, Digest, Felt, ProvenTransactionError,
+    TransactionInputError, TransactionOutputError, account::AccountId,
+    assembly::diagnostics::reporting::PrintDiagnostic, block::BlockNumber,
+    crypto::merkle::SmtProofError, note::NoteId, transaction::TransactionId,
 };
 use miden_verifier::VerificationError;
 use thiserror::Error;
@@ -17,6 +19,10 @@ use vm_processor::ExecutionError;
 pub enum TransactionExecutorError {
     #[error("failed to fetch transaction inputs from the data store")]
     FetchTransactionInputsFailed(#[source] DataStoreError),
+    #[error("failed to fetch account data accessed by the transaction from the data store")]
+    FetchAccountDataFailed(#[source] DataStoreError),
+    #[error("account data accessed by the transaction could not be loaded: {0}")]
+    AccountDataNotLoaded(MissingAccountData),
     #[error("foreign account inputs for ID {0} are not anchored on reference block")]
     ForeignAccountNotAnchoredInReference(AccountId),
     #[error(
@@ -29,6 +35,12 @@ pub enum TransactionExecutorError {
     },
     #[error("failed to create transaction inputs")]
     InvalidTransactionInputs(#[source] TransactionInputError),
+    #[error("failed to apply account delta")]
+    AccountDeltaApplyFailed(#[source] AccountError),
+    #[error("data store did not provide the full state of the account")]
+    IncompleteAccountState(#[source] AccountError),
+    #[error("transaction execution was interrupted")]
+    ExecutionInterrupted(#[source] InterruptReason),
     #[error("failed to process account update commitment: {0}")]
     AccountUpdateCommitment(&'static str),
     #[error(
@@ -47,6 +59,10 @@ pub enum TransactionExecutorError {
     InconsistentAccountNonceDelta { expected: Felt, actual: Felt },
     #[error("account witness provided for account ID {0} is invalid")]
     InvalidAccountWitness(AccountId, #[source] SmtProofError),
+    #[error("storage map witness provided by the data store is invalid")]
+    InvalidStorageMapWitness(#[source] AccountError),
+    #[error("asset vault witness provided by the data store is invalid")]
+    InvalidVaultWitness(#[source] AssetVaultError),
     #[error(
         "input note {0} was created in a block past the transaction reference block number ({1})"
     )]
@@ -55,10 +71,147 @@ pub enum TransactionExecutorError {
     TransactionHostCreationFailed(#[source] TransactionHostError),
     #[error("failed to construct transaction outputs")]
     TransactionOutputConstructionFailed(#[source] TransactionOutputError),
-    // Print the diagnostic directly instead of returning the source error. In the source error
-    // case, the diagnostic is lost if the execution error is not explicitly unwrapped.
-    #[error("failed to execute transaction kernel program:\n{}", PrintDiagnostic::new(.0))]
-    TransactionProgramExecutionFailed(ExecutionError),
+    #[error("{0}")]
+    TransactionProgramExecutionFailed(Box<ExecutionFailure>),
+    #[error(
+        "reference block with transaction kernel commitment {0} does not support any registered transaction kernel"
+    )]
+    UnsupportedTransactionKernel(Digest),
+}
+
+// EXECUTION FAILURE
+// ================================================================================================
+
+/// A failed execution of the transaction kernel program, together with the context in which the
+/// execution failed.
+///
+/// In addition to the underlying [ExecutionError], the failure contains:
+/// - The error the code of a failed assertion was decoded into, via the
+///   [`ErrorCodeRegistry`](miden_lib::errors::ErrorCodeRegistry) of the executor.
+/// - The ID of the input note whose script was being executed when the failure occurred.
+/// - The name of the procedure in which the failure occurred, if it could be resolved from the
+///   state of the VM at the point of failure.
+///
+/// The procedure is resolved, in order of preference:
+/// - From the assembly debug information of the failing operation. This requires the VM to run in
+///   debug mode and the code containing the operation to be assembled in debug mode.
+/// - From the offset of the invoked kernel procedure, if the failure occurred inside the kernel.
+/// - From the MAST root of the procedure which started the current execution context (i.e., the
+///   procedure invoked via `call` or `dyncall`), if the procedure is exported by a library
+///   registered in the [`ErrorCodeRegistry`](miden_lib::errors::ErrorCodeRegistry) or is a
+///   well-known note script.
+///
+/// The state of the VM is not available when a transaction fails while being proven, so failures
+/// reported by the prover do not contain the procedure.
+#[derive(Debug)]
+pub struct ExecutionFailure {
+    error: ExecutionError,
+    decoded_error: Option<DecodedError>,
+    note_id: Option<NoteId>,
+    procedure: Option<String>,
+}
+
+impl ExecutionFailure {
+    /// Returns a new [ExecutionFailure] instantiated from the provided execution error and the
+    /// context in which the execution failed.
+    pub fn new(
+        error: ExecutionError,
+        decoded_error: Option<DecodedError>,
+        note_id: Option<NoteId>,
+        procedure: Option<String>,
+    ) -> Self {
+        Self { error, decoded_error, note_id, procedure }
+    }
+
+    /// Returns the underlying [ExecutionError].
+    pub fn error(&self) -> &ExecutionError {
+        &self.error
+    }
+
+    /// Consumes self and returns the underlying [ExecutionError].
+    pub fn into_error(self) -> ExecutionError {
+        self.error
+    }
+
+    /// Returns the error code of the failed assertion, if the execution failed due to one.
+    pub fn error_code(&self) -> Option<Felt> {
+        error_code(&self.error)
+    }
+
+    /// Returns the error the code of the failed assertion was decoded into, if the execution
+    /// failed due to an assertion with a known error code.
+    pub fn decoded_error(&self) -> Option<&DecodedError> {
+        self.decoded_error.as_ref()
+    }
+
+    /// Returns the ID of the input note whose script was being executed when the failure
+    /// occurred, if any.
+    pub fn note_id(&self) -> Option<NoteId> {
+        self.note_id
+    }
+
+    /// Returns the name of the procedure in which the failure occurred, if known.
+    pub fn procedure(&self) -> Option<&str> {
+        self.procedure.as_deref()
+    }
+}
+
+impl core::fmt::Display for ExecutionFailure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "failed to execute transaction kernel program")?;
+        if let Some(note_id) = self.note_id {
+            write!(f, " while executing note {note_id}")?;
+        }
+        if let Some(procedure) = &self.procedure {
+            write!(f, " in procedure `{procedure}`")?;
+        }
+        if let Some(decoded_error) = &self.decoded_error {
+            write!(f, " with error {decoded_error}")?;
+        }
+        // Print the diagnostic directly instead of returning the source error. In the source error
+        // case, the diagnostic is lost if the execution error is not explicitly unwrapped.
+        write!(f, ":\n{}", PrintDiagnostic::new(&self.error))
+    }
+}
+
+impl Error for ExecutionFailure {}
+
+/// Returns the error code carried by the provided execution error, if any.
+pub(crate) fn error_code(error: &ExecutionError) -> Option<Felt> {
+    match error {
+        ExecutionError::FailedAssertion { err_code, .. }
+        | ExecutionError::MerklePathVerificationFailed { err_code, .. }
+        | ExecutionError::NotU32Value { err_code, .. } => Some(*err_code),
+        _ => None,
+    }
+}
+
+// INTERRUPT REASON
+// ================================================================================================
+
+/// The reason for which the execution of a transaction was interrupted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum InterruptReason {
+    #[error("execution was cancelled")]
+    Cancelled,
+    #[error("execution deadline was exceeded")]
+    DeadlineExceeded,
+}
+
+// MISSING ACCOUNT DATA
+// ================================================================================================
+
+/// Account data accessed by a transaction which was not available when executing the transaction.
+///
+/// See [`DataStore`](crate::DataStore) for details on how missing account data is loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Error)]
+pub enum MissingAccountData {
+    #[error("entry with key {key} of the storage map in slot {slot_index}")]
+    StorageMapEntry { slot_index: u8, key: Digest },
+    #[error("asset with vault key {vault_key}")]
+    VaultAsset { vault_key: Digest },
+    #[error("foreign account {account_id}")]
+    ForeignAccount { account_id: AccountId },
 }
 
 // TRANSACTION PROVER ERROR
@@ -72,12 +225,31 @@ pub enum TransactionProverError {
     TransactionOutputConstructionFailed(#[source] TransactionOutputError),
     #[error("failed to build proven transaction")]
     ProvenTransactionBuildFailed(#[source] ProvenTransactionError),
-    // Print the diagnostic directly instead of returning the source error. In the source error
-    // case, the diagnostic is lost if the execution error is not explicitly unwrapped.
-    #[error("failed to execute transaction kernel program:\n{}", PrintDiagnostic::new(.0))]
-    TransactionProgramExecutionFailed(ExecutionError),
+    #[error("{0}")]
+    TransactionProgramExecutionFailed(Box<ExecutionFailure>),
     #[error("failed to create transaction host")]
     TransactionHostCreationFailed(#[source] TransactionHostError),
+    #[error(
+        "reference block with transaction kernel commitment {0} does not support any registered transaction kernel"
+    )]
+    UnsupportedTransactionKernel(Digest),
+    #[error("failed to communicate with remote prover at {address} after {attempts} attempt(s)")]
+    RemoteProverConnectionFailed {
+        address: String,
+        attempts: usize,
+        source: Box<dyn Error + Send + Sync + 'static>,
+    },
+    #[error("remote prover failed to prove the transaction: {0}")]
+    RemoteProvingFailed(String),
+    #[error(
+        "{field} of transaction {transaction_id} proven by remote prover does not match the transaction witness"
+    )]
+    RemoteProverResponseMismatch {
+        transaction_id: TransactionId,
+        field: &'static str,
+    },
+    #[error("proving the transaction panicked: {0}")]
+    ProvingPanicked(String),
     /// Custom error variant for errors not covered by the other variants.
     #[error("{error_msg}")]
     Other {
@@ -109,6 +281,49 @@ impl TransactionProverError {
     }
 }
 
+// TRANSACTION REPLAY ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TransactionReplayError {
+    #[error("failed to create transaction host")]
+    TransactionHostCreationFailed(#[source] TransactionHostError),
+    #[error(
+        "reference block with transaction kernel commitment {0} does not support any registered transaction kernel"
+    )]
+    UnsupportedTransactionKernel(Digest),
+    #[error("{0}")]
+    TransactionProgramExecutionFailed(Box<ExecutionFailure>),
+    #[error("failed to construct transaction outputs")]
+    TransactionOutputConstructionFailed(#[source] TransactionOutputError),
+}
+
+// VIEW CALL DECODE ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum ViewCallDecodeError {
+    #[error("advice map entry with key {0} was not returned from the view call")]
+    AdviceMapEntryNotFound(Digest),
+    #[error("memory range {}..{} was not returned from the view call", .0.start, .0.end)]
+    MemoryRangeNotFound(Range<u32>),
+    #[error("expected {expected} elements to decode value but only {remaining} elements remain")]
+    NotEnoughElements { expected: usize, remaining: usize },
+    #[error("failed to decode account ID")]
+    InvalidAccountId(#[source] AccountIdError),
+}
+
+// PROVING CALIBRATION ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum ProvingCalibrationError {
+    #[error("proving calibration must contain at least one calibration point")]
+    NoCalibrationPoints,
+    #[error("trace length {0} of calibration point is not a power of two")]
+    InvalidTraceLength(usize),
+}
+
 // TRANSACTION VERIFIER ERROR
 // ================================================================================================
 
@@ -118,6 +333,10 @@ pub enum TransactionVerifierError {
     TransactionVerificationFailed(#[source] VerificationError),
     #[error("transaction proof security level is {actual} but must be at least {expected_minimum}")]
     InsufficientProofSecurityLevel { actual: u32, expected_minimum: u32 },
+    #[error(
+        "reference block with transaction kernel commitment {0} does not support any registered transaction kernel"
+    )]
+    UnsupportedTransactionKernel(Digest),
 }
 
 // TRANSACTION HOST ERROR
@@ -131,6 +350,28 @@ pub enum TransactionHostError {
     AccountProcedureInfoCreationFailed(#[source] AccountError),
 }
 
+// EVENT HANDLER REGISTRY ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum EventHandlerRegistryError {
+    #[error("event ID range {start}..={end} is empty")]
+    EmptyRange { start: u32, end: u32 },
+    #[error(
+        "event ID range {start}..={end} contains event IDs reserved for the transaction kernel"
+    )]
+    ReservedEventIds { start: u32, end: u32 },
+    #[error(
+        "event ID range {start}..={end} overlaps with the already registered range {existing_start}..={existing_end}"
+    )]
+    OverlappingRange {
+        start: u32,
+        end: u32,
+        existing_start: u32,
+        existing_end: u32,
+    },
+}
+
 // DATA STORE ERROR
 // ================================================================================================
 
@@ -140,6 +381,27 @@ pub enum DataStoreError {
     AccountNotFound(AccountId),
     #[error("block with number {0} not found in data store")]
     BlockNotFound(BlockNumber),
+    #[error(
+        "entry with key {map_key} of the storage map with root {map_root} of account {account_id} not found in data store"
+    )]
+    StorageMapEntryNotFound {
+        account_id: AccountId,
+        map_root: Digest,
+        map_key: Digest,
+    },
+    #[error(
+        "asset with vault key {vault_key} of the vault with root {vault_root} of account {account_id} not found in data store"
+    )]
+    VaultAssetNotFound {
+        account_id: AccountId,
+        vault_root: Digest,
+        vault_key: Digest,
+    },
+    #[error("foreign account {account_id} at block {block_num} not found in data store")]
+    ForeignAccountNotFound {
+        account_id: AccountId,
+        block_num: BlockNumber,
+    },
     /// Custom error variant for implementors of the [`DataStore`](crate::executor::DataStore)
     /// trait.
     #[error("{error_msg}")]
@@ -0,0 +1,454 @@
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+use alloc::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    sync::Arc,
+};
+
+use miden_lib::utils::sync::RwLock;
+use miden_objects::{
+    Digest,
+    account::{AccountId, PartialAccount},
+    assembly::mast::MastForest,
+    block::{BlockHeader, BlockNumber},
+    crypto::merkle::{MerklePath, MmrPeaks, PartialMmr, SmtProof},
+    transaction::{AccountInputs, PartialBlockchain},
+};
+use vm_processor::{MastForestStore, Word};
+use winter_maybe_async::*;
+
+use super::DataStore;
+use crate::DataStoreError;
+
+// CACHING DATA STORE
+// ================================================================================================
+
+/// A [DataStore] which memoizes the data returned by the wrapped data store.
+///
+/// By default, only immutable data is cached, i.e., data identified by the block numbers or
+/// commitments it was requested for, which never becomes stale:
+/// - The block headers of the requested blocks, and the peaks of the chain and the authentication
+///   paths of the requested blocks for each reference block. The [PartialBlockchain] of a
+///   transaction is built from the cached paths, and only blocks which are not cached are requested
+///   from the wrapped data store.
+/// - The storage map and vault witnesses requested for lazily loaded account data.
+/// - The inputs of the foreign accounts requested for each block.
+/// - The [MastForest]s returned for procedure lookups via [MastForestStore].
+///
+/// The state of the native account of a transaction, on the other hand, changes whenever a
+/// transaction against the account is applied to the wrapped data store, so it is fetched from
+/// the wrapped data store for every transaction. Caching of account states can be enabled via
+/// [CachingDataStore::with_account_caching], in which case the caller is responsible for
+/// invalidating the cached states via [CachingDataStore::on_new_block] and
+/// [CachingDataStore::invalidate_account].
+///
+/// Each cache holds a bounded number of entries. When a cache is full, the oldest entry is evicted
+/// to make room for a new one, and a bound of zero disables caching of the respective data.
+pub struct CachingDataStore<D> {
+    inner: D,
+    block_headers: RwLock<BoundedCache<BlockNumber, BlockHeader>>,
+    chain_peaks: RwLock<BoundedCache<BlockNumber, MmrPeaks>>,
+    block_paths: RwLock<BoundedCache<(BlockNumber, BlockNumber), MerklePath>>,
+    accounts: RwLock<BoundedCache<AccountId, (PartialAccount, Option<Word>)>>,
+    storage_map_witnesses: RwLock<BoundedCache<(AccountId, Digest, Digest), SmtProof>>,
+    vault_witnesses: RwLock<BoundedCache<(AccountId, Digest, Digest), SmtProof>>,
+    foreign_accounts: RwLock<BoundedCache<(AccountId, BlockNumber), AccountInputs>>,
+    mast_forests: RwLock<BoundedCache<Digest, Arc<MastForest>>>,
+}
+
+impl<D: DataStore> CachingDataStore<D> {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The default maximum number of cached block headers, chain peaks and authentication paths,
+    /// each.
+    pub const DEFAULT_MAX_BLOCK_DATA: usize = 256;
+
+    /// The default maximum number of cached foreign account inputs.
+    pub const DEFAULT_MAX_FOREIGN_ACCOUNTS: usize = 64;
+
+    /// The default maximum number of cached storage map witnesses and vault witnesses, each.
+    pub const DEFAULT_MAX_WITNESSES: usize = 1024;
+
+    /// The default maximum number of cached procedure lookups.
+    pub const DEFAULT_MAX_MAST_FORESTS: usize = 1024;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [CachingDataStore] wrapping the provided data store, using the default cache
+    /// bounds.
+    ///
+    /// Account states are not cached (see [CachingDataStore::with_account_caching]).
+    pub fn new(inner: D) -> Self {
+        Self {
+            inner,
+            block_headers: RwLock::new(BoundedCache::new(Self::DEFAULT_MAX_BLOCK_DATA)),
+            chain_peaks: RwLock::new(BoundedCache::new(Self::DEFAULT_MAX_BLOCK_DATA)),
+            block_paths: RwLock::new(BoundedCache::new(Self::DEFAULT_MAX_BLOCK_DATA)),
+            accounts: RwLock::new(BoundedCache::new(0)),
+            storage_map_witnesses: RwLock::new(BoundedCache::new(Self::DEFAULT_MAX_WITNESSES)),
+            vault_witnesses: RwLock::new(BoundedCache::new(Self::DEFAULT_MAX_WITNESSES)),
+            foreign_accounts: RwLock::new(BoundedCache::new(Self::DEFAULT_MAX_FOREIGN_ACCOUNTS)),
+            mast_forests: RwLock::new(BoundedCache::new(Self::DEFAULT_MAX_MAST_FORESTS)),
+        }
+    }
+
+    /// Enables caching of the states of at most `max_accounts` accounts.
+    ///
+    /// Cached account states become stale when a transaction against the account is applied to
+    /// the wrapped data store. Thus, [CachingDataStore::on_new_block] must be called whenever a
+    /// new block is applied to the wrapped data store, and accounts which are updated without a
+    /// new block (e.g., by applying a locally executed transaction) must be invalidated via
+    /// [CachingDataStore::invalidate_account]. Otherwise, transactions are executed against
+    /// outdated account states.
+    pub fn with_account_caching(mut self, max_accounts: usize) -> Self {
+        self.accounts = RwLock::new(BoundedCache::new(max_accounts));
+        self
+    }
+
+    /// Sets the maximum number of cached block headers, chain peaks and authentication paths,
+    /// each.
+    pub fn with_max_block_data(mut self, max_entries: usize) -> Self {
+        self.block_headers = RwLock::new(BoundedCache::new(max_entries));
+        self.chain_peaks = RwLock::new(BoundedCache::new(max_entries));
+        self.block_paths = RwLock::new(BoundedCache::new(max_entries));
+        self
+    }
+
+    /// Sets the maximum number of cached foreign account inputs.
+    pub fn with_max_foreign_accounts(mut self, max_entries: usize) -> Self {
+        self.foreign_accounts = RwLock::new(BoundedCache::new(max_entries));
+        self
+    }
+
+    /// Sets the maximum number of cached storage map witnesses and vault witnesses, each.
+    pub fn with_max_witnesses(mut self, max_entries: usize) -> Self {
+        self.storage_map_witnesses = RwLock::new(BoundedCache::new(max_entries));
+        self.vault_witnesses = RwLock::new(BoundedCache::new(max_entries));
+        self
+    }
+
+    /// Sets the maximum number of cached procedure lookups.
+    pub fn with_max_mast_forests(mut self, max_entries: usize) -> Self {
+        self.mast_forests = RwLock::new(BoundedCache::new(max_entries));
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the wrapped data store.
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+
+    /// Consumes this store and returns the wrapped data store.
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+
+    // INVALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Invalidates the cached account states and block data.
+    ///
+    /// This must be called whenever a new block is applied to the wrapped data store, as the block
+    /// may update any of the cached accounts. Block data is invalidated as well, since the
+    /// authentication paths are specific to the reference block and subsequent transactions are
+    /// expected to reference the new block. Witnesses, foreign account inputs and MAST forests
+    /// remain cached.
+    pub fn on_new_block(&self) {
+        self.accounts.write().clear();
+        self.block_headers.write().clear();
+        self.chain_peaks.write().clear();
+        self.block_paths.write().clear();
+    }
+
+    /// Invalidates the cached state of the account with the specified ID.
+    pub fn invalidate_account(&self, account_id: AccountId) {
+        self.accounts.write().remove(&account_id);
+    }
+
+    /// Removes all entries from all caches.
+    pub fn clear(&self) {
+        self.block_headers.write().clear();
+        self.chain_peaks.write().clear();
+        self.block_paths.write().clear();
+        self.accounts.write().clear();
+        self.storage_map_witnesses.write().clear();
+        self.vault_witnesses.write().clear();
+        self.foreign_accounts.write().clear();
+        self.mast_forests.write().clear();
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the cached header of the specified reference block and the peaks of the chain at
+    /// that block, if both are cached.
+    fn cached_reference_block(&self, ref_block: BlockNumber) -> Option<(BlockHeader, MmrPeaks)> {
+        let block_header = self.block_headers.read().get(&ref_block).cloned()?;
+        let peaks = self.chain_peaks.read().get(&ref_block).cloned()?;
+
+        Some((block_header, peaks))
+    }
+
+    /// Returns the cached headers and authentication paths of the provided blocks preceding the
+    /// specified reference block, together with the set of those blocks which are not cached.
+    fn cached_blocks(
+        &self,
+        ref_block: BlockNumber,
+        ref_blocks: &BTreeSet<BlockNumber>,
+    ) -> (BTreeMap<BlockNumber, (BlockHeader, MerklePath)>, BTreeSet<BlockNumber>) {
+        let block_headers = self.block_headers.read();
+        let block_paths = self.block_paths.read();
+
+        let mut cached_blocks = BTreeMap::new();
+        let mut missing_blocks = BTreeSet::new();
+        for &block_num in ref_blocks.range(..ref_block) {
+            match (block_headers.get(&block_num), block_paths.get(&(ref_block, block_num))) {
+                (Some(block_header), Some(path)) => {
+                    cached_blocks.insert(block_num, (block_header.clone(), path.clone()));
+                },
+                _ => {
+                    missing_blocks.insert(block_num);
+                },
+            }
+        }
+
+        (cached_blocks, missing_blocks)
+    }
+
+    /// Caches the provided reference block header and the peaks, block headers and authentication
+    /// paths of the provided partial blockchain, and returns the headers and paths of the blocks
+    /// tracked by the partial blockchain.
+    fn insert_block_data(
+        &self,
+        ref_block_header: &BlockHeader,
+        partial_blockchain: &PartialBlockchain,
+    ) -> BTreeMap<BlockNumber, (BlockHeader, MerklePath)> {
+        let ref_block = ref_block_header.block_num();
+        let blocks: BTreeMap<_, _> = partial_blockchain
+            .block_headers()
+            .filter_map(|block_header| {
+                let block_num = block_header.block_num();
+                let proof = partial_blockchain.mmr().open(block_num.as_usize()).ok().flatten()?;
+                Some((block_num, (block_header.clone(), proof.merkle_path)))
+            })
+            .collect();
+
+        let mut block_headers = self.block_headers.write();
+        block_headers.insert(ref_block, ref_block_header.clone());
+        for (&block_num, (block_header, _)) in blocks.iter() {
+            block_headers.insert(block_num, block_header.clone());
+        }
+        drop(block_headers);
+
+        self.chain_peaks.write().insert(ref_block, partial_blockchain.peaks());
+
+        let mut block_paths = self.block_paths.write();
+        for (&block_num, (_, path)) in blocks.iter() {
+            block_paths.insert((ref_block, block_num), path.clone());
+        }
+
+        blocks
+    }
+}
+
+// DATA STORE IMPLEMENTATION
+// ================================================================================================
+
+#[maybe_async_trait]
+impl<D: DataStore> DataStore for CachingDataStore<D> {
+    #[maybe_async]
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        ref_blocks: BTreeSet<BlockNumber>,
+    ) -> Result<(PartialAccount, Option<Word>, BlockHeader, PartialBlockchain), DataStoreError>
+    {
+        let Some(&ref_block) = ref_blocks.last() else {
+            return maybe_await!(self.inner.get_transaction_inputs(account_id, ref_blocks));
+        };
+
+        let account = self.accounts.read().get(&account_id).cloned();
+        let reference_block = self.cached_reference_block(ref_block);
+        let (mut cached_blocks, missing_blocks) = self.cached_blocks(ref_block, &ref_blocks);
+        if let (Some((account, seed)), Some((block_header, peaks))) = (account, reference_block) {
+            if missing_blocks.is_empty() {
+                let partial_blockchain = build_partial_blockchain(peaks, cached_blocks)?;
+                return Ok((account, seed, block_header, partial_blockchain));
+            }
+        }
+
+        // the reference block is always requested, as the wrapped data store uses the highest
+        // requested block as the reference block, while cached blocks are not requested again
+        let mut requested_blocks = missing_blocks;
+        requested_blocks.insert(ref_block);
+        let (account, seed, block_header, partial_blockchain) =
+            maybe_await!(self.inner.get_transaction_inputs(account_id, requested_blocks))?;
+
+        self.accounts.write().insert(account_id, (account.clone(), seed));
+        let fetched_blocks = self.insert_block_data(&block_header, &partial_blockchain);
+        if cached_blocks.is_empty() {
+            return Ok((account, seed, block_header, partial_blockchain));
+        }
+
+        cached_blocks.extend(fetched_blocks);
+        let partial_blockchain =
+            build_partial_blockchain(partial_blockchain.peaks(), cached_blocks)?;
+
+        Ok((account, seed, block_header, partial_blockchain))
+    }
+
+    #[maybe_async]
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Digest,
+        map_key: Digest,
+    ) -> Result<SmtProof, DataStoreError> {
+        let key = (account_id, map_root, map_key);
+        if let Some(witness) = self.storage_map_witnesses.read().get(&key) {
+            return Ok(witness.clone());
+        }
+
+        let witness =
+            maybe_await!(self.inner.get_storage_map_witness(account_id, map_root, map_key))?;
+        self.storage_map_witnesses.write().insert(key, witness.clone());
+
+        Ok(witness)
+    }
+
+    #[maybe_async]
+    fn get_vault_asset_witness(
+        &self,
+        account_id: AccountId,
+        vault_root: Digest,
+        vault_key: Digest,
+    ) -> Result<SmtProof, DataStoreError> {
+        let key = (account_id, vault_root, vault_key);
+        if let Some(witness) = self.vault_witnesses.read().get(&key) {
+            return Ok(witness.clone());
+        }
+
+        let witness =
+            maybe_await!(self.inner.get_vault_asset_witness(account_id, vault_root, vault_key))?;
+        self.vault_witnesses.write().insert(key, witness.clone());
+
+        Ok(witness)
+    }
+
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+    ) -> Result<AccountInputs, DataStoreError> {
+        let key = (account_id, block_num);
+        if let Some(account_inputs) = self.foreign_accounts.read().get(&key) {
+            return Ok(account_inputs.clone());
+        }
+
+        let account_inputs =
+            maybe_await!(self.inner.get_foreign_account_inputs(account_id, block_num))?;
+        self.foreign_accounts.write().insert(key, account_inputs.clone());
+
+        Ok(account_inputs)
+    }
+}
+
+// MAST FOREST STORE IMPLEMENTATION
+// ================================================================================================
+
+impl<D: DataStore> MastForestStore for CachingDataStore<D> {
+    fn get(&self, procedure_hash: &Digest) -> Option<Arc<MastForest>> {
+        if let Some(mast_forest) = self.mast_forests.read().get(procedure_hash) {
+            return Some(mast_forest.clone());
+        }
+
+        // failed lookups are not cached, as the wrapped store may learn about the procedure later
+        let mast_forest = self.inner.get(procedure_hash)?;
+        self.mast_forests.write().insert(*procedure_hash, mast_forest.clone());
+
+        Some(mast_forest)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds a [PartialBlockchain] with the provided chain peaks which tracks the provided blocks
+/// using their authentication paths.
+fn build_partial_blockchain(
+    peaks: MmrPeaks,
+    blocks: BTreeMap<BlockNumber, (BlockHeader, MerklePath)>,
+) -> Result<PartialBlockchain, DataStoreError> {
+    let mut partial_mmr = PartialMmr::from_peaks(peaks);
+    for (block_num, (block_header, path)) in blocks.iter() {
+        partial_mmr
+            .track(block_num.as_usize(), block_header.commitment(), path)
+            .map_err(|err| {
+                DataStoreError::other_with_source(
+                    format!("failed to track the authentication path of block {block_num}"),
+                    err,
+                )
+            })?;
+    }
+
+    PartialBlockchain::new(partial_mmr, blocks.into_values().map(|(block_header, _)| block_header))
+        .map_err(|err| DataStoreError::other_with_source("failed to build partial blockchain", err))
+}
+
+// BOUNDED CACHE
+// ================================================================================================
+
+/// A map holding at most `capacity` entries, which evicts the oldest entry when a new entry is
+/// inserted into a full map.
+struct BoundedCache<K, V> {
+    entries: BTreeMap<K, V>,
+    insertion_order: VecDeque<K>,
+    capacity: usize,
+}
+
+impl<K: Ord + Clone, V> BoundedCache<K, V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            insertion_order: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(key)
+    }
+
+    /// Inserts the provided entry, replacing the value of an existing entry with the same key
+    /// without changing its age.
+    fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.entries.insert(key.clone(), value).is_none() {
+            self.insertion_order.push_back(key);
+            while self.entries.len() > self.capacity {
+                let oldest = self.insertion_order.pop_front().expect("cache should not be empty");
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+
+    fn remove(&mut self, key: &K) {
+        if self.entries.remove(key).is_some() {
+            self.insertion_order.retain(|k| k != key);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.insertion_order.clear();
+    }
+}
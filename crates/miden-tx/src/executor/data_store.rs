@@ -3,9 +3,11 @@ use alloc::boxed::Box;
 use alloc::collections::BTreeSet;
 
 use miden_objects::{
-    account::{Account, AccountId},
+    Digest,
+    account::{AccountId, PartialAccount},
     block::{BlockHeader, BlockNumber},
-    transaction::PartialBlockchain,
+    crypto::merkle::SmtProof,
+    transaction::{AccountInputs, PartialBlockchain},
 };
 use vm_processor::{MastForestStore, Word};
 use winter_maybe_async::*;
@@ -17,6 +19,24 @@ use crate::DataStoreError;
 
 /// The [DataStore] trait defines the interface that transaction objects use to fetch data
 /// required for transaction execution.
+///
+/// The account against which a transaction is executed is provided as a [PartialAccount], which
+/// does not need to contain all storage map entries and assets of the account. When a transaction
+/// accesses a storage map entry or an asset which is not contained in the partial account, the
+/// transaction host requests a Merkle opening of the missing data via
+/// [DataStore::get_storage_map_witness] or [DataStore::get_vault_asset_witness], provides it to
+/// the VM and continues execution. Once the transaction has been executed, the openings are added
+/// to the transaction inputs, so the executed transaction can be proven without access to the
+/// data store.
+///
+/// Similarly, when a transaction invokes a procedure of a foreign account which was not provided
+/// via the [`TransactionArgs`](miden_objects::transaction::TransactionArgs), the host requests
+/// the inputs of the foreign account via [DataStore::get_foreign_account_inputs] during execution,
+/// and the inputs are added to the transaction arguments of the executed transaction.
+///
+/// With the `async` feature, the data store can not be queried while the VM executes a
+/// transaction. In this case, execution is halted at the missing data, which is then fetched and
+/// added to the transaction inputs or arguments before the transaction is re-executed.
 #[maybe_async_trait]
 pub trait DataStore: MastForestStore {
     /// Returns all the data required to execute a transaction against the account with the
@@ -26,6 +46,8 @@ pub trait DataStore: MastForestStore {
     /// general, it is recommended that the reference corresponds to the latest block available
     /// in the data store.
     ///
+    /// For new accounts, the returned partial account must contain the full state of the account.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - The account with the specified ID could not be found in the data store.
@@ -37,5 +59,69 @@ pub trait DataStore: MastForestStore {
         &self,
         account_id: AccountId,
         ref_blocks: BTreeSet<BlockNumber>,
-    ) -> Result<(Account, Option<Word>, BlockHeader, PartialBlockchain), DataStoreError>;
+    ) -> Result<(PartialAccount, Option<Word>, BlockHeader, PartialBlockchain), DataStoreError>;
+
+    /// Returns a Merkle opening of the entry with the specified key in the storage map with the
+    /// specified root of the account with the specified ID.
+    ///
+    /// The key is the original (unhashed) key of the entry, so the opening can be obtained via
+    /// [`StorageMap::open`](miden_objects::account::StorageMap::open). The opening must be
+    /// provided even if the map does not contain an entry with the specified key.
+    ///
+    /// The default implementation returns [DataStoreError::StorageMapEntryNotFound], i.e., all
+    /// storage map entries accessed by a transaction must be contained in the partial account.
+    ///
+    /// # Errors
+    /// Returns an error if the opening could not be obtained.
+    #[maybe_async]
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Digest,
+        map_key: Digest,
+    ) -> Result<SmtProof, DataStoreError> {
+        Err(DataStoreError::StorageMapEntryNotFound { account_id, map_root, map_key })
+    }
+
+    /// Returns a Merkle opening of the asset with the specified vault key in the vault with the
+    /// specified root of the account with the specified ID.
+    ///
+    /// The opening must be provided even if the vault does not contain an asset with the specified
+    /// key.
+    ///
+    /// The default implementation returns [DataStoreError::VaultAssetNotFound], i.e., all assets
+    /// accessed by a transaction must be contained in the partial account.
+    ///
+    /// # Errors
+    /// Returns an error if the opening could not be obtained.
+    #[maybe_async]
+    fn get_vault_asset_witness(
+        &self,
+        account_id: AccountId,
+        vault_root: Digest,
+        vault_key: Digest,
+    ) -> Result<SmtProof, DataStoreError> {
+        Err(DataStoreError::VaultAssetNotFound { account_id, vault_root, vault_key })
+    }
+
+    /// Returns the inputs of the foreign account with the specified ID, i.e., the state of the
+    /// account together with a witness of its commitment against the account root of the block
+    /// with the specified number.
+    ///
+    /// The code of the foreign account must be available via the [MastForestStore] of the data
+    /// store.
+    ///
+    /// The default implementation returns [DataStoreError::ForeignAccountNotFound], i.e., all
+    /// foreign accounts accessed by a transaction must be provided upfront.
+    ///
+    /// # Errors
+    /// Returns an error if the inputs of the foreign account could not be obtained.
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+    ) -> Result<AccountInputs, DataStoreError> {
+        Err(DataStoreError::ForeignAccountNotFound { account_id, block_num })
+    }
 }
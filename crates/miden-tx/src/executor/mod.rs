@@ -1,24 +1,55 @@
-use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use core::time::Duration;
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{
+    errors::ErrorCodeRegistry,
+    transaction::{
+        TransactionAdviceInputs, TransactionKernel, TransactionKernelRegistry, VersionedKernel,
+        memory::CURRENT_KERNEL_PROCEDURE_OFFSET_PTR,
+    },
+};
 use miden_objects::{
-    Felt, MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES,
-    account::AccountId,
+    Digest, Felt, MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, Word, ZERO,
+    account::{Account, AccountCode, AccountId, PartialAccount},
     assembly::SourceManager,
     block::{BlockHeader, BlockNumber},
     note::{NoteId, NoteScript},
     transaction::{
         AccountInputs, ExecutedTransaction, InputNote, InputNotes, TransactionArgs,
-        TransactionInputs, TransactionScript,
+        TransactionInputs, TransactionMeasurements, TransactionScript,
     },
-    vm::{AdviceMap, StackOutputs},
+    vm::{AdviceMap, Program, StackOutputs},
+};
+use vm_processor::{
+    AdviceInputs, AdviceProvider, ChipletsLengths, ContextId, ExecutionError, ExecutionTrace,
+    MemAdviceProvider, Process, ProcessState, RecAdviceProvider, StackInputs, TraceLenSummary,
 };
-use vm_processor::{AdviceInputs, MemAdviceProvider, Process, RecAdviceProvider};
 pub use vm_processor::{ExecutionOptions, MastForestStore};
 use winter_maybe_async::{maybe_async, maybe_await};
 
 use super::{TransactionExecutorError, TransactionHost};
-use crate::{auth::TransactionAuthenticator, host::ScriptMastForestStore};
+#[cfg(feature = "async")]
+use crate::errors::MissingAccountData;
+#[cfg(not(feature = "async"))]
+use crate::host::AccountDataLoader;
+use crate::{
+    auth::TransactionAuthenticator,
+    errors::{ExecutionFailure, InterruptReason, error_code},
+    host::{
+        CancellationToken, EventHandlerRegistry, ExecutionInterrupt, ScriptMastForestStore,
+        SimulatedAccountCode, TransactionProgress,
+    },
+};
+
+mod caching_data_store;
+pub use caching_data_store::CachingDataStore;
 
 mod data_store;
 pub use data_store::DataStore;
@@ -26,6 +57,21 @@ pub use data_store::DataStore;
 mod notes_checker;
 pub use notes_checker::{NoteConsumptionChecker, NoteInputsCheck};
 
+mod signing_request;
+pub use signing_request::{SigningRequest, TransactionExecution};
+
+mod profile;
+pub use profile::TransactionProfile;
+
+mod simulated_tx;
+pub use simulated_tx::SimulatedTransaction;
+
+mod trace;
+pub use trace::{TraceExporter, TraceSpan, TraceSpanKind, TransactionTrace};
+
+mod view_call;
+pub use view_call::{ViewCall, ViewCallOutput, ViewValue, ViewValueType};
+
 // TRANSACTION EXECUTOR
 // ================================================================================================
 
@@ -42,6 +88,15 @@ pub struct TransactionExecutor<'store, 'auth> {
     data_store: &'store dyn DataStore,
     authenticator: Option<&'auth dyn TransactionAuthenticator>,
     exec_options: ExecutionOptions,
+    cancellation_token: Option<CancellationToken>,
+    #[cfg(feature = "std")]
+    timeout: Option<Duration>,
+    #[cfg(feature = "std")]
+    cycles_per_second: Option<u32>,
+    event_handlers: EventHandlerRegistry,
+    error_codes: ErrorCodeRegistry,
+    trace_exporter: Option<Arc<dyn TraceExporter>>,
+    kernel_registry: TransactionKernelRegistry,
 }
 
 impl<'store, 'auth> TransactionExecutor<'store, 'auth> {
@@ -66,6 +121,15 @@ impl<'store, 'auth> TransactionExecutor<'store, 'auth> {
                 false,
             )
             .expect("Must not fail while max cycles is more than min trace length"),
+            cancellation_token: None,
+            #[cfg(feature = "std")]
+            timeout: None,
+            #[cfg(feature = "std")]
+            cycles_per_second: None,
+            event_handlers: EventHandlerRegistry::default(),
+            error_codes: ErrorCodeRegistry::new(),
+            trace_exporter: None,
+            kernel_registry: TransactionKernelRegistry::default(),
         }
     }
 
@@ -82,7 +146,20 @@ impl<'store, 'auth> TransactionExecutor<'store, 'auth> {
         validate_num_cycles(exec_options.max_cycles())?;
         validate_num_cycles(exec_options.expected_cycles())?;
 
-        Ok(Self { data_store, authenticator, exec_options })
+        Ok(Self {
+            data_store,
+            authenticator,
+            exec_options,
+            cancellation_token: None,
+            #[cfg(feature = "std")]
+            timeout: None,
+            #[cfg(feature = "std")]
+            cycles_per_second: None,
+            event_handlers: EventHandlerRegistry::default(),
+            error_codes: ErrorCodeRegistry::new(),
+            trace_exporter: None,
+            kernel_registry: TransactionKernelRegistry::default(),
+        })
     }
 
     /// Puts the [TransactionExecutor] into debug mode.
@@ -105,6 +182,99 @@ impl<'store, 'auth> TransactionExecutor<'store, 'auth> {
         self
     }
 
+    /// Configures the [TransactionExecutor] to export the [TransactionTrace] of every executed
+    /// transaction to the provided [TraceExporter]. This also enables tracing (see
+    /// [Self::with_tracing]).
+    ///
+    /// The trace is exported once the transaction program has been executed successfully by
+    /// [`execute_transaction()`](Self::execute_transaction),
+    /// [`execute_transaction_chain()`](Self::execute_transaction_chain) or
+    /// [`profile_transaction()`](Self::profile_transaction).
+    pub fn with_trace_exporter(mut self, exporter: Arc<dyn TraceExporter>) -> Self {
+        self.exec_options = self.exec_options.with_tracing();
+        self.trace_exporter = Some(exporter);
+        self
+    }
+
+    /// Sets the [TransactionKernelRegistry] from which the kernel used to execute a transaction is
+    /// selected.
+    ///
+    /// A transaction is executed against the latest kernel supported by its reference block (see
+    /// [`TransactionKernelRegistry::select()`]). By default, the registry contains the kernels
+    /// built into miden-lib. The MAST of kernels which are not built into miden-lib must be
+    /// available in the [DataStore].
+    pub fn with_kernel_registry(mut self, kernel_registry: TransactionKernelRegistry) -> Self {
+        self.kernel_registry = kernel_registry;
+        self
+    }
+
+    /// Configures the [TransactionExecutor] to interrupt execution when the provided
+    /// [CancellationToken] is cancelled.
+    ///
+    /// Cancellation is cooperative: the token is checked before the transaction program is
+    /// executed and whenever the transaction kernel emits an event (see [ExecutionInterrupt]).
+    /// When the token is cancelled, execution fails with
+    /// [`TransactionExecutorError::ExecutionInterrupted`].
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Configures the [TransactionExecutor] to interrupt each execution which takes longer than
+    /// the provided wall-clock timeout.
+    ///
+    /// The timeout covers the whole execution, including fetching the data from the
+    /// [DataStore]. Like cancellation, it is checked whenever the transaction kernel emits an
+    /// event (see [ExecutionInterrupt]). Since code which does not interact with the kernel (e.g.,
+    /// a note script stuck in a tight loop) never emits events, the timeout also bounds the
+    /// maximum number of cycles of each execution by the number of cycles the VM can execute
+    /// until the deadline (see [`ExecutionInterrupt::max_cycles()`]). When the timeout expires or
+    /// this bound is exceeded, execution fails with
+    /// [`TransactionExecutorError::ExecutionInterrupted`].
+    ///
+    /// For code which never emits events, the timeout is therefore only approximate, since the
+    /// bound depends on the number of cycles the VM is assumed to execute per second (see
+    /// [Self::with_cycles_per_second]).
+    #[cfg(feature = "std")]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Configures the number of cycles the VM is assumed to execute per second, from which the
+    /// bound on the number of cycles until the timeout of an execution is derived.
+    ///
+    /// Defaults to [`ExecutionInterrupt::DEFAULT_CYCLES_PER_SECOND`]. Setting the rate measured on
+    /// the executing machine makes the timeout of code which never emits events more accurate
+    /// (see [`ExecutionInterrupt::with_cycles_per_second()`]).
+    #[cfg(feature = "std")]
+    pub fn with_cycles_per_second(mut self, cycles_per_second: u32) -> Self {
+        self.cycles_per_second = Some(cycles_per_second);
+        self
+    }
+
+    /// Configures the [TransactionExecutor] to dispatch events emitted by user code (e.g., account
+    /// components or note scripts) to the handlers in the provided [EventHandlerRegistry].
+    ///
+    /// To prove the executed transactions, the same handlers must be registered with the prover
+    /// (see [`LocalTransactionProver::with_event_handlers()`](crate::LocalTransactionProver::with_event_handlers)).
+    pub fn with_event_handlers(mut self, event_handlers: EventHandlerRegistry) -> Self {
+        self.event_handlers = event_handlers;
+        self
+    }
+
+    /// Configures the [TransactionExecutor] to decode the error codes of failed executions using
+    /// the provided [ErrorCodeRegistry].
+    ///
+    /// The errors defined in the transaction kernel and the standard library of Miden are always
+    /// decoded. The registry can be extended with the errors of user-defined account components
+    /// and note scripts (see [`ErrorCodeRegistry::with_account_component()`]). The decoded error
+    /// is available via [`ExecutionFailure::decoded_error()`].
+    pub fn with_error_codes(mut self, error_codes: ErrorCodeRegistry) -> Self {
+        self.error_codes = error_codes;
+        self
+    }
+
     // TRANSACTION EXECUTION
     // --------------------------------------------------------------------------------------------
 
@@ -132,6 +302,8 @@ impl<'store, 'auth> TransactionExecutor<'store, 'auth> {
     /// - If the transaction arguments contain foreign account data not anchored in the reference
     ///   block.
     /// - If any input notes were created in block numbers higher than the reference block.
+    /// - If the reference block does not support any kernel of the kernel registry of the executor.
+    /// - If execution is cancelled or exceeds the timeout of the executor.
     #[maybe_async]
     pub fn execute_transaction(
         &self,
@@ -141,49 +313,453 @@ impl<'store, 'auth> TransactionExecutor<'store, 'auth> {
         tx_args: TransactionArgs,
         source_manager: Arc<dyn SourceManager>,
     ) -> Result<ExecutedTransaction, TransactionExecutorError> {
-        let mut ref_blocks = validate_input_notes(&notes, block_ref)?;
-        ref_blocks.insert(block_ref);
+        let interrupt = self.execution_interrupt();
+        let tx_inputs =
+            maybe_await!(self.prepare_tx_inputs(account_id, block_ref, notes, &tx_args))?;
+
+        maybe_await!(self.execute_prepared_transaction(
+            tx_inputs,
+            tx_args,
+            interrupt,
+            source_manager
+        ))
+    }
 
-        let (account, seed, ref_block, mmr) =
+    /// Prepares and executes a chain of transactions against the same account and returns the
+    /// resulting [`ExecutedTransaction`]s in the order of execution.
+    ///
+    /// Each transaction in the chain is specified by its input notes and transaction arguments and
+    /// is executed against the account state produced by the previous transaction. The data
+    /// required to execute the transactions is fetched from the [`DataStore`] only once, and the
+    /// intermediate account states are computed locally by applying the [`AccountDelta`] of each
+    /// executed transaction to a copy of the account. Thus, the initial account commitment of each
+    /// transaction matches the final account commitment of the previous one.
+    ///
+    /// All transactions share the same reference block specified by `block_ref`. Since the
+    /// intermediate account states are computed locally, the [`DataStore`] must provide the full
+    /// state of the account, i.e., a [`DataStore`] which provides only a part of the account and
+    /// loads the rest lazily can not be used to execute a chain of transactions. Otherwise,
+    /// [`TransactionExecutorError::IncompleteAccountState`] is returned.
+    ///
+    /// # Errors:
+    ///
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [`DataStore`].
+    /// - If the [`DataStore`] does not provide the full state of the account.
+    /// - If the transaction arguments of any transaction contain foreign account data not anchored
+    ///   in the reference block.
+    /// - If any input notes were created in block numbers higher than the reference block.
+    /// - If the execution of any transaction in the chain fails.
+    /// - If the account delta of any transaction can not be applied to the account.
+    /// - If execution is cancelled or exceeds the timeout of the executor.
+    ///
+    /// [`AccountDelta`]: miden_objects::account::AccountDelta
+    #[maybe_async]
+    pub fn execute_transaction_chain(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        transactions: Vec<(InputNotes<InputNote>, TransactionArgs)>,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<Vec<ExecutedTransaction>, TransactionExecutorError> {
+        let interrupt = self.execution_interrupt();
+        let mut ref_blocks = BTreeSet::from([block_ref]);
+        for (notes, _) in transactions.iter() {
+            ref_blocks.extend(validate_input_notes(notes, block_ref)?);
+        }
+
+        let (account, mut seed, ref_block, mmr) =
             maybe_await!(self.data_store.get_transaction_inputs(account_id, ref_blocks))
                 .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+        let mut account =
+            Account::try_from(account).map_err(TransactionExecutorError::IncompleteAccountState)?;
+
+        let mut executed_transactions = Vec::with_capacity(transactions.len());
+        for (notes, tx_args) in transactions {
+            validate_account_inputs(&tx_args, &ref_block)?;
+
+            let tx_inputs = TransactionInputs::new(
+                account.clone().into(),
+                seed,
+                ref_block.clone(),
+                mmr.clone(),
+                notes,
+            )
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
 
-        validate_account_inputs(&tx_args, &ref_block)?;
+            let executed_tx = maybe_await!(self.execute_prepared_transaction(
+                tx_inputs,
+                tx_args,
+                interrupt.clone(),
+                source_manager.clone()
+            ))?;
+
+            // the account is no longer new once the first transaction has been executed against
+            // it, so the seed is not needed anymore
+            account
+                .apply_delta(executed_tx.account_delta())
+                .map_err(TransactionExecutorError::AccountDeltaApplyFailed)?;
+            seed = None;
+
+            executed_transactions.push(executed_tx);
+        }
 
-        let tx_inputs = TransactionInputs::new(account, seed, ref_block, mmr, notes)
-            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+        Ok(executed_transactions)
+    }
+
+    // TRANSACTION PROFILING
+    // --------------------------------------------------------------------------------------------
+
+    /// Prepares and executes a transaction specified by the provided arguments in profiling mode
+    /// and returns the resulting [`ExecutedTransaction`] together with a [`TransactionProfile`].
+    ///
+    /// The transaction is executed in the same way as in
+    /// [`execute_transaction()`](Self::execute_transaction), except that the VM records the debug
+    /// information required to attribute the executed cycles to individual procedures. See
+    /// [`TransactionProfile`] for details on how the cycles are attributed.
+    ///
+    /// # Errors:
+    ///
+    /// Returns an error in the same cases as [`execute_transaction()`](Self::execute_transaction).
+    #[maybe_async]
+    pub fn profile_transaction(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<(ExecutedTransaction, TransactionProfile), TransactionExecutorError> {
+        let interrupt = self.execution_interrupt();
+        let tx_inputs =
+            maybe_await!(self.prepare_tx_inputs(account_id, block_ref, notes, &tx_args))?;
+
+        let mut signatures = BTreeMap::new();
+        maybe_await!(self.execute_with_lazy_loading(tx_inputs, tx_args, |tx_inputs, tx_args| {
+            interrupt.check().map_err(TransactionExecutorError::ExecutionInterrupted)?;
+
+            let (kernel, stack_inputs, advice_inputs) =
+                self.prepare_kernel_inputs(&tx_inputs, &tx_args, None)?;
+
+            let advice_recorder = RecAdviceProvider::from(advice_inputs.into_inner());
+
+            let script_mast_store = ScriptMastForestStore::new(
+                tx_args.tx_script(),
+                tx_inputs.input_notes().iter().map(|n| n.note().script()),
+            );
+
+            let mut host = self
+                .build_host(
+                    &tx_inputs,
+                    &tx_args,
+                    advice_recorder,
+                    script_mast_store,
+                    self.authenticator,
+                    &interrupt,
+                )?
+                .with_generated_signatures(signatures.clone());
+
+            // the operations and assembly ops executed by the VM are recorded only in debug mode
+            let program = kernel.main();
+            let mut process = Process::new(
+                program.kernel().clone(),
+                stack_inputs,
+                self.process_options(host.interrupt()).with_debugging(true),
+            )
+            .with_source_manager(source_manager.clone());
+            let stack_outputs = match process.execute(program, &mut host) {
+                Ok(stack_outputs) => stack_outputs,
+                Err(err) => {
+                    signatures.extend(host.generated_signatures().clone());
+                    return Err(map_execution_error(
+                        &mut host,
+                        &self.error_codes,
+                        self.exec_options.max_cycles(),
+                        kernel,
+                        process,
+                        err,
+                    ));
+                },
+            };
+
+            let (system, decoder, stack, range, chiplets) = process.into_parts();
+            let trace_len_summary = TraceLenSummary::new(
+                system.clk().into(),
+                range.get_number_range_checker_rows(),
+                ChipletsLengths::new(&chiplets),
+            );
+            let debug_info = decoder.debug_info();
+            self.export_trace(debug_info.operations().len(), host.tx_progress());
+            // the stack history is recorded only in debug mode
+            let kernel_calls = profile::kernel_calls(debug_info.operations(), kernel, |clk| {
+                stack.get_state_at(clk.into())[0]
+            });
+            let profile = TransactionProfile::new(
+                debug_info.operations().len(),
+                debug_info.assembly_ops(),
+                &kernel_calls,
+                host.tx_progress(),
+            );
+
+            let executed_tx = build_executed_transaction(
+                &self.kernel_registry,
+                kernel.version(),
+                tx_args.clone(),
+                tx_inputs,
+                stack_outputs,
+                &trace_len_summary,
+                host,
+            )?;
+
+            Ok((executed_tx, profile))
+        }))
+    }
 
-        let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
+    // TRANSACTION SIMULATION
+    // --------------------------------------------------------------------------------------------
 
-        let advice_recorder = RecAdviceProvider::from(advice_inputs.into_inner());
+    /// Prepares and executes a transaction specified by the provided arguments without generating
+    /// any signatures, and returns a [`SimulatedTransaction`].
+    ///
+    /// The transaction is executed in the same way as in
+    /// [`execute_transaction()`](Self::execute_transaction), except that the transaction
+    /// authenticator is never consulted. Instead, every signature request which cannot be served
+    /// from the advice inputs is answered with a placeholder signature. Since signatures are
+    /// verified inside the VM, the transaction is executed against a copy of the account code in
+    /// which the verification of Falcon signatures via the standard library (which is used by the
+    /// standard authentication components) is skipped. Thus, the transaction is executed to
+    /// completion, including the authentication procedure and the epilogue.
+    ///
+    /// Consequently:
+    /// - The returned account delta, output notes and fee are those of the full transaction.
+    /// - The returned measurements do not include the cycles of the skipped signature
+    ///   verifications.
+    /// - If the account code was replaced, the simulated transaction cannot be proven (see
+    ///   [`SimulatedTransaction::is_provable()`]).
+    ///
+    /// The code of a new account is never replaced, since the account ID commits to it. Hence,
+    /// simulating a transaction against a new account which verifies a signature fails.
+    ///
+    /// # Errors:
+    ///
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [`DataStore`].
+    /// - If the transaction arguments contain foreign account data not anchored in the reference
+    ///   block.
+    /// - If any input notes were created in block numbers higher than the reference block.
+    /// - If the execution of the transaction program fails.
+    #[maybe_async]
+    pub fn simulate_transaction(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<SimulatedTransaction, TransactionExecutorError> {
+        let interrupt = self.execution_interrupt();
+        let tx_inputs =
+            maybe_await!(self.prepare_tx_inputs(account_id, block_ref, notes, &tx_args))?;
+
+        maybe_await!(self.execute_with_lazy_loading(tx_inputs, tx_args, |tx_inputs, tx_args| {
+            interrupt.check().map_err(TransactionExecutorError::ExecutionInterrupted)?;
+
+            // the ID of a new account commits to its code, so the code can only be replaced for
+            // existing accounts
+            let simulated_code = if tx_inputs.account().is_new() {
+                None
+            } else {
+                SimulatedAccountCode::new(tx_inputs.account().code())
+            };
+            let tx_inputs = match &simulated_code {
+                Some(simulated_code) => {
+                    replace_account_code(tx_inputs, simulated_code.code().clone())?
+                },
+                None => tx_inputs,
+            };
+
+            let (kernel, stack_inputs, advice_inputs) =
+                self.prepare_kernel_inputs(&tx_inputs, &tx_args, None)?;
+
+            let advice_recorder = RecAdviceProvider::from(advice_inputs.into_inner());
+
+            let script_mast_store = ScriptMastForestStore::new(
+                tx_args.tx_script(),
+                tx_inputs.input_notes().iter().map(|n| n.note().script()),
+            );
+
+            let mut host = self
+                .build_host(
+                    &tx_inputs,
+                    &tx_args,
+                    advice_recorder,
+                    script_mast_store,
+                    None,
+                    &interrupt,
+                )?
+                .with_simulated_signatures(simulated_code.as_ref());
+
+            let trace = self.execute_program(
+                kernel,
+                kernel.main(),
+                stack_inputs,
+                &mut host,
+                source_manager.clone(),
+            )?;
+
+            let simulated_signatures = host
+                .signature_simulator()
+                .expect("signatures should be simulated")
+                .requests()
+                .to_vec();
+            // the transaction is executed against the original account code only if the code
+            // does not verify signatures
+            let is_provable = simulated_code.is_none();
+
+            let executed_tx = build_executed_transaction(
+                &self.kernel_registry,
+                kernel.version(),
+                tx_args.clone(),
+                tx_inputs,
+                trace.stack_outputs().clone(),
+                trace.trace_len_summary(),
+                host,
+            )?;
+
+            Ok(SimulatedTransaction::new(executed_tx, simulated_signatures, is_provable))
+        }))
+    }
 
-        let script_mast_store = ScriptMastForestStore::new(
-            tx_args.tx_script(),
-            tx_inputs.input_notes().iter().map(|n| n.note().script()),
-        );
+    // DEFERRED SIGNING
+    // --------------------------------------------------------------------------------------------
 
-        let mut host = TransactionHost::new(
-            &tx_inputs.account().into(),
-            advice_recorder,
-            self.data_store,
-            script_mast_store,
-            self.authenticator,
-            tx_args.foreign_account_code_commitments(),
-        )
-        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
-
-        // Execute the transaction kernel
-        let trace = vm_processor::execute(
-            &TransactionKernel::main(),
-            stack_inputs,
-            &mut host,
-            self.exec_options,
-            source_manager,
-        )
-        .map_err(TransactionExecutorError::TransactionProgramExecutionFailed)?;
+    /// Prepares and executes a transaction specified by the provided arguments, pausing execution
+    /// at the first signature request which cannot be served from the advice inputs.
+    ///
+    /// The transaction authenticator is never consulted. Instead, if the transaction requests a
+    /// signature, [`TransactionExecution::SignatureRequired`] is returned with a [`SigningRequest`]
+    /// containing the public key, the message to sign and the account delta accumulated up to that
+    /// point. Once the message is signed out of band, execution can be resumed via
+    /// [`resume_transaction()`](Self::resume_transaction). If the transaction does not request any
+    /// signatures, [`TransactionExecution::Executed`] is returned.
+    ///
+    /// # Errors:
+    ///
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [`DataStore`].
+    /// - If the transaction arguments contain foreign account data not anchored in the reference
+    ///   block.
+    /// - If any input notes were created in block numbers higher than the reference block.
+    /// - If the execution of the transaction program fails for a reason other than a signature
+    ///   request.
+    #[maybe_async]
+    pub fn execute_until_signature(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<TransactionExecution, TransactionExecutorError> {
+        maybe_await!(self.execute_with_deferred_signatures(
+            account_id,
+            block_ref,
+            notes,
+            tx_args,
+            BTreeMap::new(),
+            true,
+            source_manager
+        ))
+    }
 
-        build_executed_transaction(tx_args, tx_inputs, trace.stack_outputs().clone(), host)
+    /// Resumes the execution of a transaction which was paused at the provided [`SigningRequest`]
+    /// using the provided signature.
+    ///
+    /// The signature is expected to be in the format expected by the signature verification
+    /// procedure on the advice stack, e.g. as produced by
+    /// [`get_falcon_signature()`](crate::auth::signatures::get_falcon_signature).
+    ///
+    /// The transaction is re-executed from the start with the signatures of the current and all
+    /// previous requests pre-seeded into the transaction host. If the transaction requests another
+    /// signature, a new [`SigningRequest`] is returned.
+    ///
+    /// # Errors:
+    ///
+    /// Returns an error in the same cases as
+    /// [`execute_until_signature()`](Self::execute_until_signature). If the signature is invalid,
+    /// the execution of the transaction program fails.
+    #[maybe_async]
+    pub fn resume_transaction(
+        &self,
+        request: SigningRequest,
+        signature: Vec<Felt>,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<TransactionExecution, TransactionExecutorError> {
+        let account_id = request.account_id();
+        let pub_key = request.pub_key();
+        let (block_ref, notes, tx_args, signatures) =
+            request.into_resume_parts([(pub_key, signature)]);
+
+        maybe_await!(self.execute_with_deferred_signatures(
+            account_id,
+            block_ref,
+            notes,
+            tx_args,
+            signatures,
+            true,
+            source_manager
+        ))
+    }
+
+    /// Resumes the execution of a transaction which was paused at the provided [`SigningRequest`]
+    /// using the provided set of signatures over the message of the request, and executes it to
+    /// completion.
+    ///
+    /// The signatures are expected to be provided as pairs of the public key and the signature,
+    /// e.g. as returned by
+    /// [`MultisigAuthenticator::collect_signatures()`](crate::auth::MultisigAuthenticator::collect_signatures).
+    /// This allows a transaction against a multisig account to be signed by several keyholders out
+    /// of band: all keyholders sign the message of the first request, since the multisig component
+    /// requests all signatures over the same message.
+    ///
+    /// Unlike [`resume_transaction()`](Self::resume_transaction), the provided signatures are
+    /// treated as final: the transaction is re-executed from the start with the signatures of the
+    /// current and all previous requests pre-seeded into the transaction host, and any signature
+    /// request which cannot be served from them is treated as declined. Thus, the signatures of
+    /// keyholders which did not sign are reported as unavailable to the multisig component.
+    ///
+    /// # Errors:
+    ///
+    /// Returns an error in the same cases as
+    /// [`execute_until_signature()`](Self::execute_until_signature). If any of the signatures is
+    /// invalid, if too few signatures were provided or if a signature which is not optional was
+    /// not provided, the execution of the transaction program fails.
+    #[maybe_async]
+    pub fn resume_transaction_with_signatures(
+        &self,
+        request: SigningRequest,
+        signatures: impl IntoIterator<Item = (Word, Vec<Felt>)>,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let account_id = request.account_id();
+        let (block_ref, notes, tx_args, signatures) = request.into_resume_parts(signatures);
+
+        let execution = maybe_await!(self.execute_with_deferred_signatures(
+            account_id,
+            block_ref,
+            notes,
+            tx_args,
+            signatures,
+            false,
+            source_manager
+        ))?;
+
+        match execution {
+            TransactionExecution::Executed(executed_tx) => Ok(*executed_tx),
+            TransactionExecution::SignatureRequired(_) => {
+                unreachable!("signature requests are not deferred")
+            },
+        }
     }
 
     // SCRIPT EXECUTION
@@ -192,6 +768,9 @@ impl<'store, 'auth> TransactionExecutor<'store, 'auth> {
     /// Executes an arbitrary script against the given account and returns the stack state at the
     /// end of execution.
     ///
+    /// This is a shorthand for [TransactionExecutor::execute_view_call] which returns only the
+    /// operand stack.
+    ///
     /// The `source_manager` is used to map potential errors back to their source code. To get the
     /// most value out of it, use the source manager from the
     /// [`Assembler`](miden_objects::assembly::Assembler) that assembled the Miden Assembly code
@@ -214,6 +793,43 @@ impl<'store, 'auth> TransactionExecutor<'store, 'auth> {
         foreign_account_inputs: Vec<AccountInputs>,
         source_manager: Arc<dyn SourceManager>,
     ) -> Result<[Felt; 16], TransactionExecutorError> {
+        let view_call = ViewCall::new(tx_script)
+            .with_advice_inputs(advice_inputs)
+            .with_foreign_account_inputs(foreign_account_inputs);
+
+        let output =
+            maybe_await!(self.execute_view_call(account_id, block_ref, view_call, source_manager))?;
+
+        Ok(*output.stack())
+    }
+
+    /// Executes the script of the provided [ViewCall] against the given account and returns the
+    /// operand stack at the end of execution, together with the advice map entries and the ranges
+    /// of the kernel memory requested by the view call.
+    ///
+    /// The returned [ViewCallOutput] can be decoded into typed values via
+    /// [ViewCallOutput::decode_stack], [ViewCallOutput::decode_advice_map_entry] and
+    /// [ViewCallOutput::decode_memory_range].
+    ///
+    /// See [TransactionExecutor::execute_tx_view_script] for the meaning of `source_manager`.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction host can not be created from the provided values.
+    /// - If the execution of the provided program fails.
+    #[maybe_async]
+    pub fn execute_view_call(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        view_call: ViewCall,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<ViewCallOutput, TransactionExecutorError> {
+        let (tx_script, advice_inputs, foreign_account_inputs, advice_map_keys, memory_ranges) =
+            view_call.into_parts();
+
+        let interrupt = self.execution_interrupt();
         let ref_blocks = [block_ref].into_iter().collect();
         let (account, seed, ref_block, mmr) =
             maybe_await!(self.data_store.get_transaction_inputs(account_id, ref_blocks))
@@ -226,34 +842,75 @@ impl<'store, 'auth> TransactionExecutor<'store, 'auth> {
         let tx_inputs = TransactionInputs::new(account, seed, ref_block, mmr, Default::default())
             .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
 
-        let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, Some(advice_inputs));
-        let advice_recorder = RecAdviceProvider::from(advice_inputs.into_inner());
-
-        let scripts_mast_store =
-            ScriptMastForestStore::new(tx_args.tx_script(), core::iter::empty::<&NoteScript>());
-
-        let mut host = TransactionHost::new(
-            &tx_inputs.account().into(),
-            advice_recorder,
-            self.data_store,
-            scripts_mast_store,
-            self.authenticator,
-            tx_args.foreign_account_code_commitments(),
-        )
-        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
+        let mut signatures = BTreeMap::new();
+        maybe_await!(self.execute_with_lazy_loading(tx_inputs, tx_args, |tx_inputs, tx_args| {
+            interrupt.check().map_err(TransactionExecutorError::ExecutionInterrupted)?;
+
+            let (kernel, stack_inputs, advice_inputs) =
+                self.prepare_kernel_inputs(&tx_inputs, &tx_args, Some(advice_inputs.clone()))?;
+            let advice_recorder = RecAdviceProvider::from(advice_inputs.into_inner());
+
+            let scripts_mast_store =
+                ScriptMastForestStore::new(tx_args.tx_script(), core::iter::empty::<&NoteScript>());
+
+            let mut host = self
+                .build_host(
+                    &tx_inputs,
+                    &tx_args,
+                    advice_recorder,
+                    scripts_mast_store,
+                    self.authenticator,
+                    &interrupt,
+                )?
+                .with_generated_signatures(signatures.clone());
+
+            let program = kernel.tx_script_main();
+            let mut process = Process::new(
+                program.kernel().clone(),
+                stack_inputs,
+                self.process_options(&interrupt),
+            )
+            .with_source_manager(source_manager.clone());
+            let stack_outputs = match process.execute(program, &mut host) {
+                Ok(stack_outputs) => stack_outputs,
+                Err(err) => {
+                    signatures.extend(host.generated_signatures().clone());
+                    return Err(map_execution_error(
+                        &mut host,
+                        &self.error_codes,
+                        self.exec_options.max_cycles(),
+                        kernel,
+                        process,
+                        err,
+                    ));
+                },
+            };
+
+            // the requested memory ranges are read from the root context, i.e. the kernel memory
+            let process_state = ProcessState::from(&process);
+            let memory = memory_ranges
+                .iter()
+                .map(|range| {
+                    let elements = range
+                        .clone()
+                        .map(|addr| {
+                            process_state.get_mem_value(ContextId::root(), addr).unwrap_or(ZERO)
+                        })
+                        .collect();
+                    (range.clone(), elements)
+                })
+                .collect();
 
-        let mut process = Process::new(
-            TransactionKernel::tx_script_main().kernel().clone(),
-            stack_inputs,
-            self.exec_options,
-        )
-        .with_source_manager(source_manager);
-        let stack_outputs = process
-            .execute(&TransactionKernel::tx_script_main(), &mut host)
-            .map_err(TransactionExecutorError::TransactionProgramExecutionFailed)?;
+            let (advice_provider, ..) = host.into_parts();
+            let advice_map_entries = advice_map_keys
+                .iter()
+                .filter_map(|key| {
+                    advice_provider.get_mapped_values(key).map(|values| (*key, values.to_vec()))
+                })
+                .collect();
 
-        Ok(*stack_outputs)
+            Ok(ViewCallOutput::new(*stack_outputs, advice_map_entries, memory))
+        }))
     }
 
     // CHECK CONSUMABILITY
@@ -284,93 +941,579 @@ impl<'store, 'auth> TransactionExecutor<'store, 'auth> {
         tx_args: TransactionArgs,
         source_manager: Arc<dyn SourceManager>,
     ) -> Result<NoteAccountExecution, TransactionExecutorError> {
-        let mut ref_blocks = validate_input_notes(&notes, block_ref)?;
-        ref_blocks.insert(block_ref);
+        let interrupt = self.execution_interrupt();
+        let tx_inputs =
+            maybe_await!(self.prepare_tx_inputs(account_id, block_ref, notes, &tx_args))?;
+
+        let mut signatures = BTreeMap::new();
+        maybe_await!(self.execute_with_lazy_loading(tx_inputs, tx_args, |tx_inputs, tx_args| {
+            interrupt.check().map_err(TransactionExecutorError::ExecutionInterrupted)?;
+
+            let (kernel, stack_inputs, advice_inputs) =
+                self.prepare_kernel_inputs(&tx_inputs, &tx_args, None)?;
+
+            let advice_provider = MemAdviceProvider::from(advice_inputs.into_inner());
+
+            let scripts_mast_store = ScriptMastForestStore::new(
+                tx_args.tx_script(),
+                tx_inputs.input_notes().iter().map(|n| n.note().script()),
+            );
+
+            let mut host = self
+                .build_host(
+                    &tx_inputs,
+                    &tx_args,
+                    advice_provider,
+                    scripts_mast_store,
+                    self.authenticator,
+                    &interrupt,
+                )?
+                .with_generated_signatures(signatures.clone());
+
+            // execute the transaction kernel
+            let result = self.execute_program(
+                kernel,
+                kernel.main(),
+                stack_inputs,
+                &mut host,
+                source_manager.clone(),
+            );
+            signatures.extend(host.generated_signatures().clone());
+
+            match result {
+                Ok(_) => Ok(NoteAccountExecution::Success),
+                // an interrupted execution says nothing about the consumability of the notes, and
+                // neither does an execution which failed to load missing account data; an
+                // execution halted at missing account data is retried once the data is loaded
+                Err(
+                    err @ (TransactionExecutorError::ExecutionInterrupted(_)
+                    | TransactionExecutorError::AccountDataNotLoaded(_)
+                    | TransactionExecutorError::FetchAccountDataFailed(_)
+                    | TransactionExecutorError::InvalidStorageMapWitness(_)
+                    | TransactionExecutorError::InvalidVaultWitness(_)
+                    | TransactionExecutorError::InvalidAccountWitness(..)
+                    | TransactionExecutorError::ForeignAccountNotAnchoredInReference(_)),
+                ) => Err(err),
+                Err(tx_execution_error) => {
+                    let notes = host.tx_progress().note_execution();
+
+                    // empty notes vector means that we didn't process the notes, so an error
+                    // occurred somewhere else
+                    if notes.is_empty() {
+                        return Err(tx_execution_error);
+                    }
+
+                    let ((last_note, last_note_interval), success_notes) = notes
+                        .split_last()
+                        .expect("notes vector should not be empty because we just checked");
+
+                    // if the interval end of the last note is specified, then an error occurred
+                    // after notes processing
+                    if last_note_interval.end().is_some() {
+                        return Err(tx_execution_error);
+                    }
+
+                    Ok(NoteAccountExecution::Failure {
+                        failed_note_id: *last_note,
+                        successful_notes: success_notes.iter().map(|(note, _)| *note).collect(),
+                        error: Some(tx_execution_error),
+                    })
+                },
+            }
+        }))
+    }
 
-        let (account, seed, ref_block, mmr) =
-            maybe_await!(self.data_store.get_transaction_inputs(account_id, ref_blocks))
-                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
 
-        validate_account_inputs(&tx_args, &ref_block)?;
+    /// Returns the [ExecutionInterrupt] for an execution starting now, built from the
+    /// cancellation token and the timeout of this executor.
+    fn execution_interrupt(&self) -> ExecutionInterrupt {
+        let mut interrupt = ExecutionInterrupt::new();
+        if let Some(token) = &self.cancellation_token {
+            interrupt = interrupt.with_cancellation_token(token.clone());
+        }
+        #[cfg(feature = "std")]
+        if let Some(timeout) = self.timeout {
+            interrupt = interrupt.with_deadline(std::time::Instant::now() + timeout);
+        }
+        #[cfg(feature = "std")]
+        if let Some(cycles_per_second) = self.cycles_per_second {
+            interrupt = interrupt.with_cycles_per_second(cycles_per_second);
+        }
+        interrupt
+    }
 
-        let tx_inputs = TransactionInputs::new(account, seed, ref_block, mmr, notes)
-            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+    /// Returns the [ExecutionOptions] for an execution starting now, with the maximum number of
+    /// cycles bounded by the deadline of the provided [ExecutionInterrupt] (see
+    /// [`ExecutionInterrupt::max_cycles()`]).
+    fn process_options(&self, interrupt: &ExecutionInterrupt) -> ExecutionOptions {
+        let max_cycles = interrupt
+            .max_cycles(self.exec_options.max_cycles())
+            .max(MIN_TX_EXECUTION_CYCLES);
+
+        ExecutionOptions::new(
+            Some(max_cycles),
+            self.exec_options.expected_cycles().min(max_cycles),
+            self.exec_options.enable_tracing(),
+            self.exec_options.enable_debugging(),
+        )
+        .expect("max cycles should not be less than the minimum number of execution cycles")
+    }
 
-        let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
+    /// Selects the kernel supported by the reference block of the transaction from the kernel
+    /// registry of this executor, and prepares the stack and advice inputs for executing it.
+    ///
+    /// If `init_advice_inputs` is provided, they will be included in the returned advice inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the reference block does not support any of the registered kernels.
+    fn prepare_kernel_inputs(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        init_advice_inputs: Option<AdviceInputs>,
+    ) -> Result<(&VersionedKernel, StackInputs, TransactionAdviceInputs), TransactionExecutorError>
+    {
+        let tx_kernel_commitment = tx_inputs.block_header().tx_kernel_commitment();
+        let kernel = self
+            .kernel_registry
+            .select(tx_kernel_commitment)
+            .ok_or(TransactionExecutorError::UnsupportedTransactionKernel(tx_kernel_commitment))?;
+        let (stack_inputs, advice_inputs) = self.kernel_registry.prepare_inputs(
+            kernel.version(),
+            tx_inputs,
+            tx_args,
+            init_advice_inputs,
+        );
 
-        let advice_provider = MemAdviceProvider::from(advice_inputs.into_inner());
+        Ok((kernel, stack_inputs, advice_inputs))
+    }
 
-        let scripts_mast_store = ScriptMastForestStore::new(
-            tx_args.tx_script(),
-            tx_inputs.input_notes().iter().map(|n| n.note().script()),
-        );
+    /// Executes the provided program of the specified kernel against the provided host and returns
+    /// the resulting execution trace.
+    ///
+    /// # Errors
+    /// Returns an error if the execution of the program fails (see [map_execution_error]).
+    fn execute_program<A: AdviceProvider>(
+        &self,
+        kernel: &VersionedKernel,
+        program: &Program,
+        stack_inputs: StackInputs,
+        host: &mut TransactionHost<A>,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<ExecutionTrace, TransactionExecutorError> {
+        let exec_options = self.process_options(host.interrupt());
+        let mut process = Process::new(program.kernel().clone(), stack_inputs, exec_options)
+            .with_source_manager(source_manager);
+        match process.execute(program, host) {
+            Ok(stack_outputs) => Ok(ExecutionTrace::new(process, stack_outputs)),
+            Err(err) => Err(map_execution_error(
+                host,
+                &self.error_codes,
+                self.exec_options.max_cycles(),
+                kernel,
+                process,
+                err,
+            )),
+        }
+    }
 
-        let mut host = TransactionHost::new(
-            &tx_inputs.account().into(),
-            advice_provider,
+    /// Creates a [TransactionHost] for executing a transaction with the provided inputs and
+    /// arguments.
+    ///
+    /// Unless the `async` feature is enabled, the host loads the account data which is accessed by
+    /// the transaction but missing from the provided inputs from the [DataStore] of this executor
+    /// during execution (see [DataStore] for details).
+    fn build_host<A: AdviceProvider>(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        adv_provider: A,
+        scripts_mast_store: ScriptMastForestStore,
+        authenticator: Option<&'auth dyn TransactionAuthenticator>,
+        interrupt: &ExecutionInterrupt,
+    ) -> Result<TransactionHost<'store, 'auth, A>, TransactionExecutorError> {
+        let host = TransactionHost::new(
+            tx_inputs.account(),
+            adv_provider,
             self.data_store,
             scripts_mast_store,
-            self.authenticator,
-            tx_args.foreign_account_code_commitments(),
-        )
-        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
-
-        // execute the transaction kernel
-        let result = vm_processor::execute(
-            &TransactionKernel::main(),
-            stack_inputs,
-            &mut host,
-            self.exec_options,
-            source_manager,
+            authenticator,
+            tx_args,
         )
-        .map_err(TransactionExecutorError::TransactionProgramExecutionFailed);
+        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?
+        .with_interrupt(interrupt.clone())
+        .with_event_handlers(self.event_handlers.clone());
 
-        match result {
-            Ok(_) => Ok(NoteAccountExecution::Success),
-            Err(tx_execution_error) => {
-                let notes = host.tx_progress().note_execution();
+        #[cfg(not(feature = "async"))]
+        let host =
+            host.with_account_data_loader(AccountDataLoader::new(self.data_store, tx_inputs));
 
-                // empty notes vector means that we didn't process the notes, so an error
-                // occurred somewhere else
-                if notes.is_empty() {
-                    return Err(tx_execution_error);
-                }
+        Ok(host)
+    }
 
-                let ((last_note, last_note_interval), success_notes) = notes
-                    .split_last()
-                    .expect("notes vector should not be empty because we just checked");
+    /// Exports the trace of a transaction which took `num_cycles` cycles to the trace exporter of
+    /// this executor, if any.
+    fn export_trace(&self, num_cycles: usize, tx_progress: &TransactionProgress) {
+        if let Some(exporter) = &self.trace_exporter {
+            exporter.export(&TransactionTrace::new(num_cycles, tx_progress));
+        }
+    }
 
-                // if the interval end of the last note is specified, then an error occurred after
-                // notes processing
-                if last_note_interval.end().is_some() {
-                    return Err(tx_execution_error);
-                }
+    /// Fetches the data required to execute a transaction against the specified account from the
+    /// [DataStore] and validates it against the provided notes and transaction arguments.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction arguments contain foreign account data not anchored in the reference
+    ///   block.
+    /// - If any input notes were created in block numbers higher than the reference block.
+    #[maybe_async]
+    fn prepare_tx_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: &TransactionArgs,
+    ) -> Result<TransactionInputs, TransactionExecutorError> {
+        let mut ref_blocks = validate_input_notes(&notes, block_ref)?;
+        ref_blocks.insert(block_ref);
 
-                Ok(NoteAccountExecution::Failure {
-                    failed_note_id: *last_note,
-                    successful_notes: success_notes.iter().map(|(note, _)| *note).collect(),
-                    error: Some(tx_execution_error),
-                })
+        let (account, seed, ref_block, mmr) =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, ref_blocks))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+        validate_account_inputs(tx_args, &ref_block)?;
+
+        TransactionInputs::new(account, seed, ref_block, mmr, notes)
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)
+    }
+
+    /// Loads the account data which is missing from the provided transaction inputs and arguments
+    /// from the [DataStore] and adds it to them.
+    ///
+    /// The witness of missing native account data is fetched against the initial state of the
+    /// account, i.e., the storage map root and vault root of the account in the transaction
+    /// inputs. Missing foreign accounts are fetched against the reference block and added to the
+    /// foreign account inputs of the transaction arguments.
+    ///
+    /// This is used only with the `async` feature, see [`Self::execute_with_lazy_loading`].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the missing data can not be fetched from the [DataStore].
+    /// - If the fetched witness does not match the account in the transaction inputs.
+    /// - If the fetched foreign account is not anchored in the reference block.
+    #[cfg(feature = "async")]
+    #[maybe_async]
+    fn load_account_data(
+        &self,
+        tx_inputs: TransactionInputs,
+        mut tx_args: TransactionArgs,
+        missing_data: MissingAccountData,
+    ) -> Result<(TransactionInputs, TransactionArgs), TransactionExecutorError> {
+        let (mut account, seed, block_header, mmr, notes) = tx_inputs.into_parts();
+
+        match missing_data {
+            MissingAccountData::StorageMapEntry { slot_index, key } => {
+                let (_, map_root) = account
+                    .storage()
+                    .header()
+                    .slot(slot_index as usize)
+                    .map_err(TransactionExecutorError::InvalidStorageMapWitness)?;
+                let witness = maybe_await!(self.data_store.get_storage_map_witness(
+                    account.id(),
+                    (*map_root).into(),
+                    key
+                ))
+                .map_err(TransactionExecutorError::FetchAccountDataFailed)?;
+
+                account
+                    .storage_mut()
+                    .add_storage_map_proof(key, witness)
+                    .map_err(TransactionExecutorError::InvalidStorageMapWitness)?;
+            },
+            MissingAccountData::VaultAsset { vault_key } => {
+                let witness = maybe_await!(self.data_store.get_vault_asset_witness(
+                    account.id(),
+                    account.vault().root(),
+                    vault_key
+                ))
+                .map_err(TransactionExecutorError::FetchAccountDataFailed)?;
+
+                account
+                    .vault_mut()
+                    .add_proof(witness)
+                    .map_err(TransactionExecutorError::InvalidVaultWitness)?;
+            },
+            MissingAccountData::ForeignAccount { account_id } => {
+                let foreign_account_inputs = maybe_await!(
+                    self.data_store
+                        .get_foreign_account_inputs(account_id, block_header.block_num())
+                )
+                .map_err(TransactionExecutorError::FetchAccountDataFailed)?;
+
+                tx_args.add_foreign_account_inputs(foreign_account_inputs);
+                validate_account_inputs(&tx_args, &block_header)?;
             },
         }
+
+        let tx_inputs = TransactionInputs::new(account, seed, block_header, mmr, notes)
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+
+        Ok((tx_inputs, tx_args))
+    }
+
+    /// Executes the provided closure with the provided transaction inputs and arguments.
+    ///
+    /// Without the `async` feature, the account data which is accessed by the transaction but
+    /// missing from the inputs is loaded by the transaction host during execution, so the closure
+    /// is executed exactly once.
+    ///
+    /// With the `async` feature, the [DataStore] can not be queried while the VM executes the
+    /// transaction. Instead, execution is halted at missing account data, which is then loaded and
+    /// the closure is re-executed, until execution is no longer halted at missing account data.
+    /// Each storage map entry, vault asset and foreign account is loaded at most once, so if
+    /// execution is halted at data which was already loaded, the
+    /// [`TransactionExecutorError::AccountDataNotLoaded`] error is returned.
+    ///
+    /// Closures which invoke the transaction authenticator are expected to pre-seed their
+    /// transaction host with the signatures generated in previous executions and to retain the
+    /// signatures generated by the host when execution is halted, so that a re-execution does not
+    /// request the same signature from the authenticator again. The event handlers, on the other
+    /// hand, are invoked again by each re-execution.
+    #[maybe_async]
+    fn execute_with_lazy_loading<T>(
+        &self,
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+        mut execute: impl FnMut(
+            TransactionInputs,
+            TransactionArgs,
+        ) -> Result<T, TransactionExecutorError>,
+    ) -> Result<T, TransactionExecutorError> {
+        #[cfg(not(feature = "async"))]
+        return execute(tx_inputs, tx_args);
+
+        #[cfg(feature = "async")]
+        {
+            let (mut tx_inputs, mut tx_args) = (tx_inputs, tx_args);
+            let mut loaded_data = BTreeSet::new();
+            loop {
+                match execute(tx_inputs.clone(), tx_args.clone()) {
+                    Err(TransactionExecutorError::AccountDataNotLoaded(missing_data))
+                        if loaded_data.insert(missing_data) =>
+                    {
+                        (tx_inputs, tx_args) =
+                            maybe_await!(self.load_account_data(tx_inputs, tx_args, missing_data))?;
+                    },
+                    result => return result,
+                }
+            }
+        }
+    }
+
+    /// Executes a transaction with the provided inputs and returns an [`ExecutedTransaction`].
+    #[maybe_async]
+    fn execute_prepared_transaction(
+        &self,
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+        interrupt: ExecutionInterrupt,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let mut signatures = BTreeMap::new();
+        maybe_await!(self.execute_with_lazy_loading(tx_inputs, tx_args, |tx_inputs, tx_args| {
+            interrupt.check().map_err(TransactionExecutorError::ExecutionInterrupted)?;
+
+            let (kernel, stack_inputs, advice_inputs) =
+                self.prepare_kernel_inputs(&tx_inputs, &tx_args, None)?;
+
+            let advice_recorder = RecAdviceProvider::from(advice_inputs.into_inner());
+
+            let script_mast_store = ScriptMastForestStore::new(
+                tx_args.tx_script(),
+                tx_inputs.input_notes().iter().map(|n| n.note().script()),
+            );
+
+            let mut host = self
+                .build_host(
+                    &tx_inputs,
+                    &tx_args,
+                    advice_recorder,
+                    script_mast_store,
+                    self.authenticator,
+                    &interrupt,
+                )?
+                .with_generated_signatures(signatures.clone());
+
+            // Execute the transaction kernel
+            let trace = self.execute_program(
+                kernel,
+                kernel.main(),
+                stack_inputs,
+                &mut host,
+                source_manager.clone(),
+            );
+            signatures.extend(host.generated_signatures().clone());
+            let trace = trace?;
+            self.export_trace(trace.trace_len_summary().main_trace_len(), host.tx_progress());
+
+            build_executed_transaction(
+                &self.kernel_registry,
+                kernel.version(),
+                tx_args.clone(),
+                tx_inputs,
+                trace.stack_outputs().clone(),
+                trace.trace_len_summary(),
+                host,
+            )
+        }))
+    }
+
+    /// Executes a transaction with the provided signatures pre-seeded into the transaction host.
+    ///
+    /// If `defer_signatures` is set, execution is halted at the first signature request which
+    /// cannot be served from the provided signatures. Otherwise, such requests are treated as
+    /// declined.
+    #[maybe_async]
+    fn execute_with_deferred_signatures(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+        signatures: BTreeMap<Digest, Vec<Felt>>,
+        defer_signatures: bool,
+        source_manager: Arc<dyn SourceManager>,
+    ) -> Result<TransactionExecution, TransactionExecutorError> {
+        let interrupt = self.execution_interrupt();
+        let tx_inputs =
+            maybe_await!(self.prepare_tx_inputs(account_id, block_ref, notes, &tx_args))?;
+
+        maybe_await!(self.execute_with_lazy_loading(tx_inputs, tx_args, |tx_inputs, tx_args| {
+            interrupt.check().map_err(TransactionExecutorError::ExecutionInterrupted)?;
+
+            let (kernel, stack_inputs, advice_inputs) =
+                self.prepare_kernel_inputs(&tx_inputs, &tx_args, None)?;
+
+            let advice_recorder = RecAdviceProvider::from(advice_inputs.into_inner());
+
+            let script_mast_store = ScriptMastForestStore::new(
+                tx_args.tx_script(),
+                tx_inputs.input_notes().iter().map(|n| n.note().script()),
+            );
+
+            let mut host = self
+                .build_host(
+                    &tx_inputs,
+                    &tx_args,
+                    advice_recorder,
+                    script_mast_store,
+                    None,
+                    &interrupt,
+                )?
+                .with_generated_signatures(signatures.clone());
+            if defer_signatures {
+                host = host.with_deferred_signatures();
+            }
+
+            let result = self.execute_program(
+                kernel,
+                kernel.main(),
+                stack_inputs,
+                &mut host,
+                source_manager.clone(),
+            );
+
+            match result {
+                Ok(trace) => {
+                    let executed_tx = build_executed_transaction(
+                        &self.kernel_registry,
+                        kernel.version(),
+                        tx_args.clone(),
+                        tx_inputs,
+                        trace.stack_outputs().clone(),
+                        trace.trace_len_summary(),
+                        host,
+                    )?;
+                    Ok(TransactionExecution::Executed(Box::new(executed_tx)))
+                },
+                Err(err) => {
+                    let Some((pub_key, message)) = host.deferred_signature() else {
+                        return Err(err);
+                    };
+
+                    let (_, account_delta, ..) = host.into_parts();
+                    let request = SigningRequest::new(
+                        pub_key,
+                        message,
+                        account_delta,
+                        block_ref,
+                        tx_inputs.input_notes().clone(),
+                        tx_args.clone(),
+                        signatures.clone(),
+                    );
+                    Ok(TransactionExecution::SignatureRequired(Box::new(request)))
+                },
+            }
+        }))
     }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns the provided transaction inputs with the code of the account replaced by the provided
+/// code.
+fn replace_account_code(
+    tx_inputs: TransactionInputs,
+    code: AccountCode,
+) -> Result<TransactionInputs, TransactionExecutorError> {
+    let (account, account_seed, block_header, block_chain, input_notes) = tx_inputs.into_parts();
+    let account = PartialAccount::new(
+        account.id(),
+        account.nonce(),
+        code,
+        account.storage().clone(),
+        account.vault().clone(),
+    );
+
+    TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+        .map_err(TransactionExecutorError::InvalidTransactionInputs)
+}
+
 /// Creates a new [ExecutedTransaction] from the provided data.
+///
+/// The account data loaded by the host during execution is added to the transaction inputs and
+/// arguments, so that the transaction can be proven without access to the [DataStore].
+///
+/// The transaction must have been executed against the kernel with the specified version from the
+/// provided registry.
 fn build_executed_transaction(
-    tx_args: TransactionArgs,
-    tx_inputs: TransactionInputs,
+    kernel_registry: &TransactionKernelRegistry,
+    kernel_version: u8,
+    mut tx_args: TransactionArgs,
+    mut tx_inputs: TransactionInputs,
     stack_outputs: StackOutputs,
-    host: TransactionHost<RecAdviceProvider>,
+    trace_len_summary: &TraceLenSummary,
+    mut host: TransactionHost<RecAdviceProvider>,
 ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    if let Some((account, foreign_account_inputs)) = host.take_loaded_account_data() {
+        let (_, seed, block_header, mmr, notes) = tx_inputs.into_parts();
+        tx_inputs = TransactionInputs::new(account, seed, block_header, mmr, notes)
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+        for account_inputs in foreign_account_inputs {
+            tx_args.add_foreign_account_inputs(account_inputs);
+        }
+    }
+
     let (advice_recorder, account_delta, output_notes, generated_signatures, tx_progress) =
         host.into_parts();
 
-    let (mut advice_witness, _, map, _store) = advice_recorder.finalize();
+    let (advice_witness, _, map, _store) = advice_recorder.finalize();
 
     let advice_map = AdviceMap::from(map);
     let tx_outputs =
@@ -404,7 +1547,14 @@ fn build_executed_transaction(
         });
     }
 
-    // introduce generated signatures into the witness inputs
+    // drop the redundant data from the witness and introduce the generated signatures into it
+    let mut advice_witness = minimize_advice_witness(
+        kernel_registry,
+        kernel_version,
+        &tx_inputs,
+        &tx_args,
+        advice_witness,
+    );
     advice_witness.extend_map(generated_signatures);
 
     Ok(ExecutedTransaction::new(
@@ -413,10 +1563,163 @@ fn build_executed_transaction(
         account_delta,
         tx_args,
         advice_witness,
-        tx_progress.into(),
+        build_measurements(tx_progress, trace_len_summary),
     ))
 }
 
+/// Returns the [TransactionMeasurements] of a transaction with the provided progress and trace
+/// lengths.
+fn build_measurements(
+    tx_progress: TransactionProgress,
+    trace_len_summary: &TraceLenSummary,
+) -> TransactionMeasurements {
+    let mut measurements = TransactionMeasurements::from(tx_progress);
+    measurements.range_trace_length = trace_len_summary.range_trace_len();
+    measurements.chiplets_trace_length = trace_len_summary.chiplets_trace_len().trace_len();
+    measurements
+}
+
+/// Returns the subset of the recorded advice witness which cannot be re-created from the
+/// transaction inputs and the transaction arguments.
+///
+/// The recorded witness contains the entire initial advice stack as well as all advice map
+/// entries and merkle store nodes read during execution. When the transaction is proven, the
+/// advice stack and all advice data prepared from the transaction inputs are re-created via
+/// [TransactionKernelRegistry::prepare_inputs], so they are dropped from the witness. The advice
+/// map and merkle store of the transaction arguments, however, are not part of the
+/// [`TransactionWitness`](miden_objects::transaction::TransactionWitness), so the entries of them
+/// which were read are retained.
+fn minimize_advice_witness(
+    kernel_registry: &TransactionKernelRegistry,
+    kernel_version: u8,
+    tx_inputs: &TransactionInputs,
+    tx_args: &TransactionArgs,
+    advice_witness: AdviceInputs,
+) -> AdviceInputs {
+    let mut witness_tx_args = tx_args.clone();
+    witness_tx_args.clear_advice_map_and_store();
+
+    let (_, prepared_inputs) =
+        kernel_registry.prepare_inputs(kernel_version, tx_inputs, &witness_tx_args, None);
+    let (_, prepared_map, prepared_store) =
+        MemAdviceProvider::from(prepared_inputs.into_inner()).into_parts();
+    let prepared_nodes = prepared_store.into_inner();
+
+    let (_, recorded_map, recorded_store) = MemAdviceProvider::from(advice_witness).into_parts();
+    let map = recorded_map
+        .into_iter()
+        .filter(|(key, values)| prepared_map.get(key) != Some(values));
+    let nodes = recorded_store
+        .inner_nodes()
+        .filter(|node| !prepared_nodes.contains_key(&node.value))
+        .collect();
+
+    AdviceInputs::default().with_map(map).with_merkle_store(nodes)
+}
+
+/// Converts an error returned by the VM while executing a program of the specified kernel in the
+/// provided process into a [TransactionExecutorError].
+///
+/// If execution was halted because the interrupt of the host fired, the error is reported as
+/// [`TransactionExecutorError::ExecutionInterrupted`]. If execution was halted because the
+/// transaction accessed account data missing from the advice provider, the error is reported as
+/// [`TransactionExecutorError::AccountDataNotLoaded`]. Otherwise, the error is reported as an
+/// [ExecutionFailure].
+fn map_execution_error<A: AdviceProvider>(
+    host: &mut TransactionHost<A>,
+    error_codes: &ErrorCodeRegistry,
+    max_cycles: u32,
+    kernel: &VersionedKernel,
+    process: Process,
+    err: ExecutionError,
+) -> TransactionExecutorError {
+    if let Some(reason) = host.interrupt_reason() {
+        return TransactionExecutorError::ExecutionInterrupted(reason);
+    }
+
+    // the number of cycles is bounded by the deadline of the interrupt of the host, so exceeding a
+    // bound below the configured maximum means that the deadline has passed
+    if matches!(err, ExecutionError::CycleLimitExceeded(limit) if limit < max_cycles) {
+        return TransactionExecutorError::ExecutionInterrupted(InterruptReason::DeadlineExceeded);
+    }
+
+    if let Some(err) = host.take_account_data_error() {
+        return err;
+    }
+
+    if let Some(missing_data) = host.missing_account_data() {
+        return TransactionExecutorError::AccountDataNotLoaded(missing_data);
+    }
+
+    let procedure = failing_procedure(process, kernel, error_codes);
+    TransactionExecutorError::TransactionProgramExecutionFailed(Box::new(build_execution_failure(
+        host,
+        error_codes,
+        err,
+        procedure,
+    )))
+}
+
+/// Builds the [ExecutionFailure] of a failed execution of the transaction kernel program from the
+/// provided error, the state of the host and the name of the procedure in which the execution
+/// failed, if known.
+pub(crate) fn build_execution_failure<A: AdviceProvider>(
+    host: &TransactionHost<A>,
+    error_codes: &ErrorCodeRegistry,
+    err: ExecutionError,
+    procedure: Option<String>,
+) -> ExecutionFailure {
+    let decoded_error = error_code(&err).and_then(|code| error_codes.decode(code));
+    // the note being executed is the last one whose execution started but did not end
+    let note_id = host
+        .tx_progress()
+        .note_execution()
+        .last()
+        .filter(|(_, interval)| interval.end().is_none())
+        .map(|(note_id, _)| *note_id);
+
+    ExecutionFailure::new(err, decoded_error, note_id, procedure)
+}
+
+/// Returns the name of the procedure which the provided process executed when executing a program
+/// of the specified kernel failed.
+///
+/// See [ExecutionFailure] for details on how the procedure is resolved.
+pub(crate) fn failing_procedure(
+    process: Process,
+    kernel: &VersionedKernel,
+    error_codes: &ErrorCodeRegistry,
+) -> Option<String> {
+    let (system, decoder, _, _, chiplets) = process.into_parts();
+
+    // the assembly operations are recorded only in debug mode, and only for code assembled in
+    // debug mode
+    if decoder.in_debug_mode() {
+        let clk = system.clk().as_usize();
+        let assembly_op = decoder
+            .debug_info()
+            .assembly_ops()
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= clk)
+            .filter(|(start, asmop)| clk < start + asmop.num_cycles() as usize);
+        if let Some((_, asmop)) = assembly_op {
+            return Some(asmop.context_name().to_string());
+        }
+    }
+
+    // kernel procedures are invoked via `exec_kernel_proc`, which records the offset of the
+    // invoked procedure in the kernel memory
+    if system.in_syscall() {
+        let offset = chiplets
+            .memory
+            .get_value(ContextId::root(), CURRENT_KERNEL_PROCEDURE_OFFSET_PTR)?;
+        return kernel.procedure_name(offset.as_int() as usize).map(String::from);
+    }
+
+    error_codes.procedure_name(Digest::from(system.fn_hash())).map(String::from)
+}
+
 /// Validates the account inputs against the reference block header.
 fn validate_account_inputs(
     tx_args: &TransactionArgs,
@@ -424,14 +1727,23 @@ fn validate_account_inputs(
 ) -> Result<(), TransactionExecutorError> {
     // Validate that foreign account inputs are anchored in the reference block
     for foreign_account in tx_args.foreign_account_inputs() {
-        let computed_account_root = foreign_account.compute_account_root().map_err(|err| {
-            TransactionExecutorError::InvalidAccountWitness(foreign_account.id(), err)
-        })?;
-        if computed_account_root != ref_block.account_root() {
-            return Err(TransactionExecutorError::ForeignAccountNotAnchoredInReference(
-                foreign_account.id(),
-            ));
-        }
+        validate_foreign_account_inputs(foreign_account, ref_block)?;
+    }
+    Ok(())
+}
+
+/// Validates that the provided foreign account inputs are anchored in the reference block.
+pub(crate) fn validate_foreign_account_inputs(
+    foreign_account: &AccountInputs,
+    ref_block: &BlockHeader,
+) -> Result<(), TransactionExecutorError> {
+    let computed_account_root = foreign_account.compute_account_root().map_err(|err| {
+        TransactionExecutorError::InvalidAccountWitness(foreign_account.id(), err)
+    })?;
+    if computed_account_root != ref_block.account_root() {
+        return Err(TransactionExecutorError::ForeignAccountNotAnchoredInReference(
+            foreign_account.id(),
+        ));
     }
     Ok(())
 }
@@ -38,6 +38,11 @@ impl<'a> NoteConsumptionChecker<'a> {
     ///     associated with `Failure` variant contains the ID of the failed note, a vector of IDs of
     ///     the notes, which were successfully executed, and the [TransactionExecutorError] if the
     ///     check failed during the execution stage.
+    ///
+    /// If the execution is cancelled or exceeds the timeout of the underlying
+    /// [TransactionExecutor], [`TransactionExecutorError::ExecutionInterrupted`] is returned as an
+    /// error rather than as a `Failure`, since the interruption does not indicate whether the notes
+    /// can be consumed.
     #[maybe_async]
     pub fn check_notes_consumability(
         &self,
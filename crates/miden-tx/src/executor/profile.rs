@@ -0,0 +1,345 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::Range;
+
+use miden_lib::transaction::VersionedKernel;
+use miden_objects::{Felt, note::NoteId};
+use vm_processor::{AssemblyOp, Operation, RowIndex};
+
+use crate::host::TransactionProgress;
+
+// TRANSACTION PROFILE
+// ================================================================================================
+
+/// Attribution of the cycles of an executed transaction to the procedures executed during the
+/// transaction.
+///
+/// A transaction profile is produced by
+/// [`TransactionExecutor::profile_transaction()`](super::TransactionExecutor::profile_transaction)
+/// and maps call stacks to the number of cycles spent in the innermost frame of each stack. The
+/// root frames of every stack correspond to the stages of transaction execution reported by
+/// [`TransactionProgress`] (i.e., prologue, note execution, transaction script processing and
+/// epilogue), and the remaining frames correspond to the procedures executed during that stage.
+///
+/// Kernel procedures invoked via `syscall` (e.g., `account_get_item`) are always reported under
+/// their names in the kernel, independently of whether the kernel was assembled with debug
+/// information. All other procedures are identified by the names recorded in the assembly debug
+/// information. Thus, only code assembled in debug mode is attributed to these procedures; the
+/// cycles of code without debug information are attributed to the innermost kernel procedure or
+/// procedure with debug information which executed it, or to the stage itself.
+///
+/// The profile can be rendered in the folded-stack format consumed by flamegraph tools via
+/// [`TransactionProfile::to_folded_stacks`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionProfile {
+    stacks: BTreeMap<String, usize>,
+    total_cycles: usize,
+}
+
+impl TransactionProfile {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionProfile] built from the debug information recorded while
+    /// executing a transaction.
+    ///
+    /// `num_cycles` is the number of executed operations, `assembly_ops` is the list of assembly
+    /// operations executed during the transaction together with the cycle at which each of them
+    /// started, sorted by cycle, and `kernel_calls` are the invocations of kernel procedures as
+    /// returned by [kernel_calls].
+    ///
+    /// Since the VM does not record the call stack, the stack is reconstructed from the sequence of
+    /// procedures which executed assembly operations: entering a procedure which is not on the
+    /// stack pushes it onto the stack, while returning to a procedure which is already on the
+    /// stack pops all frames above it. Kernel procedures are pushed onto the stack for the cycles
+    /// of their invocations.
+    pub(crate) fn new(
+        num_cycles: usize,
+        assembly_ops: &[(usize, AssemblyOp)],
+        kernel_calls: &[(Range<usize>, &str)],
+        tx_progress: &TransactionProgress,
+    ) -> Self {
+        let stages = StageMap::new(tx_progress);
+
+        let mut stacks = BTreeMap::new();
+        let mut call_stack: Vec<&str> = Vec::new();
+        let mut current_stage = None;
+        let mut current_key = String::new();
+        let mut run_length = 0;
+
+        let mut asmop_idx = 0;
+        let mut active_asmop: Option<&(usize, AssemblyOp)> = None;
+
+        // the end of each active kernel call together with the size of the call stack at its start
+        let mut kernel_call_idx = 0;
+        let mut active_kernel_calls: Vec<(usize, usize)> = Vec::new();
+
+        for clk in 0..num_cycles {
+            while asmop_idx < assembly_ops.len() && assembly_ops[asmop_idx].0 <= clk {
+                active_asmop = Some(&assembly_ops[asmop_idx]);
+                asmop_idx += 1;
+            }
+            let procedure = active_asmop
+                .filter(|(start, asmop)| clk < start + asmop.num_cycles() as usize)
+                .map(|(_, asmop)| asmop.context_name());
+
+            let stage = stages.stage_at(clk);
+            let mut stack_changed = false;
+            if current_stage != Some(stage) {
+                current_stage = Some(stage);
+                call_stack.clear();
+                active_kernel_calls.clear();
+                stack_changed = true;
+            }
+
+            while let Some(&(end, stack_len)) = active_kernel_calls.last() {
+                if clk < end {
+                    break;
+                }
+                active_kernel_calls.pop();
+                call_stack.truncate(stack_len);
+                stack_changed = true;
+            }
+            while kernel_call_idx < kernel_calls.len()
+                && kernel_calls[kernel_call_idx].0.start <= clk
+            {
+                let (cycles, procedure) = &kernel_calls[kernel_call_idx];
+                active_kernel_calls.push((cycles.end, call_stack.len()));
+                call_stack.push(procedure);
+                stack_changed = true;
+                kernel_call_idx += 1;
+            }
+
+            if let Some(procedure) = procedure {
+                match call_stack.iter().rposition(|frame| *frame == procedure) {
+                    Some(pos) if pos + 1 == call_stack.len() => (),
+                    Some(pos) => {
+                        call_stack.truncate(pos + 1);
+                        stack_changed = true;
+                    },
+                    None => {
+                        call_stack.push(procedure);
+                        stack_changed = true;
+                    },
+                }
+            }
+
+            if stack_changed {
+                if run_length > 0 {
+                    *stacks.entry(core::mem::take(&mut current_key)).or_default() += run_length;
+                }
+                current_key = build_stack_key(&stages, stage, &call_stack);
+                run_length = 0;
+            }
+            run_length += 1;
+        }
+
+        if run_length > 0 {
+            *stacks.entry(current_key).or_default() += run_length;
+        }
+
+        Self { stacks, total_cycles: num_cycles }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the total number of cycles executed by the transaction.
+    pub fn total_cycles(&self) -> usize {
+        self.total_cycles
+    }
+
+    /// Returns an iterator over the recorded call stacks and the number of cycles spent in the
+    /// innermost frame of each stack.
+    ///
+    /// The frames of a stack are separated by `;`, starting from the root frame.
+    pub fn stacks(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.stacks.iter().map(|(stack, cycles)| (stack.as_str(), *cycles))
+    }
+
+    /// Returns the number of cycles spent in each frame, excluding the cycles spent in the frames
+    /// called from it.
+    pub fn self_cycles(&self) -> BTreeMap<&str, usize> {
+        let mut self_cycles = BTreeMap::new();
+        for (stack, cycles) in self.stacks() {
+            let frame = stack.rsplit(';').next().expect("stack should have at least one frame");
+            *self_cycles.entry(frame).or_default() += cycles;
+        }
+        self_cycles
+    }
+
+    /// Returns the profile in the folded-stack format, i.e., one line per call stack consisting
+    /// of the `;`-separated frames followed by a space and the number of cycles.
+    ///
+    /// The output can be passed directly to flamegraph tools such as `flamegraph.pl` or
+    /// `inferno-flamegraph`.
+    pub fn to_folded_stacks(&self) -> String {
+        let mut output = String::new();
+        for (stack, cycles) in self.stacks() {
+            output.push_str(stack);
+            output.push(' ');
+            output.push_str(&cycles.to_string());
+            output.push('\n');
+        }
+        output
+    }
+}
+
+// KERNEL CALLS
+// ================================================================================================
+
+/// Returns the ranges of cycles spent in invocations of the procedures of the provided kernel,
+/// together with the names of the invoked procedures, sorted by cycle.
+///
+/// `operations` are the operations executed during the transaction, and `stack_top_at` returns the
+/// element on top of the stack at the specified cycle. Kernel procedures are invoked via a
+/// `syscall` to `exec_kernel_proc` with the offset of the invoked procedure on top of the stack,
+/// and each `syscall` block ends with the `END` operation matching its `SYSCALL` operation.
+pub(crate) fn kernel_calls<'a>(
+    operations: &[Operation],
+    kernel: &'a VersionedKernel,
+    stack_top_at: impl Fn(usize) -> Felt,
+) -> Vec<(Range<usize>, &'a str)> {
+    let mut kernel_calls = Vec::new();
+    // the start of each open control block, if the block is a syscall
+    let mut blocks: Vec<Option<usize>> = Vec::new();
+
+    for (clk, op) in operations.iter().enumerate() {
+        match op {
+            Operation::SysCall => blocks.push(Some(clk)),
+            Operation::Join
+            | Operation::Split
+            | Operation::Loop
+            | Operation::Call
+            | Operation::Dyn
+            | Operation::Dyncall
+            | Operation::Span => blocks.push(None),
+            Operation::End => {
+                if let Some(Some(start)) = blocks.pop() {
+                    let offset = stack_top_at(start).as_int() as usize;
+                    if let Some(procedure) = kernel.procedure_name(offset) {
+                        kernel_calls.push((start..clk + 1, procedure));
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+
+    // kernel calls are recorded when they end, so nested calls precede the calls enclosing them
+    kernel_calls.sort_by_key(|(cycles, _)| cycles.start);
+    kernel_calls
+}
+
+// STAGE MAP
+// ================================================================================================
+
+/// The stage of transaction execution a cycle belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Transaction,
+    Prologue,
+    NotesProcessing,
+    NoteExecution(usize),
+    TxScriptProcessing,
+    Epilogue,
+}
+
+/// Maps cycles to the stages of transaction execution recorded in a [TransactionProgress].
+struct StageMap {
+    prologue: Option<Range<usize>>,
+    notes_processing: Option<Range<usize>>,
+    note_execution: Vec<(NoteId, Range<usize>)>,
+    tx_script_processing: Option<Range<usize>>,
+    epilogue: Option<Range<usize>>,
+}
+
+impl StageMap {
+    fn new(tx_progress: &TransactionProgress) -> Self {
+        let prologue = tx_progress.prologue();
+        let notes_processing = tx_progress.notes_processing();
+        let tx_script_processing = tx_progress.tx_script_processing();
+        let epilogue = tx_progress.epilogue();
+
+        Self {
+            prologue: cycle_range(prologue.start(), prologue.end()),
+            notes_processing: cycle_range(notes_processing.start(), notes_processing.end()),
+            note_execution: tx_progress
+                .note_execution()
+                .iter()
+                .filter_map(|(note_id, interval)| {
+                    cycle_range(interval.start(), interval.end()).map(|range| (*note_id, range))
+                })
+                .collect(),
+            tx_script_processing: cycle_range(
+                tx_script_processing.start(),
+                tx_script_processing.end(),
+            ),
+            epilogue: cycle_range(epilogue.start(), epilogue.end()),
+        }
+    }
+
+    /// Returns the stage to which the specified cycle belongs.
+    fn stage_at(&self, clk: usize) -> Stage {
+        let contains =
+            |range: &Option<Range<usize>>| range.as_ref().is_some_and(|r| r.contains(&clk));
+
+        if contains(&self.prologue) {
+            Stage::Prologue
+        } else if contains(&self.notes_processing) {
+            // note executions are recorded in the order of execution, so they are sorted by cycle
+            let idx = self.note_execution.partition_point(|(_, range)| range.start <= clk);
+            match idx.checked_sub(1) {
+                Some(idx) if self.note_execution[idx].1.contains(&clk) => Stage::NoteExecution(idx),
+                _ => Stage::NotesProcessing,
+            }
+        } else if contains(&self.tx_script_processing) {
+            Stage::TxScriptProcessing
+        } else if contains(&self.epilogue) {
+            Stage::Epilogue
+        } else {
+            Stage::Transaction
+        }
+    }
+
+    /// Returns the frames of the specified stage, separated by `;`.
+    fn stage_frames(&self, stage: Stage) -> String {
+        match stage {
+            Stage::Transaction => String::from("transaction"),
+            Stage::Prologue => String::from("transaction;prologue"),
+            Stage::NotesProcessing => String::from("transaction;notes_processing"),
+            Stage::NoteExecution(idx) => {
+                format!("transaction;notes_processing;note({})", self.note_execution[idx].0)
+            },
+            Stage::TxScriptProcessing => String::from("transaction;tx_script_processing"),
+            Stage::Epilogue => String::from("transaction;epilogue"),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the range of cycles covered by an interval, or `None` if the interval was not started.
+///
+/// An interval which was started but not finished is assumed to extend until the end of execution.
+fn cycle_range(start: Option<RowIndex>, end: Option<RowIndex>) -> Option<Range<usize>> {
+    let start = start?.as_usize();
+    let end = end.map(|end| end.as_usize()).unwrap_or(usize::MAX);
+    Some(start..end)
+}
+
+/// Builds the folded-stack key for the specified stage and call stack.
+fn build_stack_key(stages: &StageMap, stage: Stage, call_stack: &[&str]) -> String {
+    let mut key = stages.stage_frames(stage);
+    for frame in call_stack {
+        key.push(';');
+        // `;` separates frames and whitespace separates the stack from the cycle count, so neither
+        // may appear in a frame name
+        key.extend(frame.chars().map(|c| if c == ';' || c.is_whitespace() { '_' } else { c }));
+    }
+    key
+}
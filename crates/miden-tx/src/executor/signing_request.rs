@@ -0,0 +1,180 @@
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+
+use miden_objects::{
+    Digest, Felt, Hasher, Word,
+    account::{AccountDelta, AccountId},
+    block::BlockNumber,
+    transaction::{ExecutedTransaction, InputNote, InputNotes, TransactionArgs},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+// TRANSACTION EXECUTION
+// ================================================================================================
+
+/// Describes the outcome of a transaction execution in which signature generation is deferred.
+///
+/// See [`TransactionExecutor::execute_until_signature()`](super::TransactionExecutor::execute_until_signature)
+/// for details.
+#[derive(Debug)]
+pub enum TransactionExecution {
+    /// The transaction was executed to completion.
+    Executed(Box<ExecutedTransaction>),
+    /// Execution was paused at a signature request which has to be signed before execution can
+    /// be resumed.
+    SignatureRequired(Box<SigningRequest>),
+}
+
+// SIGNING REQUEST
+// ================================================================================================
+
+/// A signature request at which the execution of a transaction was paused.
+///
+/// A signing request contains the public key and the message which have to be signed, as well as
+/// the account delta accumulated up to the point of the request, which allows the signer to review
+/// the changes made to the account before signing. The signature can be produced out of band (e.g.,
+/// by an offline signer) and passed to
+/// [`TransactionExecutor::resume_transaction()`](super::TransactionExecutor::resume_transaction).
+///
+/// In addition, the request keeps all the data required to re-execute the transaction, including
+/// the signatures provided for any previous requests of the same transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SigningRequest {
+    pub_key: Word,
+    message: Word,
+    account_delta: AccountDelta,
+    block_ref: BlockNumber,
+    notes: InputNotes<InputNote>,
+    tx_args: TransactionArgs,
+    signatures: BTreeMap<Digest, Vec<Felt>>,
+}
+
+impl SigningRequest {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [SigningRequest] instantiated from the provided data.
+    pub(crate) fn new(
+        pub_key: Word,
+        message: Word,
+        account_delta: AccountDelta,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+        signatures: BTreeMap<Digest, Vec<Felt>>,
+    ) -> Self {
+        Self {
+            pub_key,
+            message,
+            account_delta,
+            block_ref,
+            notes,
+            tx_args,
+            signatures,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the public key against which the message has to be signed.
+    pub fn pub_key(&self) -> Word {
+        self.pub_key
+    }
+
+    /// Returns the message which has to be signed.
+    pub fn message(&self) -> Word {
+        self.message
+    }
+
+    /// Returns the changes made to the account up to the point of the signature request.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the ID of the account against which the transaction is executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_delta.id()
+    }
+
+    /// Returns the reference block number of the transaction.
+    pub fn block_ref(&self) -> BlockNumber {
+        self.block_ref
+    }
+
+    /// Returns the notes consumed by the transaction.
+    pub fn input_notes(&self) -> &InputNotes<InputNote> {
+        &self.notes
+    }
+
+    /// Returns the arguments of the transaction.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes the request and returns the data required to resume the transaction, with the
+    /// provided signatures over the message of this request added to the signatures of the
+    /// previous requests.
+    ///
+    /// The signatures are expected to be provided as pairs of the public key and the signature
+    /// created with the corresponding secret key.
+    pub(crate) fn into_resume_parts(
+        self,
+        signatures: impl IntoIterator<Item = (Word, Vec<Felt>)>,
+    ) -> (BlockNumber, InputNotes<InputNote>, TransactionArgs, BTreeMap<Digest, Vec<Felt>>) {
+        let Self {
+            message,
+            block_ref,
+            notes,
+            tx_args,
+            signatures: mut all_signatures,
+            ..
+        } = self;
+
+        for (pub_key, signature) in signatures {
+            let signature_key = Hasher::merge(&[pub_key.into(), message.into()]);
+            all_signatures.insert(signature_key, signature);
+        }
+
+        (block_ref, notes, tx_args, all_signatures)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for SigningRequest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.pub_key.write_into(target);
+        self.message.write_into(target);
+        self.account_delta.write_into(target);
+        self.block_ref.write_into(target);
+        self.notes.write_into(target);
+        self.tx_args.write_into(target);
+        self.signatures.write_into(target);
+    }
+}
+
+impl Deserializable for SigningRequest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let pub_key = Word::read_from(source)?;
+        let message = Word::read_from(source)?;
+        let account_delta = AccountDelta::read_from(source)?;
+        let block_ref = BlockNumber::read_from(source)?;
+        let notes = InputNotes::<InputNote>::read_from(source)?;
+        let tx_args = TransactionArgs::read_from(source)?;
+        let signatures = BTreeMap::<Digest, Vec<Felt>>::read_from(source)?;
+
+        Ok(Self::new(
+            pub_key,
+            message,
+            account_delta,
+            block_ref,
+            notes,
+            tx_args,
+            signatures,
+        ))
+    }
+}
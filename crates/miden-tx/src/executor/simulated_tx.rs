@@ -0,0 +1,100 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    Word,
+    account::{AccountDelta, AccountId},
+    asset::FungibleAsset,
+    transaction::{ExecutedTransaction, OutputNotes, TransactionMeasurements},
+};
+
+// SIMULATED TRANSACTION
+// ================================================================================================
+
+/// Describes the result of executing a transaction in simulation mode.
+///
+/// A simulated transaction is produced by
+/// [`TransactionExecutor::simulate_transaction()`](super::TransactionExecutor::simulate_transaction)
+/// without generating any signatures. Every signature request of the transaction is answered with a
+/// placeholder signature, and the transaction is executed to completion against account code in
+/// which the verification of signatures is skipped. Consequently, the account delta, the output
+/// notes, the fee and the cycle counts describe the full transaction, except that the cycles of
+/// the skipped signature verifications are not included in the measurements.
+///
+/// The code of a new account cannot be replaced, since the account ID commits to it. Hence, a
+/// simulated transaction can only be proven if the account code does not verify signatures (see
+/// [`SimulatedTransaction::is_provable`]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedTransaction {
+    executed_tx: ExecutedTransaction,
+    simulated_signatures: Vec<(Word, Word)>,
+    is_provable: bool,
+}
+
+impl SimulatedTransaction {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [SimulatedTransaction] instantiated from the provided data.
+    pub(crate) fn new(
+        executed_tx: ExecutedTransaction,
+        simulated_signatures: Vec<(Word, Word)>,
+        is_provable: bool,
+    ) -> Self {
+        Self {
+            executed_tx,
+            simulated_signatures,
+            is_provable,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account against which this transaction was simulated.
+    pub fn account_id(&self) -> AccountId {
+        self.executed_tx.account_id()
+    }
+
+    /// Returns a description of the changes the transaction would make to the account.
+    pub fn account_delta(&self) -> &AccountDelta {
+        self.executed_tx.account_delta()
+    }
+
+    /// Returns the notes the transaction would create.
+    pub fn output_notes(&self) -> &OutputNotes {
+        self.executed_tx.output_notes()
+    }
+
+    /// Returns the fee the transaction would pay.
+    pub fn fee(&self) -> FungibleAsset {
+        self.executed_tx.fee()
+    }
+
+    /// Returns the cycle counts measured while simulating the transaction.
+    ///
+    /// The measurements do not include the cycles of the skipped signature verifications.
+    pub fn measurements(&self) -> &TransactionMeasurements {
+        self.executed_tx.measurements()
+    }
+
+    /// Returns the public keys and messages of the signature requests which were answered with a
+    /// placeholder signature, in the order in which they were requested.
+    pub fn simulated_signatures(&self) -> &[(Word, Word)] {
+        &self.simulated_signatures
+    }
+
+    /// Returns `true` if the transaction can be proven, i.e., if the verification of signatures
+    /// was not skipped during the simulation.
+    pub fn is_provable(&self) -> bool {
+        self.is_provable
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [ExecutedTransaction] of this simulation if it can be proven, or `None`
+    /// otherwise.
+    pub fn into_executed_transaction(self) -> Option<ExecutedTransaction> {
+        self.is_provable.then_some(self.executed_tx)
+    }
+}
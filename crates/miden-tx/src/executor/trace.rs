@@ -0,0 +1,306 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use miden_objects::Digest;
+use vm_processor::RowIndex;
+
+use crate::host::TransactionProgress;
+
+// TRACE EXPORTER
+// ================================================================================================
+
+/// Receives the [TransactionTrace] of every transaction executed by a
+/// [`TransactionExecutor`](super::TransactionExecutor).
+///
+/// An exporter is registered via
+/// [`TransactionExecutor::with_trace_exporter()`](super::TransactionExecutor::with_trace_exporter)
+/// and is invoked once the transaction program has been executed successfully. The exporter may,
+/// for example, write the trace to a file in one of the formats supported by [TransactionTrace].
+pub trait TraceExporter: Send + Sync {
+    /// Exports the trace of an executed transaction.
+    fn export(&self, trace: &TransactionTrace);
+}
+
+impl<F> TraceExporter for F
+where
+    F: Fn(&TransactionTrace) + Send + Sync,
+{
+    fn export(&self, trace: &TransactionTrace) {
+        self(trace)
+    }
+}
+
+// TRANSACTION TRACE
+// ================================================================================================
+
+/// The timeline of an executed transaction, represented as a tree of spans with cycle
+/// timestamps.
+///
+/// A trace is built from the [`TransactionProgress`] recorded during execution and contains the
+/// following spans:
+/// - A root `transaction` span covering the entire execution.
+/// - A span for each stage of transaction execution, i.e., prologue, notes processing, transaction
+///   script processing and epilogue.
+/// - A span for the execution of each input note, nested in the notes processing span.
+/// - A span for each foreign procedure invocation, nested in the innermost span which contains it
+///   (which may itself be a foreign account span).
+/// - A zero-length span for each signature request, nested in the innermost span which contains it.
+///
+/// The trace can be rendered as JSON lines via [`TransactionTrace::to_json_lines`], or in the
+/// Chrome trace-event format via [`TransactionTrace::to_chrome_trace`], which can be loaded into
+/// standard trace viewers such as Perfetto or `chrome://tracing`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionTrace {
+    spans: Vec<TraceSpan>,
+}
+
+impl TransactionTrace {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionTrace] built from the progress recorded while executing a
+    /// transaction which took `num_cycles` cycles.
+    ///
+    /// Intervals which were not started are omitted, while intervals which were started but not
+    /// finished are assumed to extend until the end of execution.
+    pub(crate) fn new(num_cycles: usize, tx_progress: &TransactionProgress) -> Self {
+        let mut trace = Self::default();
+        trace.push_span(TraceSpanKind::Transaction, "transaction".to_string(), 0, num_cycles);
+
+        let stages = [
+            ("prologue", tx_progress.prologue()),
+            ("notes_processing", tx_progress.notes_processing()),
+            ("tx_script_processing", tx_progress.tx_script_processing()),
+            ("epilogue", tx_progress.epilogue()),
+        ];
+        for (name, interval) in stages {
+            trace.push_interval(
+                TraceSpanKind::Stage,
+                name.to_string(),
+                (interval.start(), interval.end()),
+                num_cycles,
+            );
+        }
+
+        for (note_id, interval) in tx_progress.note_execution() {
+            trace.push_interval(
+                TraceSpanKind::Note,
+                note_id.to_hex(),
+                (interval.start(), interval.end()),
+                num_cycles,
+            );
+        }
+
+        // foreign contexts are recorded in the order in which they were started, so the outer
+        // contexts are added before the nested ones
+        for (account_id, interval) in tx_progress.foreign_account_execution() {
+            trace.push_interval(
+                TraceSpanKind::ForeignAccount,
+                account_id.to_hex(),
+                (interval.start(), interval.end()),
+                num_cycles,
+            );
+        }
+
+        for (cycle, pub_key) in tx_progress.signature_requests() {
+            let cycle = cycle.as_usize();
+            trace.push_span(
+                TraceSpanKind::SignatureRequest,
+                Digest::from(*pub_key).to_hex(),
+                cycle,
+                cycle,
+            );
+        }
+
+        trace
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the spans of this trace.
+    ///
+    /// The first span is the root `transaction` span, and every other span is preceded by its
+    /// parent.
+    pub fn spans(&self) -> &[TraceSpan] {
+        &self.spans
+    }
+
+    /// Renders the trace as JSON lines, with one JSON object per span.
+    ///
+    /// Each object contains the `id` of the span (its index in [`Self::spans`]), the `id` of its
+    /// `parent` (or `null` for the root span), as well as its `depth`, `kind`, `name`, `start`
+    /// cycle and `end` cycle.
+    pub fn to_json_lines(&self) -> String {
+        let mut output = String::new();
+        for (id, span) in self.spans.iter().enumerate() {
+            let parent = match span.parent {
+                Some(parent) => parent.to_string(),
+                None => "null".to_string(),
+            };
+            writeln!(
+                output,
+                r#"{{"id":{id},"parent":{parent},"depth":{},"kind":"{}","name":"{}","start":{},"end":{}}}"#,
+                span.depth,
+                span.kind.as_str(),
+                span.name,
+                span.start,
+                span.end,
+            )
+            .expect("writing to a string should not fail");
+        }
+        output
+    }
+
+    /// Renders the trace in the Chrome trace-event format.
+    ///
+    /// Spans are rendered as complete events and signature requests as instant events on a single
+    /// thread, so that trace viewers nest them by their timestamps. Since trace viewers interpret
+    /// timestamps as microseconds, every cycle is rendered as one microsecond.
+    pub fn to_chrome_trace(&self) -> String {
+        let events: Vec<String> = self
+            .spans
+            .iter()
+            .map(|span| {
+                let common = format!(
+                    r#""name":"{}","cat":"{}","ts":{},"pid":1,"tid":1"#,
+                    span.name,
+                    span.kind.as_str(),
+                    span.start
+                );
+                match span.kind {
+                    TraceSpanKind::SignatureRequest => format!(r#"{{{common},"ph":"i","s":"t"}}"#),
+                    _ => format!(r#"{{{common},"ph":"X","dur":{}}}"#, span.len()),
+                }
+            })
+            .collect();
+
+        format!(r#"{{"traceEvents":[{}]}}"#, events.join(","))
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds a span covering the interval with the provided bounds, if the interval was started.
+    fn push_interval(
+        &mut self,
+        kind: TraceSpanKind,
+        name: String,
+        (start, end): (Option<RowIndex>, Option<RowIndex>),
+        num_cycles: usize,
+    ) {
+        if let Some(start) = start {
+            let end = end.map(|end| end.as_usize()).unwrap_or(num_cycles);
+            self.push_span(kind, name, start.as_usize(), end);
+        }
+    }
+
+    /// Adds a span with the provided bounds as a child of the innermost span which contains it.
+    ///
+    /// If several spans with the same bounds contain the new span, the one added last is chosen as
+    /// the parent.
+    fn push_span(&mut self, kind: TraceSpanKind, name: String, start: usize, end: usize) {
+        let parent = self
+            .spans
+            .iter()
+            .enumerate()
+            .filter(|(_, span)| span.start <= start && end <= span.end)
+            .min_by_key(|(idx, span)| (span.len(), usize::MAX - idx))
+            .map(|(idx, _)| idx);
+        let depth = parent.map(|parent| self.spans[parent].depth + 1).unwrap_or_default();
+
+        self.spans.push(TraceSpan { kind, name, start, end, parent, depth });
+    }
+}
+
+// TRACE SPAN
+// ================================================================================================
+
+/// A span of a [TransactionTrace], covering the cycles from `start` (inclusive) to `end`
+/// (exclusive).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceSpan {
+    kind: TraceSpanKind,
+    name: String,
+    start: usize,
+    end: usize,
+    parent: Option<usize>,
+    depth: usize,
+}
+
+impl TraceSpan {
+    /// Returns the kind of this span.
+    pub fn kind(&self) -> TraceSpanKind {
+        self.kind
+    }
+
+    /// Returns the name of this span.
+    ///
+    /// Stages are named after the stage, while the spans of notes, foreign accounts and signature
+    /// requests are named after the note ID, the account ID and the public key, respectively.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the cycle at which this span starts.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the cycle at which this span ends.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the number of cycles covered by this span.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns true if this span covers no cycles.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the index of the parent of this span in [`TransactionTrace::spans`], or `None` for
+    /// the root span.
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+
+    /// Returns the number of ancestors of this span.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+/// The kind of a [TraceSpan].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceSpanKind {
+    /// The execution of the entire transaction.
+    Transaction,
+    /// A stage of transaction execution.
+    Stage,
+    /// The execution of an input note.
+    Note,
+    /// The execution of a foreign account procedure.
+    ForeignAccount,
+    /// A signature request to the host.
+    SignatureRequest,
+}
+
+impl TraceSpanKind {
+    /// Returns the name of this kind as rendered in the exported traces.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TraceSpanKind::Transaction => "transaction",
+            TraceSpanKind::Stage => "stage",
+            TraceSpanKind::Note => "note",
+            TraceSpanKind::ForeignAccount => "foreign_account",
+            TraceSpanKind::SignatureRequest => "signature_request",
+        }
+    }
+}
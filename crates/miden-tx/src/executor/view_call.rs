@@ -0,0 +1,314 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::ops::Range;
+
+use miden_objects::{
+    Digest, Felt, Word,
+    account::AccountId,
+    transaction::{AccountInputs, TransactionScript},
+    vm::AdviceInputs,
+};
+
+use crate::errors::ViewCallDecodeError;
+
+// VIEW CALL
+// ================================================================================================
+
+/// A script executed against an account without producing a transaction, together with a
+/// description of the data which should be returned from the execution.
+///
+/// A view call is executed via
+/// [`TransactionExecutor::execute_view_call()`](super::TransactionExecutor::execute_view_call).
+/// In addition to the operand stack at the end of execution, which is always returned, the
+/// following data can be requested:
+/// - Entries of the advice map, which allows the script to return data of arbitrary length via the
+///   `adv.insert_mem` instruction.
+/// - Ranges of the memory of the transaction kernel (i.e., the root context), which contains the
+///   data of the native account and of the foreign accounts loaded during execution. The layout of
+///   the kernel memory is described in [`miden_lib::transaction::memory`].
+#[derive(Debug, Clone)]
+pub struct ViewCall {
+    tx_script: TransactionScript,
+    advice_inputs: AdviceInputs,
+    foreign_account_inputs: Vec<AccountInputs>,
+    advice_map_keys: Vec<Digest>,
+    memory_ranges: Vec<Range<u32>>,
+}
+
+impl ViewCall {
+    /// Returns a new [ViewCall] executing the provided script.
+    pub fn new(tx_script: TransactionScript) -> Self {
+        Self {
+            tx_script,
+            advice_inputs: AdviceInputs::default(),
+            foreign_account_inputs: Vec::new(),
+            advice_map_keys: Vec::new(),
+            memory_ranges: Vec::new(),
+        }
+    }
+
+    /// Sets the advice inputs provided to the script.
+    pub fn with_advice_inputs(mut self, advice_inputs: AdviceInputs) -> Self {
+        self.advice_inputs = advice_inputs;
+        self
+    }
+
+    /// Sets the inputs of the foreign accounts whose procedures are invoked by the script.
+    pub fn with_foreign_account_inputs(
+        mut self,
+        foreign_account_inputs: Vec<AccountInputs>,
+    ) -> Self {
+        self.foreign_account_inputs = foreign_account_inputs;
+        self
+    }
+
+    /// Requests the advice map entry with the provided key to be returned.
+    pub fn with_advice_map_entry(mut self, key: Digest) -> Self {
+        self.advice_map_keys.push(key);
+        self
+    }
+
+    /// Requests the elements at the provided range of addresses of the kernel memory to be
+    /// returned.
+    pub fn with_memory_range(mut self, range: Range<u32>) -> Self {
+        self.memory_ranges.push(range);
+        self
+    }
+
+    /// Consumes self and returns the script, the advice inputs, the foreign account inputs, the
+    /// requested advice map keys and the requested memory ranges.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn into_parts(
+        self,
+    ) -> (
+        TransactionScript,
+        AdviceInputs,
+        Vec<AccountInputs>,
+        Vec<Digest>,
+        Vec<Range<u32>>,
+    ) {
+        (
+            self.tx_script,
+            self.advice_inputs,
+            self.foreign_account_inputs,
+            self.advice_map_keys,
+            self.memory_ranges,
+        )
+    }
+}
+
+// VIEW CALL OUTPUT
+// ================================================================================================
+
+/// The data returned from the execution of a [ViewCall].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewCallOutput {
+    stack: [Felt; 16],
+    advice_map_entries: BTreeMap<Digest, Vec<Felt>>,
+    memory_ranges: Vec<(Range<u32>, Vec<Felt>)>,
+}
+
+impl ViewCallOutput {
+    /// Returns a new [ViewCallOutput] instantiated from the provided data.
+    pub(crate) fn new(
+        stack: [Felt; 16],
+        advice_map_entries: BTreeMap<Digest, Vec<Felt>>,
+        memory_ranges: Vec<(Range<u32>, Vec<Felt>)>,
+    ) -> Self {
+        Self { stack, advice_map_entries, memory_ranges }
+    }
+
+    /// Returns the operand stack at the end of execution, starting with the top of the stack.
+    pub fn stack(&self) -> &[Felt; 16] {
+        &self.stack
+    }
+
+    /// Returns the requested advice map entry with the provided key, or `None` if the advice map
+    /// did not contain the entry at the end of execution or the entry was not requested.
+    pub fn advice_map_entry(&self, key: &Digest) -> Option<&[Felt]> {
+        self.advice_map_entries.get(key).map(Vec::as_slice)
+    }
+
+    /// Returns the elements at the requested range of addresses of the kernel memory, or `None`
+    /// if the range was not requested.
+    ///
+    /// Addresses which were never written to contain zeros.
+    pub fn memory_range(&self, range: &Range<u32>) -> Option<&[Felt]> {
+        self.memory_ranges
+            .iter()
+            .find(|(requested_range, _)| requested_range == range)
+            .map(|(_, elements)| elements.as_slice())
+    }
+
+    /// Decodes values of the provided types from the operand stack, starting at the top of the
+    /// stack.
+    ///
+    /// Values occupy consecutive stack elements in the order in which they are pushed by MASM
+    /// code, e.g., a word pushed via `push.1.2.3.4` or `mem_loadw` is decoded as `[1, 2, 3, 4]`,
+    /// and an account ID is decoded from `[account_id_prefix, account_id_suffix, ...]`.
+    ///
+    /// # Errors
+    /// Returns an error if the stack does not contain enough elements or a value is invalid.
+    pub fn decode_stack(
+        &self,
+        types: &[ViewValueType],
+    ) -> Result<Vec<ViewValue>, ViewCallDecodeError> {
+        decode_values(&self.stack, types, ElementOrder::Stack)
+    }
+
+    /// Decodes values of the provided types from the requested advice map entry with the provided
+    /// key.
+    ///
+    /// Values occupy consecutive elements in the order in which they are laid out in memory, e.g.,
+    /// an entry inserted via `adv.insert_mem` is decoded in the same way as the memory range it was
+    /// created from (see [ViewCallOutput::decode_memory_range]).
+    ///
+    /// # Errors
+    /// Returns an error if the entry was not returned, does not contain enough elements or a value
+    /// is invalid.
+    pub fn decode_advice_map_entry(
+        &self,
+        key: &Digest,
+        types: &[ViewValueType],
+    ) -> Result<Vec<ViewValue>, ViewCallDecodeError> {
+        let elements = self
+            .advice_map_entry(key)
+            .ok_or(ViewCallDecodeError::AdviceMapEntryNotFound(*key))?;
+        decode_values(elements, types, ElementOrder::Memory)
+    }
+
+    /// Decodes values of the provided types from the requested range of the kernel memory.
+    ///
+    /// Values occupy consecutive elements in the order in which they are laid out in memory, e.g.,
+    /// a word is decoded from the four elements starting at its address, and an account ID is
+    /// decoded from `[account_id_suffix, account_id_prefix, ...]`.
+    ///
+    /// # Errors
+    /// Returns an error if the range was not requested, does not contain enough elements or a
+    /// value is invalid.
+    pub fn decode_memory_range(
+        &self,
+        range: &Range<u32>,
+        types: &[ViewValueType],
+    ) -> Result<Vec<ViewValue>, ViewCallDecodeError> {
+        let elements = self
+            .memory_range(range)
+            .ok_or_else(|| ViewCallDecodeError::MemoryRangeNotFound(range.clone()))?;
+        decode_values(elements, types, ElementOrder::Memory)
+    }
+}
+
+// VIEW VALUE TYPE
+// ================================================================================================
+
+/// The type of a value returned from a [ViewCall].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewValueType {
+    /// A single field element.
+    Felt,
+    /// A single field element interpreted as an integer, e.g., a nonce or an asset amount.
+    U64,
+    /// Four field elements forming a word.
+    Word,
+    /// Two field elements forming an account ID.
+    AccountId,
+    /// The specified number of consecutive words.
+    Words(usize),
+}
+
+impl ViewValueType {
+    /// Returns the number of field elements occupied by a value of this type.
+    pub fn num_elements(&self) -> usize {
+        match self {
+            Self::Felt | Self::U64 => 1,
+            Self::Word => 4,
+            Self::AccountId => 2,
+            Self::Words(num_words) => num_words * 4,
+        }
+    }
+
+    /// Decodes a value of this type from the provided elements, which must contain exactly
+    /// [ViewValueType::num_elements] elements.
+    fn decode(
+        &self,
+        elements: &[Felt],
+        order: ElementOrder,
+    ) -> Result<ViewValue, ViewCallDecodeError> {
+        let value = match self {
+            Self::Felt => ViewValue::Felt(elements[0]),
+            Self::U64 => ViewValue::U64(elements[0].as_int()),
+            Self::Word => ViewValue::Word(decode_word(elements, order)),
+            Self::AccountId => {
+                let (prefix, suffix) = match order {
+                    ElementOrder::Stack => (elements[0], elements[1]),
+                    ElementOrder::Memory => (elements[1], elements[0]),
+                };
+                let account_id = AccountId::try_from([prefix, suffix])
+                    .map_err(ViewCallDecodeError::InvalidAccountId)?;
+                ViewValue::AccountId(account_id)
+            },
+            Self::Words(_) => ViewValue::Words(
+                elements.chunks_exact(4).map(|word| decode_word(word, order)).collect(),
+            ),
+        };
+
+        Ok(value)
+    }
+}
+
+// VIEW VALUE
+// ================================================================================================
+
+/// A value decoded from the output of a [ViewCall] according to a [ViewValueType].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViewValue {
+    Felt(Felt),
+    U64(u64),
+    Word(Word),
+    AccountId(AccountId),
+    Words(Vec<Word>),
+}
+
+// HELPERS
+// ================================================================================================
+
+/// The order of the elements from which values are decoded.
+#[derive(Debug, Clone, Copy)]
+enum ElementOrder {
+    /// The elements are read from the operand stack, where the elements of each value are
+    /// reversed relative to their order in memory.
+    Stack,
+    /// The elements are laid out in memory order.
+    Memory,
+}
+
+/// Decodes values of the provided types from consecutive elements.
+fn decode_values(
+    mut elements: &[Felt],
+    types: &[ViewValueType],
+    order: ElementOrder,
+) -> Result<Vec<ViewValue>, ViewCallDecodeError> {
+    types
+        .iter()
+        .map(|value_type| {
+            let num_elements = value_type.num_elements();
+            if elements.len() < num_elements {
+                return Err(ViewCallDecodeError::NotEnoughElements {
+                    expected: num_elements,
+                    remaining: elements.len(),
+                });
+            }
+
+            let (value_elements, remaining) = elements.split_at(num_elements);
+            elements = remaining;
+            value_type.decode(value_elements, order)
+        })
+        .collect()
+}
+
+/// Decodes a word from four elements.
+fn decode_word(elements: &[Felt], order: ElementOrder) -> Word {
+    match order {
+        ElementOrder::Stack => [elements[3], elements[2], elements[1], elements[0]],
+        ElementOrder::Memory => [elements[0], elements[1], elements[2], elements[3]],
+    }
+}
@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    Digest,
+    account::{AccountId, PartialAccount},
+    block::BlockHeader,
+    crypto::merkle::SmtProof,
+    transaction::{AccountInputs, TransactionInputs},
+};
+
+use crate::{
+    DataStore, errors::TransactionExecutorError, executor::validate_foreign_account_inputs,
+};
+
+// ACCOUNT DATA LOADER
+// ================================================================================================
+
+/// Loads the account data which is accessed by a transaction but missing from its inputs from a
+/// [DataStore] while the transaction is executed.
+///
+/// The loaded data is added to a copy of the native account and to the list of foreign accounts
+/// maintained by the loader, so that it can be made part of the transaction inputs once execution
+/// has finished (see [AccountDataLoader::into_parts]).
+pub struct AccountDataLoader<'store> {
+    data_store: &'store dyn DataStore,
+    account: PartialAccount,
+    ref_block: BlockHeader,
+    foreign_account_inputs: Vec<AccountInputs>,
+    error: Option<TransactionExecutorError>,
+}
+
+impl<'store> AccountDataLoader<'store> {
+    /// Returns a new [AccountDataLoader] which loads missing data of the transaction with the
+    /// provided inputs from the provided [DataStore].
+    pub fn new(data_store: &'store dyn DataStore, tx_inputs: &TransactionInputs) -> Self {
+        Self {
+            data_store,
+            account: tx_inputs.account().clone(),
+            ref_block: tx_inputs.block_header().clone(),
+            foreign_account_inputs: Vec::new(),
+            error: None,
+        }
+    }
+
+    // LOADERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Loads the opening of the entry with the specified key of the storage map in the specified
+    /// slot of the native account, and returns it.
+    ///
+    /// The opening is fetched against the initial state of the account, i.e., the root of the
+    /// storage map at the beginning of the transaction.
+    pub fn load_storage_map_entry(
+        &mut self,
+        slot_index: u8,
+        key: Digest,
+    ) -> Result<SmtProof, TransactionExecutorError> {
+        let (_, map_root) = self
+            .account
+            .storage()
+            .header()
+            .slot(slot_index as usize)
+            .map_err(TransactionExecutorError::InvalidStorageMapWitness)?;
+        let witness = self
+            .data_store
+            .get_storage_map_witness(self.account.id(), (*map_root).into(), key)
+            .map_err(TransactionExecutorError::FetchAccountDataFailed)?;
+
+        self.account
+            .storage_mut()
+            .add_storage_map_proof(key, witness.clone())
+            .map_err(TransactionExecutorError::InvalidStorageMapWitness)?;
+
+        Ok(witness)
+    }
+
+    /// Loads the opening of the asset with the specified vault key of the native account vault,
+    /// and returns it.
+    ///
+    /// The opening is fetched against the initial state of the account, i.e., the root of the
+    /// account vault at the beginning of the transaction.
+    pub fn load_vault_asset(
+        &mut self,
+        vault_key: Digest,
+    ) -> Result<SmtProof, TransactionExecutorError> {
+        let witness = self
+            .data_store
+            .get_vault_asset_witness(self.account.id(), self.account.vault().root(), vault_key)
+            .map_err(TransactionExecutorError::FetchAccountDataFailed)?;
+
+        self.account
+            .vault_mut()
+            .add_proof(witness.clone())
+            .map_err(TransactionExecutorError::InvalidVaultWitness)?;
+
+        Ok(witness)
+    }
+
+    /// Loads the inputs of the foreign account with the specified ID at the reference block of the
+    /// transaction, and returns them.
+    pub fn load_foreign_account(
+        &mut self,
+        account_id: AccountId,
+    ) -> Result<&AccountInputs, TransactionExecutorError> {
+        let account_inputs = self
+            .data_store
+            .get_foreign_account_inputs(account_id, self.ref_block.block_num())
+            .map_err(TransactionExecutorError::FetchAccountDataFailed)?;
+        validate_foreign_account_inputs(&account_inputs, &self.ref_block)?;
+
+        self.foreign_account_inputs.push(account_inputs);
+        Ok(self
+            .foreign_account_inputs
+            .last()
+            .expect("foreign account inputs were just added"))
+    }
+
+    // ERROR HANDLING
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the provided error, which caused the execution of the transaction to be halted.
+    pub fn set_error(&mut self, error: TransactionExecutorError) {
+        self.error = Some(error);
+    }
+
+    /// Returns the error at which loading account data failed, if any, leaving `None` in its
+    /// place.
+    pub fn take_error(&mut self) -> Option<TransactionExecutorError> {
+        self.error.take()
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes the loader and returns the native account extended with all loaded storage map
+    /// entries and vault assets, as well as the inputs of all loaded foreign accounts.
+    pub fn into_parts(self) -> (PartialAccount, Vec<AccountInputs>) {
+        (self.account, self.foreign_account_inputs)
+    }
+}
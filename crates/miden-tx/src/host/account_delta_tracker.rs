@@ -1,6 +1,6 @@
 use miden_objects::{
-    Felt, ZERO,
-    account::{AccountDelta, AccountId, AccountStorageHeader, AccountVaultDelta},
+    Digest, Felt, ZERO,
+    account::{AccountCode, AccountDelta, AccountId, AccountStorageHeader, AccountVaultDelta},
 };
 
 use crate::host::storage_delta_tracker::StorageDeltaTracker;
@@ -14,25 +14,31 @@ use crate::host::storage_delta_tracker::StorageDeltaTracker;
 /// - Changes to the account storage, slots and maps.
 /// - Changes to the account vault.
 /// - Changes to the account nonce.
-///
-/// TODO: implement tracking of:
-/// - account code changes.
+/// - Changes to the account code.
 #[derive(Debug, Clone)]
 pub struct AccountDeltaTracker {
     account_id: AccountId,
     storage: StorageDeltaTracker,
     vault: AccountVaultDelta,
     nonce_delta: Felt,
+    initial_code_commitment: Digest,
+    code: Option<AccountCode>,
 }
 
 impl AccountDeltaTracker {
     /// Returns a new [AccountDeltaTracker] instantiated for the specified account.
-    pub fn new(account_id: AccountId, storage_header: AccountStorageHeader) -> Self {
+    pub fn new(
+        account_id: AccountId,
+        storage_header: AccountStorageHeader,
+        initial_code_commitment: Digest,
+    ) -> Self {
         Self {
             account_id,
             storage: StorageDeltaTracker::new(storage_header),
             vault: AccountVaultDelta::default(),
             nonce_delta: ZERO,
+            initial_code_commitment,
+            code: None,
         }
     }
 
@@ -46,6 +52,17 @@ impl AccountDeltaTracker {
         &mut self.vault
     }
 
+    /// Returns the code commitment of the account at the beginning of the transaction.
+    pub fn initial_code_commitment(&self) -> Digest {
+        self.initial_code_commitment
+    }
+
+    /// Tracks the new code of the account, or a reset of the code to the initial one if `None`
+    /// is provided.
+    pub fn set_code(&mut self, code: Option<AccountCode>) {
+        self.code = code;
+    }
+
     /// Returns a mutable reference to the current storage delta tracker.
     pub fn storage(&mut self) -> &mut StorageDeltaTracker {
         &mut self.storage
@@ -62,7 +79,14 @@ impl AccountDeltaTracker {
         let storage_delta = self.storage.into_delta();
         let vault_delta = self.vault;
 
-        AccountDelta::new(account_id, storage_delta, vault_delta, nonce_delta)
-            .expect("account delta created in delta tracker should be valid")
+        let delta = AccountDelta::new(account_id, storage_delta, vault_delta, nonce_delta)
+            .expect("account delta created in delta tracker should be valid");
+
+        match self.code {
+            Some(code) => delta
+                .with_code(code)
+                .expect("account code change tracked in delta tracker should be valid"),
+            None => delta,
+        }
     }
 }
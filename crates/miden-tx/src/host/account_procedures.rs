@@ -37,6 +37,22 @@ impl AccountProcedureIndexMap {
         Ok(Self(result))
     }
 
+    /// Adds the procedures of the account code with the specified commitment, which are read from
+    /// the provided advice provider, to this map.
+    ///
+    /// This is used to add the procedures of foreign accounts which are loaded during transaction
+    /// execution.
+    pub fn insert_code(
+        &mut self,
+        code_commitment: Digest,
+        adv_provider: &impl AdviceProvider,
+    ) -> Result<(), TransactionHostError> {
+        let account_procs_map = build_account_procedure_map(code_commitment, adv_provider)?;
+        self.0.insert(code_commitment, account_procs_map);
+
+        Ok(())
+    }
+
     /// Returns index of the procedure whose root is currently at the top of the operand stack in
     /// the provided process.
     ///
@@ -0,0 +1,214 @@
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
+use core::{fmt, ops::RangeInclusive};
+
+use miden_lib::transaction::TransactionEvent;
+use miden_objects::{Digest, assembly::mast::MastNodeExt};
+use vm_processor::{
+    AdviceProvider, AdviceSource, ErrorContext, ExecutionError, Felt, ProcessState, Word,
+};
+
+use crate::errors::EventHandlerRegistryError;
+
+/// The error returned by an [EventHandler] when it fails to handle an event.
+pub type EventError = Box<dyn core::error::Error + Send + Sync + 'static>;
+
+// EVENT HANDLER
+// ================================================================================================
+
+/// Handles events emitted by user code (e.g., account components or note scripts) via the `emit`
+/// instruction.
+///
+/// Event handlers are registered in an [EventHandlerRegistry] for a range of event IDs and are
+/// invoked by the [`TransactionHost`](super::TransactionHost) whenever an event with an ID in that
+/// range is emitted, regardless of the context in which the event was emitted. This allows user
+/// code to call into host-side logic, e.g., to look up data from an oracle or to log values.
+///
+/// The same handlers should be used when executing and when proving a transaction. Since the
+/// prover re-executes the transaction, a handler must behave deterministically: any data it
+/// provides via the advice provider during execution must be provided again during proving.
+pub trait EventHandler: Send + Sync {
+    /// Handles the event with the specified ID.
+    ///
+    /// The handler has read access to the state of the VM via `process`, and can provide
+    /// non-deterministic inputs to the program via `advice`.
+    ///
+    /// # Errors
+    /// Returning an error halts execution of the transaction.
+    fn on_event(
+        &self,
+        event_id: u32,
+        process: ProcessState,
+        advice: &mut dyn EventAdvice,
+    ) -> Result<(), EventError>;
+}
+
+// EVENT ADVICE
+// ================================================================================================
+
+/// Provides access to the advice provider of the [`TransactionHost`](super::TransactionHost) to
+/// [EventHandler]s.
+pub trait EventAdvice {
+    /// Pushes the provided value onto the advice stack.
+    fn push_stack(&mut self, value: Felt);
+
+    /// Pushes the provided word onto the advice stack, such that the first element of the word
+    /// is at the top of the stack.
+    fn push_stack_word(&mut self, word: Word);
+
+    /// Returns the top `length` elements of the advice stack.
+    fn peek_stack(&self, length: usize) -> &[Felt];
+
+    /// Returns the values stored under the specified key in the advice map, if any.
+    fn get_mapped_values(&self, key: &Digest) -> Option<&[Felt]>;
+
+    /// Inserts the provided values into the advice map under the specified key.
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>);
+
+    /// Returns the node at the specified depth and index of the Merkle tree with the specified
+    /// root in the Merkle store of the advice provider.
+    fn get_tree_node(&self, root: Word, depth: Felt, index: Felt) -> Result<Word, ExecutionError>;
+}
+
+/// Exposes an [AdviceProvider] as [EventAdvice] in the error context of the event being handled.
+pub(super) struct EventAdviceProvider<'a, 'ctx, A, N: MastNodeExt> {
+    adv_provider: &'a mut A,
+    err_ctx: &'a ErrorContext<'ctx, N>,
+}
+
+impl<'a, 'ctx, A: AdviceProvider, N: MastNodeExt> EventAdviceProvider<'a, 'ctx, A, N> {
+    pub(super) fn new(adv_provider: &'a mut A, err_ctx: &'a ErrorContext<'ctx, N>) -> Self {
+        Self { adv_provider, err_ctx }
+    }
+}
+
+impl<A: AdviceProvider, N: MastNodeExt> EventAdvice for EventAdviceProvider<'_, '_, A, N> {
+    fn push_stack(&mut self, value: Felt) {
+        self.adv_provider
+            .push_stack(AdviceSource::Value(value), self.err_ctx)
+            .expect("failed to push value onto advice stack");
+    }
+
+    fn push_stack_word(&mut self, word: Word) {
+        self.adv_provider
+            .push_stack(AdviceSource::Word(word), self.err_ctx)
+            .expect("failed to push word onto advice stack");
+    }
+
+    fn peek_stack(&self, length: usize) -> &[Felt] {
+        self.adv_provider.peek_stack(length)
+    }
+
+    fn get_mapped_values(&self, key: &Digest) -> Option<&[Felt]> {
+        self.adv_provider.get_mapped_values(key)
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) {
+        self.adv_provider.insert_into_map(key, values);
+    }
+
+    fn get_tree_node(&self, root: Word, depth: Felt, index: Felt) -> Result<Word, ExecutionError> {
+        self.adv_provider.get_tree_node(root, &depth, &index, self.err_ctx)
+    }
+}
+
+// EVENT HANDLER REGISTRY
+// ================================================================================================
+
+/// A set of [EventHandler]s keyed by non-overlapping ranges of event IDs.
+///
+/// Event IDs with the [`TransactionEvent::ID_PREFIX`] in the upper 16 bits are reserved for the
+/// transaction kernel and cannot be handled by user-defined handlers.
+///
+/// Cloning a registry is cheap, as the handlers are shared between the clones.
+#[derive(Clone, Default)]
+pub struct EventHandlerRegistry {
+    /// Maps the first event ID of each range to the last event ID of the range and its handler.
+    handlers: BTreeMap<u32, (u32, Arc<dyn EventHandler>)>,
+}
+
+impl EventHandlerRegistry {
+    /// Returns a new empty [EventHandlerRegistry].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the provided handler for all event IDs in the specified range and returns the
+    /// updated registry.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The range is empty.
+    /// - The range contains event IDs reserved for the transaction kernel.
+    /// - The range overlaps with the range of a previously registered handler.
+    pub fn with_handler(
+        mut self,
+        event_ids: RangeInclusive<u32>,
+        handler: Arc<dyn EventHandler>,
+    ) -> Result<Self, EventHandlerRegistryError> {
+        self.register(event_ids, handler)?;
+        Ok(self)
+    }
+
+    /// Registers the provided handler for all event IDs in the specified range.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The range is empty.
+    /// - The range contains event IDs reserved for the transaction kernel.
+    /// - The range overlaps with the range of a previously registered handler.
+    pub fn register(
+        &mut self,
+        event_ids: RangeInclusive<u32>,
+        handler: Arc<dyn EventHandler>,
+    ) -> Result<(), EventHandlerRegistryError> {
+        let (start, end) = event_ids.into_inner();
+        if start > end {
+            return Err(EventHandlerRegistryError::EmptyRange { start, end });
+        }
+
+        let reserved_start = TransactionEvent::ID_PREFIX << 16;
+        let reserved_end = reserved_start | 0xffff;
+        if start <= reserved_end && reserved_start <= end {
+            return Err(EventHandlerRegistryError::ReservedEventIds { start, end });
+        }
+
+        // since the registered ranges do not overlap, only the range starting closest before the
+        // end of the new range can overlap with it
+        if let Some((&existing_start, &(existing_end, _))) = self.handlers.range(..=end).next_back()
+        {
+            if existing_end >= start {
+                return Err(EventHandlerRegistryError::OverlappingRange {
+                    start,
+                    end,
+                    existing_start,
+                    existing_end,
+                });
+            }
+        }
+
+        self.handlers.insert(start, (end, handler));
+        Ok(())
+    }
+
+    /// Returns the handler registered for the specified event ID, if any.
+    pub fn get(&self, event_id: u32) -> Option<&Arc<dyn EventHandler>> {
+        self.handlers
+            .range(..=event_id)
+            .next_back()
+            .filter(|(_, (end, _))| event_id <= *end)
+            .map(|(_, (_, handler))| handler)
+    }
+
+    /// Returns `true` if no handlers are registered.
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+}
+
+impl fmt::Debug for EventHandlerRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.handlers.iter().map(|(start, (end, _))| *start..=*end))
+            .finish()
+    }
+}
@@ -0,0 +1,141 @@
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use crate::errors::InterruptReason;
+
+// CANCELLATION TOKEN
+// ================================================================================================
+
+/// A token which can be used to cooperatively cancel the execution of transactions.
+///
+/// Cloned tokens share the same state, so a clone of the token can be passed to the
+/// [`TransactionExecutor`](crate::TransactionExecutor) while the original is kept to cancel the
+/// execution from another thread.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Returns a new [CancellationToken] which has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels all executions using this token or any of its clones.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// EXECUTION INTERRUPT
+// ================================================================================================
+
+/// Describes the conditions under which the execution of a transaction should be interrupted.
+///
+/// The conditions are checked cooperatively, i.e., only when the VM calls into the
+/// [`TransactionHost`](super::TransactionHost) to handle an event emitted by the transaction
+/// kernel. Since code which does not interact with the kernel (e.g., a tight loop in a note script)
+/// never calls into the host, and the VM provides no hook which is invoked at regular cycle
+/// intervals, the deadline is additionally enforced by the VM through a bound on the number of
+/// cycles of the execution (see [Self::max_cycles]).
+///
+/// This bound is derived from the number of cycles the VM is assumed to execute per second (see
+/// [Self::with_cycles_per_second]). Thus, code which never calls into the host is interrupted only
+/// approximately at the deadline: earlier if the VM executes fewer cycles per second than assumed,
+/// and later if it executes more.
+#[derive(Debug, Clone)]
+pub struct ExecutionInterrupt {
+    cancellation_token: Option<CancellationToken>,
+    #[cfg(feature = "std")]
+    deadline: Option<Instant>,
+    #[cfg(feature = "std")]
+    cycles_per_second: u32,
+}
+
+impl ExecutionInterrupt {
+    /// The number of cycles the VM is assumed to execute per second by default.
+    ///
+    /// This is a conservative estimate for release builds on current hardware.
+    pub const DEFAULT_CYCLES_PER_SECOND: u32 = 1 << 25;
+
+    /// Returns a new [ExecutionInterrupt] which never fires.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interrupts execution when the provided token is cancelled.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Interrupts execution when the provided deadline has passed.
+    #[cfg(feature = "std")]
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Sets the number of cycles the VM is assumed to execute per second, from which the bound on
+    /// the number of cycles until the deadline is derived (see [Self::max_cycles]).
+    ///
+    /// Defaults to [Self::DEFAULT_CYCLES_PER_SECOND]. The rate should be measured on the machine
+    /// executing the transactions, e.g., by dividing the number of cycles of an execution by its
+    /// duration.
+    #[cfg(feature = "std")]
+    pub fn with_cycles_per_second(mut self, cycles_per_second: u32) -> Self {
+        self.cycles_per_second = cycles_per_second;
+        self
+    }
+
+    /// Returns the reason for which execution should be interrupted, or `Ok(())` if execution
+    /// can continue.
+    pub fn check(&self) -> Result<(), InterruptReason> {
+        if self.cancellation_token.as_ref().is_some_and(CancellationToken::is_cancelled) {
+            return Err(InterruptReason::Cancelled);
+        }
+
+        #[cfg(feature = "std")]
+        if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return Err(InterruptReason::DeadlineExceeded);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the maximum number of cycles of an execution starting now, i.e., the provided
+    /// `max_cycles` bounded by the number of cycles the VM is assumed to execute until the
+    /// deadline of this interrupt, if any.
+    ///
+    /// Unlike the conditions checked by [Self::check], this bound is enforced by the VM itself, so
+    /// it also interrupts code which never calls into the host.
+    pub fn max_cycles(&self, max_cycles: u32) -> u32 {
+        #[cfg(feature = "std")]
+        if let Some(deadline) = self.deadline {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            // the conversion saturates at u32::MAX
+            let cycles = (remaining.as_secs_f64() * f64::from(self.cycles_per_second)) as u32;
+            return max_cycles.min(cycles);
+        }
+
+        max_cycles
+    }
+}
+
+impl Default for ExecutionInterrupt {
+    fn default() -> Self {
+        Self {
+            cancellation_token: None,
+            #[cfg(feature = "std")]
+            deadline: None,
+            #[cfg(feature = "std")]
+            cycles_per_second: Self::DEFAULT_CYCLES_PER_SECOND,
+        }
+    }
+}
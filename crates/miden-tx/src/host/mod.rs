@@ -1,26 +1,32 @@
-use alloc::{
-    boxed::Box,
-    collections::{BTreeMap, BTreeSet},
-    sync::Arc,
-    vec::Vec,
-};
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
 
+#[cfg(not(feature = "async"))]
+use miden_lib::transaction::TransactionAdviceInputs;
 use miden_lib::transaction::{
     TransactionEvent, TransactionEventError, TransactionKernelError,
-    memory::{CURRENT_INPUT_NOTE_PTR, NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR},
+    memory::{
+        ACCOUNT_STACK_TOP_PTR, ACCT_STORAGE_SLOT_NUM_ELEMENTS, CURRENT_INPUT_NOTE_PTR,
+        NATIVE_ACCOUNT_DATA_PTR, NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR, NATIVE_ACCT_VAULT_ROOT_PTR,
+        NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR,
+    },
 };
+#[cfg(not(feature = "async"))]
+use miden_objects::crypto::merkle::SmtProof;
 use miden_objects::{
-    Digest, Hasher,
-    account::{AccountDelta, PartialAccount},
+    Digest, EMPTY_WORD, Hasher, ONE, ZERO,
+    account::{AccountCode, AccountDelta, AccountId, PartialAccount, StorageMap},
     assembly::mast::MastNodeExt,
-    asset::Asset,
+    asset::{Asset, FungibleAsset},
+    crypto::merkle::{LeafIndex, SMT_DEPTH},
     note::NoteId,
-    transaction::{OutputNote, TransactionMeasurements},
+    transaction::{AccountInputs, OutputNote, TransactionArgs, TransactionMeasurements},
     vm::RowIndex,
 };
+#[cfg(not(feature = "async"))]
+use vm_processor::{AdviceInputs, MemAdviceProvider};
 use vm_processor::{
     AdviceProvider, AdviceSource, ContextId, ErrorContext, ExecutionError, Felt, Host, MastForest,
-    MastForestStore, MemoryError, ProcessState,
+    MastForestStore, MemoryError, ProcessState, Word,
 };
 
 mod account_delta_tracker;
@@ -34,6 +40,11 @@ pub use link_map::{Entry, EntryMetadata, LinkMap};
 mod account_procedures;
 pub use account_procedures::AccountProcedureIndexMap;
 
+#[cfg(not(feature = "async"))]
+mod account_data_loader;
+#[cfg(not(feature = "async"))]
+pub(crate) use account_data_loader::AccountDataLoader;
+
 mod note_builder;
 use note_builder::OutputNoteBuilder;
 
@@ -43,7 +54,20 @@ pub use script_mast_forest_store::ScriptMastForestStore;
 mod tx_progress;
 pub use tx_progress::TransactionProgress;
 
-use crate::{auth::TransactionAuthenticator, errors::TransactionHostError};
+mod interrupt;
+pub use interrupt::{CancellationToken, ExecutionInterrupt};
+
+mod signature_simulator;
+pub(crate) use signature_simulator::{SignatureSimulator, SimulatedAccountCode};
+
+mod event_handlers;
+use event_handlers::EventAdviceProvider;
+pub use event_handlers::{EventAdvice, EventError, EventHandler, EventHandlerRegistry};
+
+use crate::{
+    auth::TransactionAuthenticator,
+    errors::{InterruptReason, MissingAccountData, TransactionExecutorError, TransactionHostError},
+};
 
 // TRANSACTION HOST
 // ================================================================================================
@@ -89,7 +113,56 @@ pub struct TransactionHost<'store, 'auth, A> {
     /// signature using the transaction authenticator.
     generated_signatures: BTreeMap<Digest, Vec<Felt>>,
 
-    /// Tracks the number of cycles for each of the transaction execution stages.
+    /// Indicates whether signature requests which cannot be served from the advice provider should
+    /// be deferred instead of being forwarded to the transaction authenticator.
+    ///
+    /// When a request is deferred, the host records it in `deferred_signature` and halts
+    /// execution.
+    defer_signatures: bool,
+
+    /// The public key and the message of the signature request which was deferred, if any.
+    deferred_signature: Option<(Word, Word)>,
+
+    /// Answers signature requests which cannot be served from the advice provider with placeholder
+    /// signatures when the transaction is simulated, instead of forwarding them to the transaction
+    /// authenticator.
+    signature_simulator: Option<SignatureSimulator>,
+
+    /// The conditions under which the host halts execution, checked whenever an event is handled.
+    interrupt: ExecutionInterrupt,
+
+    /// The reason for which execution was halted by the host, if any.
+    interrupt_reason: Option<InterruptReason>,
+
+    /// The account data accessed by the transaction which is missing from the advice provider, if
+    /// any.
+    ///
+    /// When the transaction accesses a storage map entry or an asset of the native account, or a
+    /// foreign account, which is not present in the advice provider and the host has no account
+    /// data loader, the host records it in this field and halts execution.
+    missing_account_data: Option<MissingAccountData>,
+
+    /// Loads the account data which is accessed by the transaction but missing from the advice
+    /// provider while the transaction is executed, if any.
+    ///
+    /// If no loader is set, the host halts execution at missing account data instead (see
+    /// `missing_account_data`).
+    #[cfg(not(feature = "async"))]
+    account_data_loader: Option<AccountDataLoader<'store>>,
+
+    /// Handlers for events emitted by user code, i.e., events which are not transaction kernel
+    /// events.
+    event_handlers: EventHandlerRegistry,
+
+    /// The new code of the native account, which is installed if the transaction updates the
+    /// account code via the `account::set_code` kernel procedure.
+    new_account_code: Option<AccountCode>,
+
+    /// A callback which is invoked once the execution of the transaction epilogue has finished.
+    on_execution_end: Option<Box<dyn FnMut() + Send>>,
+
+    /// Tracks the number of cycles for each of the transaction execution stages, as well as the
+    /// number of account and note operations performed by the transaction.
     ///
     /// This field is updated by the [TransactionHost::on_trace()] handler.
     tx_progress: TransactionProgress,
@@ -97,14 +170,19 @@ pub struct TransactionHost<'store, 'auth, A> {
 
 impl<'store, 'auth, A: AdviceProvider> TransactionHost<'store, 'auth, A> {
     /// Returns a new [TransactionHost] instance with the provided [AdviceProvider].
+    ///
+    /// The code commitments of the foreign accounts and the new code of the native account, if
+    /// any, are taken from the provided [TransactionArgs].
     pub fn new(
         account: &PartialAccount,
         mut adv_provider: A,
         mast_store: &'store dyn MastForestStore,
         scripts_mast_store: ScriptMastForestStore,
         authenticator: Option<&'auth dyn TransactionAuthenticator>,
-        mut foreign_account_code_commitments: BTreeSet<Digest>,
+        tx_args: &TransactionArgs,
     ) -> Result<Self, TransactionHostError> {
+        let mut foreign_account_code_commitments = tx_args.foreign_account_code_commitments();
+
         // currently, the executor/prover do not keep track of the code commitment of the native
         // account, so we add it to the set here
         foreign_account_code_commitments.insert(account.code().commitment());
@@ -133,15 +211,91 @@ impl<'store, 'auth, A: AdviceProvider> TransactionHost<'store, 'auth, A> {
             account_delta: AccountDeltaTracker::new(
                 account.id(),
                 account.storage().header().clone(),
+                account.code().commitment(),
             ),
             acct_procedure_index_map: proc_index_map,
             output_notes: BTreeMap::default(),
             authenticator,
             tx_progress: TransactionProgress::default(),
             generated_signatures: BTreeMap::new(),
+            defer_signatures: false,
+            deferred_signature: None,
+            signature_simulator: None,
+            interrupt: ExecutionInterrupt::default(),
+            interrupt_reason: None,
+            missing_account_data: None,
+            #[cfg(not(feature = "async"))]
+            account_data_loader: None,
+            event_handlers: EventHandlerRegistry::default(),
+            new_account_code: tx_args.new_account_code().cloned(),
+            on_execution_end: None,
         })
     }
 
+    /// Configures the [TransactionHost] to defer signature generation.
+    ///
+    /// In this mode, the host does not consult the transaction authenticator. Instead, the first
+    /// signature request which cannot be served from the advice provider is recorded and execution
+    /// is halted with [TransactionKernelError::SignatureGenerationDeferred]. The state accumulated
+    /// by the host up to that point (account delta, output notes, etc.) can then be inspected.
+    pub fn with_deferred_signatures(mut self) -> Self {
+        self.defer_signatures = true;
+        self
+    }
+
+    /// Configures the [TransactionHost] to answer signature requests with placeholder signatures.
+    ///
+    /// In this mode, the host does not consult the transaction authenticator. Instead, signature
+    /// requests which cannot be served from the advice provider are recorded and answered with a
+    /// placeholder signature. For the execution to finish, the transaction must be executed
+    /// against the provided simulated account code, in which the verification of signatures is
+    /// skipped (see [SignatureSimulator]).
+    pub(crate) fn with_simulated_signatures(
+        mut self,
+        simulated_code: Option<&SimulatedAccountCode>,
+    ) -> Self {
+        self.signature_simulator = Some(SignatureSimulator::new(simulated_code));
+        self
+    }
+
+    /// Configures the [TransactionHost] to halt execution when the provided [ExecutionInterrupt]
+    /// fires.
+    ///
+    /// The interrupt is checked whenever an event is handled by the host. When it fires, the
+    /// reason is recorded and execution is halted with an error carrying that reason.
+    pub fn with_interrupt(mut self, interrupt: ExecutionInterrupt) -> Self {
+        self.interrupt = interrupt;
+        self
+    }
+
+    /// Configures the [TransactionHost] to dispatch events which are not transaction kernel events
+    /// to the handlers in the provided [EventHandlerRegistry].
+    pub fn with_event_handlers(mut self, event_handlers: EventHandlerRegistry) -> Self {
+        self.event_handlers = event_handlers;
+        self
+    }
+
+    /// Configures the [TransactionHost] to invoke the provided callback once the execution of the
+    /// transaction epilogue has finished.
+    pub(crate) fn with_execution_end_callback(mut self, callback: Box<dyn FnMut() + Send>) -> Self {
+        self.on_execution_end = Some(callback);
+        self
+    }
+
+    /// Extends the set of previously generated signatures of this [TransactionHost] with the
+    /// provided ones.
+    ///
+    /// The signatures are expected to be provided as a map from `hash(PUB_KEY, MESSAGE)` to the
+    /// signature data which should be pushed onto the advice stack when the signature is
+    /// requested.
+    pub fn with_generated_signatures(
+        mut self,
+        signatures: impl IntoIterator<Item = (Digest, Vec<Felt>)>,
+    ) -> Self {
+        self.generated_signatures.extend(signatures);
+        self
+    }
+
     /// Consumes `self` and returns the advice provider, account delta, output notes, generated
     /// signatures, and transaction progress.
     pub fn into_parts(
@@ -164,11 +318,78 @@ impl<'store, 'auth, A: AdviceProvider> TransactionHost<'store, 'auth, A> {
         )
     }
 
+    /// Returns the signatures generated by this [TransactionHost] so far, including the ones it
+    /// was pre-seeded with.
+    ///
+    /// The signatures are provided as a map from `hash(PUB_KEY, MESSAGE)` to the signature data.
+    pub fn generated_signatures(&self) -> &BTreeMap<Digest, Vec<Felt>> {
+        &self.generated_signatures
+    }
+
     /// Returns a reference to the `tx_progress` field of this transaction host.
     pub fn tx_progress(&self) -> &TransactionProgress {
         &self.tx_progress
     }
 
+    /// Returns the [SignatureSimulator] of this host, or `None` if signatures are not simulated.
+    pub(crate) fn signature_simulator(&self) -> Option<&SignatureSimulator> {
+        self.signature_simulator.as_ref()
+    }
+
+    /// Returns the public key and the message of the signature request at which execution was
+    /// halted, or `None` if no signature request was deferred.
+    pub fn deferred_signature(&self) -> Option<(Word, Word)> {
+        self.deferred_signature
+    }
+
+    /// Returns the [ExecutionInterrupt] of this host.
+    pub fn interrupt(&self) -> &ExecutionInterrupt {
+        &self.interrupt
+    }
+
+    /// Returns the reason for which execution was halted by the [ExecutionInterrupt] of this
+    /// host, or `None` if execution was not interrupted.
+    pub fn interrupt_reason(&self) -> Option<InterruptReason> {
+        self.interrupt_reason
+    }
+
+    /// Returns the account data at whose access execution was halted because it is missing from
+    /// the advice provider, or `None` if no missing account data was accessed.
+    pub fn missing_account_data(&self) -> Option<MissingAccountData> {
+        self.missing_account_data
+    }
+
+    /// Configures the [TransactionHost] to load account data which is accessed by the transaction
+    /// but missing from the advice provider via the provided [AccountDataLoader], instead of
+    /// halting execution.
+    #[cfg(not(feature = "async"))]
+    pub(crate) fn with_account_data_loader(mut self, loader: AccountDataLoader<'store>) -> Self {
+        self.account_data_loader = Some(loader);
+        self
+    }
+
+    /// Returns the native account extended with all account data loaded during execution, as
+    /// well as the inputs of all foreign accounts loaded during execution, or `None` if the host
+    /// has no account data loader.
+    pub(crate) fn take_loaded_account_data(
+        &mut self,
+    ) -> Option<(PartialAccount, Vec<AccountInputs>)> {
+        #[cfg(not(feature = "async"))]
+        return self.account_data_loader.take().map(AccountDataLoader::into_parts);
+
+        #[cfg(feature = "async")]
+        None
+    }
+
+    /// Returns the error at which loading missing account data failed during execution, if any.
+    pub(crate) fn take_account_data_error(&mut self) -> Option<TransactionExecutorError> {
+        #[cfg(not(feature = "async"))]
+        return self.account_data_loader.as_mut().and_then(AccountDataLoader::take_error);
+
+        #[cfg(feature = "async")]
+        None
+    }
+
     // EVENT HANDLERS
     // --------------------------------------------------------------------------------------------
 
@@ -252,6 +473,43 @@ impl<'store, 'auth, A: AdviceProvider> TransactionHost<'store, 'auth, A> {
         Ok(())
     }
 
+    /// Extracts the new code commitment of the native account from the process state and records
+    /// the corresponding code in the account delta tracker.
+    ///
+    /// If the new commitment is equal to the initial code commitment of the account, the code
+    /// update is removed from the delta.
+    ///
+    /// Expected stack state: [CODE_COMMITMENT, ...]
+    ///
+    /// # Errors
+    /// Returns an error if the code with the new commitment was not provided to the host via
+    /// [`TransactionArgs::with_new_account_code`].
+    pub fn on_account_after_set_code(
+        &mut self,
+        process: ProcessState,
+    ) -> Result<(), TransactionKernelError> {
+        let code_commitment = Digest::new([
+            process.get_stack_item(3),
+            process.get_stack_item(2),
+            process.get_stack_item(1),
+            process.get_stack_item(0),
+        ]);
+
+        if code_commitment == self.account_delta.initial_code_commitment() {
+            self.account_delta.set_code(None);
+            return Ok(());
+        }
+
+        let code = self
+            .new_account_code
+            .as_ref()
+            .filter(|code| code.commitment() == code_commitment)
+            .ok_or(TransactionKernelError::MissingNewAccountCode(code_commitment))?;
+        self.account_delta.set_code(Some(code.clone()));
+
+        Ok(())
+    }
+
     // ACCOUNT STORAGE UPDATE HANDLERS
     // --------------------------------------------------------------------------------------------
 
@@ -409,41 +667,105 @@ impl<'store, 'auth, A: AdviceProvider> TransactionHost<'store, 'auth, A> {
     /// Returns a signature as a response to the `SigToStack` injector.
     ///
     /// This signature is created during transaction execution and stored for use as advice map
-    /// inputs in the proving host. If not already present in the advice map, it is requested from
-    /// the host's authenticator.
+    /// inputs in the proving host. If not already present in the advice map or in the previously
+    /// generated signatures, it is requested from the host's authenticator, unless signature
+    /// generation is deferred.
     pub fn on_signature_requested(
         &mut self,
         process: ProcessState,
         err_ctx: &ErrorContext<'_, impl MastNodeExt>,
     ) -> Result<(), TransactionKernelError> {
+        let signature = self.get_signature(&process)?;
+        self.push_signature(signature, err_ctx)
+    }
+
+    /// Pushes a flag indicating whether a signature is available onto the advice stack, preceded
+    /// by the signature if it is.
+    ///
+    /// The signature is obtained in the same way as in [Self::on_signature_requested], except that
+    /// a failure of the authenticator to provide the signature is reported via the flag instead
+    /// of halting execution. This allows account components to request signatures from several
+    /// keyholders, only some of which may sign.
+    pub fn on_optional_signature_requested(
+        &mut self,
+        process: ProcessState,
+        err_ctx: &ErrorContext<'_, impl MastNodeExt>,
+    ) -> Result<(), TransactionKernelError> {
+        let is_available = match self.get_signature(&process) {
+            Ok(signature) => {
+                self.push_signature(signature, err_ctx)?;
+                ONE
+            },
+            Err(
+                TransactionKernelError::FailedSignatureGeneration(_)
+                | TransactionKernelError::SignatureGenerationFailed(_),
+            ) => ZERO,
+            Err(err) => return Err(err),
+        };
+
+        self.adv_provider
+            .push_stack(AdviceSource::Value(is_available), err_ctx)
+            .map_err(|_| TransactionKernelError::FailedToPushAdviceStack(is_available))
+    }
+
+    /// Returns the signature over the message at the second word of the stack against the public
+    /// key at the top of the stack.
+    fn get_signature(
+        &mut self,
+        process: &ProcessState,
+    ) -> Result<Vec<Felt>, TransactionKernelError> {
         let pub_key = process.get_stack_word(0);
         let msg = process.get_stack_word(1);
         let signature_key = Hasher::merge(&[pub_key.into(), msg.into()]);
+        self.tx_progress.record_signature_request(process.clk(), pub_key);
 
-        let signature = if let Some(signature) = self.adv_provider.get_mapped_values(&signature_key)
-        {
-            signature.to_vec()
-        } else {
-            let account_delta = self.account_delta.clone().into_delta();
+        if let Some(signature) = self.adv_provider.get_mapped_values(&signature_key) {
+            return Ok(signature.to_vec());
+        }
+        if let Some(signature) = self.generated_signatures.get(&signature_key) {
+            return Ok(signature.clone());
+        }
 
-            let signature: Vec<Felt> = match &self.authenticator {
-                None => {
-                    return Err(TransactionKernelError::FailedSignatureGeneration(
-                        "No authenticator assigned to transaction host",
-                    ));
-                },
-                Some(authenticator) => {
-                    authenticator.get_signature(pub_key, msg, &account_delta).map_err(|_| {
-                        TransactionKernelError::FailedSignatureGeneration(
-                            "Error generating signature",
-                        )
-                    })
-                },
-            }?;
+        if let Some(simulator) = &mut self.signature_simulator {
+            return Ok(simulator.answer_request(pub_key, msg));
+        }
 
-            self.generated_signatures.insert(signature_key, signature.clone());
-            signature
-        };
+        if self.defer_signatures {
+            self.deferred_signature = Some((pub_key, msg));
+
+            // the epilogue will not complete, so close its interval at the current cycle to keep
+            // the measurements of the halted execution meaningful
+            if self.tx_progress.epilogue().start().is_some() {
+                self.tx_progress.end_epilogue(process.clk());
+            }
+
+            return Err(TransactionKernelError::SignatureGenerationDeferred);
+        }
+
+        let account_delta = self.account_delta.clone().into_delta();
+
+        let signature: Vec<Felt> = match &self.authenticator {
+            None => {
+                return Err(TransactionKernelError::FailedSignatureGeneration(
+                    "No authenticator assigned to transaction host",
+                ));
+            },
+            Some(authenticator) => authenticator
+                .get_signature(pub_key, msg, &account_delta)
+                .map_err(|err| TransactionKernelError::SignatureGenerationFailed(Box::new(err))),
+        }?;
+
+        self.generated_signatures.insert(signature_key, signature.clone());
+        Ok(signature)
+    }
+
+    /// Pushes the provided signature onto the advice stack.
+    fn push_signature(
+        &mut self,
+        signature: Vec<Felt>,
+        err_ctx: &ErrorContext<'_, impl MastNodeExt>,
+    ) -> Result<(), TransactionKernelError> {
+        self.tx_progress.record_provided_signature();
 
         for r in signature {
             self.adv_provider
@@ -454,9 +776,309 @@ impl<'store, 'auth, A: AdviceProvider> TransactionHost<'store, 'auth, A> {
         Ok(())
     }
 
+    // ACCOUNT DATA ACCESS HANDLERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the storage map entry which is about to be accessed is present in the advice
+    /// provider, and loads it if it is not.
+    ///
+    /// Only the storage of the native account is checked, since foreign accounts are always
+    /// provided in full.
+    ///
+    /// Expected stack state: [slot_index, KEY, ...]
+    fn on_account_storage_before_access_map_item(
+        &mut self,
+        process: ProcessState,
+        err_ctx: &ErrorContext<'_, impl MastNodeExt>,
+    ) -> Result<(), ExecutionError> {
+        if !Self::is_native_account_active(process) {
+            return Ok(());
+        }
+
+        let slot_index = process.get_stack_item(0).as_int();
+        let key = Digest::from([
+            process.get_stack_item(4),
+            process.get_stack_item(3),
+            process.get_stack_item(2),
+            process.get_stack_item(1),
+        ]);
+
+        // the slot index is validated by the kernel, so an invalid index will fail execution
+        // right after this event
+        let Ok(slot_index) = u8::try_from(slot_index) else {
+            return Ok(());
+        };
+        let slot_ptr = NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR
+            + slot_index as u32 * ACCT_STORAGE_SLOT_NUM_ELEMENTS as u32;
+        let Some(map_root) = process.get_mem_word(ContextId::root(), slot_ptr)? else {
+            return Ok(());
+        };
+
+        let leaf_index = LeafIndex::<SMT_DEPTH>::from(StorageMap::hash_key(key));
+        self.ensure_smt_leaf_available(
+            map_root,
+            leaf_index,
+            MissingAccountData::StorageMapEntry { slot_index, key },
+            err_ctx,
+        )
+    }
+
+    /// Checks that the non-fungible asset of the native account vault which is about to be looked
+    /// up is present in the advice provider, and loads it if it is not.
+    ///
+    /// Expected stack state: [ASSET, ...]
+    fn on_account_vault_before_has_non_fungible_asset(
+        &mut self,
+        process: ProcessState,
+        err_ctx: &ErrorContext<'_, impl MastNodeExt>,
+    ) -> Result<(), ExecutionError> {
+        if !Self::is_native_account_active(process) {
+            return Ok(());
+        }
+
+        // a malformed asset is rejected by the kernel right after this event
+        let Ok(asset) = Asset::try_from(process.get_stack_word(0)) else {
+            return Ok(());
+        };
+
+        self.ensure_vault_asset_available(
+            process,
+            NATIVE_ACCT_VAULT_ROOT_PTR,
+            asset.vault_key(),
+            err_ctx,
+        )
+    }
+
+    /// Checks that the fungible asset of the native account vault whose balance is about to be
+    /// read is present in the advice provider, and loads it if it is not.
+    ///
+    /// Expected stack state: [faucet_id_prefix, faucet_id_suffix, ...]
+    fn on_account_vault_before_get_balance(
+        &mut self,
+        process: ProcessState,
+        err_ctx: &ErrorContext<'_, impl MastNodeExt>,
+    ) -> Result<(), ExecutionError> {
+        if !Self::is_native_account_active(process) {
+            return Ok(());
+        }
+
+        // an invalid faucet ID is rejected by the kernel right after this event
+        let Ok(faucet_id) =
+            AccountId::try_from([process.get_stack_item(0), process.get_stack_item(1)])
+        else {
+            return Ok(());
+        };
+        let Ok(asset) = FungibleAsset::new(faucet_id, 0) else {
+            return Ok(());
+        };
+
+        self.ensure_vault_asset_available(
+            process,
+            NATIVE_ACCT_VAULT_ROOT_PTR,
+            asset.vault_key(),
+            err_ctx,
+        )
+    }
+
+    /// Checks that the asset which is about to be added to or removed from a vault is present in
+    /// the advice provider, and loads it if it is not.
+    ///
+    /// Assets are only added to or removed from the vault of the native account, and the input and
+    /// output vaults of the transaction, which are derived from the vault of the native account.
+    /// Thus, the missing asset can always be loaded from the vault of the native account.
+    ///
+    /// Expected stack state: [ASSET, vault_root_ptr, ...]
+    fn on_asset_vault_before_update(
+        &mut self,
+        process: ProcessState,
+        err_ctx: &ErrorContext<'_, impl MastNodeExt>,
+    ) -> Result<(), ExecutionError> {
+        // a malformed asset is rejected by the kernel right after this event
+        let Ok(asset) = Asset::try_from(process.get_stack_word(0)) else {
+            return Ok(());
+        };
+        let Ok(vault_root_ptr) = u32::try_from(process.get_stack_item(4).as_int()) else {
+            return Ok(());
+        };
+
+        self.ensure_vault_asset_available(process, vault_root_ptr, asset.vault_key(), err_ctx)
+    }
+
+    /// Checks that the data of the foreign account which is about to be loaded is present in the
+    /// advice provider, and loads it if it is not.
+    ///
+    /// Expected stack state: [foreign_account_id_prefix, foreign_account_id_suffix, ...]
+    fn on_account_before_foreign_load(
+        &mut self,
+        process: ProcessState,
+        err_ctx: &ErrorContext<'_, impl MastNodeExt>,
+    ) -> Result<(), ExecutionError> {
+        // the data of an invalid account ID can not be provided, so execution fails when the kernel
+        // reads the account data right after this event
+        let Ok(account_id) =
+            AccountId::try_from([process.get_stack_item(0), process.get_stack_item(1)])
+        else {
+            return Ok(());
+        };
+
+        // NOTE: keep this in sync with the key under which the foreign account data is stored in
+        // the advice map by `TransactionAdviceInputs`
+        let account_id_key =
+            Digest::from([account_id.suffix(), account_id.prefix().as_felt(), ZERO, ZERO]);
+        if self.adv_provider.get_mapped_values(&account_id_key).is_some() {
+            return Ok(());
+        }
+
+        self.load_missing_account_data(MissingAccountData::ForeignAccount { account_id }, err_ctx)
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
+    /// Checks that the asset with the specified vault key is present in the vault whose root is
+    /// stored at the specified memory address, and loads it if it is not.
+    fn ensure_vault_asset_available(
+        &mut self,
+        process: ProcessState,
+        vault_root_ptr: u32,
+        vault_key: Word,
+        err_ctx: &ErrorContext<'_, impl MastNodeExt>,
+    ) -> Result<(), ExecutionError> {
+        let Some(vault_root) = process.get_mem_word(ContextId::root(), vault_root_ptr)? else {
+            return Ok(());
+        };
+
+        self.ensure_smt_leaf_available(
+            vault_root,
+            LeafIndex::<SMT_DEPTH>::from(vault_key),
+            MissingAccountData::VaultAsset { vault_key: vault_key.into() },
+            err_ctx,
+        )
+    }
+
+    /// Checks that the specified leaf of the sparse Merkle tree with the specified root can be
+    /// read from the advice provider, i.e., that the path to the leaf is present in the Merkle
+    /// store and the entries of a non-empty leaf are present in the advice map.
+    ///
+    /// If the leaf is not available, the provided missing account data is loaded (see
+    /// [`Self::load_missing_account_data`]).
+    fn ensure_smt_leaf_available(
+        &mut self,
+        root: Word,
+        leaf_index: LeafIndex<SMT_DEPTH>,
+        missing_data: MissingAccountData,
+        err_ctx: &ErrorContext<'_, impl MastNodeExt>,
+    ) -> Result<(), ExecutionError> {
+        let leaf = self.adv_provider.get_tree_node(
+            root,
+            &Felt::from(SMT_DEPTH),
+            &Felt::new(leaf_index.value()),
+            err_ctx,
+        );
+
+        let is_available = match leaf {
+            Ok(leaf) => {
+                leaf == EMPTY_WORD || self.adv_provider.get_mapped_values(&leaf.into()).is_some()
+            },
+            Err(_) => false,
+        };
+
+        if is_available {
+            return Ok(());
+        }
+
+        self.load_missing_account_data(missing_data, err_ctx)
+    }
+
+    /// Loads the provided account data, which is missing from the advice provider, via the account
+    /// data loader of this host and adds it to the advice provider.
+    ///
+    /// If the host has no account data loader, the missing data is recorded and execution is
+    /// halted. If loading fails, the error is recorded by the loader and execution is halted.
+    fn load_missing_account_data(
+        &mut self,
+        missing_data: MissingAccountData,
+        err_ctx: &ErrorContext<'_, impl MastNodeExt>,
+    ) -> Result<(), ExecutionError> {
+        #[cfg(not(feature = "async"))]
+        if let Some(loader) = self.account_data_loader.as_mut() {
+            let mut foreign_code_commitment = None;
+            let advice_inputs = match missing_data {
+                MissingAccountData::StorageMapEntry { slot_index, key } => loader
+                    .load_storage_map_entry(slot_index, key)
+                    .map(|witness| smt_proof_advice_inputs(&witness)),
+                MissingAccountData::VaultAsset { vault_key } => loader
+                    .load_vault_asset(vault_key)
+                    .map(|witness| smt_proof_advice_inputs(&witness)),
+                MissingAccountData::ForeignAccount { account_id } => {
+                    loader.load_foreign_account(account_id).map(|account_inputs| {
+                        foreign_code_commitment =
+                            Some(account_inputs.account().code().commitment());
+                        let mut advice_inputs = TransactionAdviceInputs::default();
+                        advice_inputs.add_foreign_account(account_inputs);
+                        advice_inputs.into_inner()
+                    })
+                },
+            };
+
+            let advice_inputs = match advice_inputs {
+                Ok(advice_inputs) => advice_inputs,
+                Err(err) => {
+                    loader.set_error(err);
+                    return Err(ExecutionError::event_error(
+                        Box::new(TransactionKernelError::AccountDataNotAvailable),
+                        err_ctx,
+                    ));
+                },
+            };
+            self.extend_advice_provider(advice_inputs, err_ctx)?;
+
+            // the procedures of a loaded foreign account must be known to the host before the
+            // account is invoked
+            if let Some(code_commitment) = foreign_code_commitment {
+                self.acct_procedure_index_map
+                    .insert_code(code_commitment, &self.adv_provider)
+                    .map_err(|err| ExecutionError::event_error(Box::new(err), err_ctx))?;
+            }
+
+            return Ok(());
+        }
+
+        self.missing_account_data = Some(missing_data);
+        Err(ExecutionError::event_error(
+            Box::new(TransactionKernelError::AccountDataNotAvailable),
+            err_ctx,
+        ))
+    }
+
+    /// Adds the map entries and Merkle store nodes of the provided advice inputs to the advice
+    /// provider of this host.
+    #[cfg(not(feature = "async"))]
+    fn extend_advice_provider(
+        &mut self,
+        advice_inputs: AdviceInputs,
+        err_ctx: &ErrorContext<'_, impl MastNodeExt>,
+    ) -> Result<(), ExecutionError> {
+        let (_, map, store) = MemAdviceProvider::from(advice_inputs).into_parts();
+        for (key, values) in map {
+            self.adv_provider.insert_into_map(key.into(), values);
+        }
+        for node in store.inner_nodes() {
+            self.adv_provider.merge_roots(node.left.into(), node.right.into(), err_ctx)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the account currently being accessed by the kernel is the native account.
+    fn is_native_account_active(process: ProcessState) -> bool {
+        process
+            .get_mem_value(ContextId::root(), ACCOUNT_STACK_TOP_PTR)
+            .and_then(|stack_top_ptr| u32::try_from(stack_top_ptr.as_int()).ok())
+            .and_then(|stack_top_ptr| process.get_mem_value(ContextId::root(), stack_top_ptr))
+            .is_some_and(|account_data_ptr| account_data_ptr == Felt::from(NATIVE_ACCOUNT_DATA_PTR))
+    }
+
     /// Returns the ID of the currently executing input note, or None if the note execution hasn't
     /// started yet or has already ended.
     ///
@@ -519,6 +1141,15 @@ impl<A: AdviceProvider> Host for TransactionHost<'_, '_, A> {
     }
 
     fn get_mast_forest(&self, node_digest: &Digest) -> Option<Arc<MastForest>> {
+        // When signatures are simulated, the simulated account code is served by the simulator
+        if let Some(forest) = self
+            .signature_simulator
+            .as_ref()
+            .and_then(|simulator| simulator.get_mast_forest(node_digest))
+        {
+            return Some(forest);
+        }
+
         // Search in the note MAST forest store, otherwise fall back to the user-provided store
         match self.scripts_mast_store.get(node_digest) {
             Some(forest) => Some(forest),
@@ -532,12 +1163,29 @@ impl<A: AdviceProvider> Host for TransactionHost<'_, '_, A> {
         event_id: u32,
         err_ctx: &ErrorContext<'_, impl MastNodeExt>,
     ) -> Result<(), ExecutionError> {
+        if let Err(reason) = self.interrupt.check() {
+            self.interrupt_reason = Some(reason);
+            return Err(ExecutionError::event_error(Box::new(reason), err_ctx));
+        }
+
+        // user-defined handlers can only be registered for event IDs outside of the range reserved
+        // for the transaction kernel, so they can be dispatched before parsing the event
+        if let Some(handler) = self.event_handlers.get(event_id).cloned() {
+            let mut advice = EventAdviceProvider::new(&mut self.adv_provider, err_ctx);
+            return handler
+                .on_event(event_id, process, &mut advice)
+                .map_err(|err| ExecutionError::event_error(err, err_ctx));
+        }
+
         let transaction_event = TransactionEvent::try_from(event_id)
             .map_err(|err| ExecutionError::event_error(Box::new(err), err_ctx))?;
 
-        // only the `FalconSigToStack` event can be executed outside the root context
+        // only the signature events can be executed outside the root context
         if process.ctx() != ContextId::root()
-            && !matches!(transaction_event, TransactionEvent::FalconSigToStack)
+            && !matches!(
+                transaction_event,
+                TransactionEvent::FalconSigToStack | TransactionEvent::FalconSigToStackIfAvailable
+            )
         {
             return Err(ExecutionError::event_error(
                 Box::new(TransactionEventError::NotRootContext(event_id)),
@@ -548,21 +1196,28 @@ impl<A: AdviceProvider> Host for TransactionHost<'_, '_, A> {
         match transaction_event {
             TransactionEvent::AccountVaultBeforeAddAsset => Ok(()),
             TransactionEvent::AccountVaultAfterAddAsset => {
+                self.tx_progress.record_vault_add();
                 self.on_account_vault_after_add_asset(process)
             },
 
             TransactionEvent::AccountVaultBeforeRemoveAsset => Ok(()),
             TransactionEvent::AccountVaultAfterRemoveAsset => {
+                self.tx_progress.record_vault_remove();
                 self.on_account_vault_after_remove_asset(process)
             },
 
             TransactionEvent::AccountStorageBeforeSetItem => Ok(()),
             TransactionEvent::AccountStorageAfterSetItem => {
+                self.tx_progress.record_storage_write();
                 self.on_account_storage_after_set_item(process)
             },
 
-            TransactionEvent::AccountStorageBeforeSetMapItem => Ok(()),
+            TransactionEvent::AccountStorageBeforeSetMapItem => {
+                self.on_account_storage_before_access_map_item(process, err_ctx)?;
+                Ok(())
+            },
             TransactionEvent::AccountStorageAfterSetMapItem => {
+                self.tx_progress.record_map_write();
                 self.on_account_storage_after_set_map_item(process)
             },
 
@@ -571,15 +1226,31 @@ impl<A: AdviceProvider> Host for TransactionHost<'_, '_, A> {
             },
             TransactionEvent::AccountAfterIncrementNonce => Ok(()),
 
+            TransactionEvent::AccountAfterSetCode => self.on_account_after_set_code(process),
+
             TransactionEvent::AccountPushProcedureIndex => {
                 self.on_account_push_procedure_index(process,err_ctx)
             },
 
-            TransactionEvent::NoteBeforeCreated => Ok(()),
-            TransactionEvent::NoteAfterCreated => self.on_note_after_created(process),
+            TransactionEvent::NoteBeforeCreated => {
+                self.tx_progress.start_note_creation(process.clk());
+                Ok(())
+            },
+            TransactionEvent::NoteAfterCreated => {
+                self.tx_progress.end_note_creation(process.clk());
+                self.on_note_after_created(process)
+            },
 
-            TransactionEvent::NoteBeforeAddAsset => self.on_note_before_add_asset(process),
-            TransactionEvent::NoteAfterAddAsset => Ok(()),
+            TransactionEvent::NoteBeforeAddAsset => {
+                self.tx_progress.start_note_asset_addition(process.clk());
+                self.on_note_before_add_asset(process)
+            },
+            TransactionEvent::NoteAfterAddAsset => {
+                // expected stack state: [note_ptr, note_idx, ...]
+                let note_idx = process.get_stack_item(1).as_int() as usize;
+                self.tx_progress.end_note_asset_addition(process.clk(), note_idx);
+                Ok(())
+            },
 
             TransactionEvent::FalconSigToStack => self.on_signature_requested(process,err_ctx),
 
@@ -628,6 +1299,9 @@ impl<A: AdviceProvider> Host for TransactionHost<'_, '_, A> {
             }
             TransactionEvent::EpilogueEnd => {
                 self.tx_progress.end_epilogue(process.clk());
+                if let Some(callback) = self.on_execution_end.as_mut() {
+                    callback();
+                }
                 Ok(())
             }
             TransactionEvent::LinkMapSetEvent => {
@@ -638,9 +1312,75 @@ impl<A: AdviceProvider> Host for TransactionHost<'_, '_, A> {
                 LinkMap::handle_get_event(process, err_ctx, self.advice_provider_mut())?;
                 Ok(())
             }
+
+            TransactionEvent::AccountStorageBeforeGetMapItem => {
+                self.tx_progress.record_map_read();
+                self.on_account_storage_before_access_map_item(process, err_ctx)?;
+                Ok(())
+            },
+
+            TransactionEvent::AccountVaultBeforeGetBalance => {
+                self.on_account_vault_before_get_balance(process, err_ctx)?;
+                Ok(())
+            },
+            TransactionEvent::AccountVaultBeforeHasNonFungibleAsset => {
+                self.on_account_vault_before_has_non_fungible_asset(process, err_ctx)?;
+                Ok(())
+            },
+
+            TransactionEvent::AssetVaultBeforeUpdate => {
+                self.on_asset_vault_before_update(process, err_ctx)?;
+                Ok(())
+            },
+
+            TransactionEvent::FalconSigToStackIfAvailable => {
+                self.on_optional_signature_requested(process, err_ctx)
+            },
+
+            TransactionEvent::AccountBeforeForeignLoad => {
+                self.on_account_before_foreign_load(process, err_ctx)?;
+                Ok(())
+            },
+
+            TransactionEvent::AccountStorageBeforeGetItem => {
+                self.tx_progress.record_storage_read();
+                Ok(())
+            },
+
+            TransactionEvent::ForeignContextStart => {
+                // an invalid account ID makes the kernel fail right after this event
+                if let Ok(account_id) =
+                    AccountId::try_from([process.get_stack_item(0), process.get_stack_item(1)])
+                {
+                    self.tx_progress.start_foreign_context(process.clk(), account_id);
+                }
+                Ok(())
+            },
+            TransactionEvent::ForeignContextEnd => {
+                self.tx_progress.end_foreign_context(process.clk());
+                Ok(())
+            },
         }
         .map_err(|err| ExecutionError::event_error(Box::new(err),err_ctx))?;
 
         Ok(())
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the advice inputs required to read the leaf opened by the provided proof, i.e., the
+/// entries of the leaf keyed by the leaf hash and the nodes of the path to the leaf.
+#[cfg(not(feature = "async"))]
+fn smt_proof_advice_inputs(proof: &SmtProof) -> AdviceInputs {
+    let leaf = proof.leaf();
+    let inner_nodes = proof
+        .path()
+        .inner_nodes(leaf.index().value(), leaf.hash())
+        .expect("any u64 value is a valid SMT leaf index");
+
+    let mut advice_inputs = AdviceInputs::default().with_map([(leaf.hash(), leaf.to_elements())]);
+    advice_inputs.extend_merkle_store(inner_nodes);
+    advice_inputs
+}
@@ -0,0 +1,230 @@
+use alloc::{string::ToString, sync::Arc, vec, vec::Vec};
+
+use miden_lib::{MidenLib, StdLibrary};
+use miden_objects::{
+    Digest,
+    account::{AccountCode, AccountProcedureInfo},
+    assembly::{
+        Library,
+        mast::{ExternalNode, MastForest, MastNode, MastNodeId},
+    },
+};
+use vm_processor::{Felt, Operation, Word};
+
+/// The fully qualified name of the procedure verifying Falcon signatures in the standard library.
+const FALCON_VERIFY_PROCEDURE: &str = "std::crypto::dsa::rpo_falcon512::verify";
+
+// SIGNATURE SIMULATOR
+// ================================================================================================
+
+/// Answers the signature requests of a simulated transaction with placeholder signatures.
+///
+/// Since signatures are verified inside the VM, a placeholder signature would make the execution
+/// fail. To let the execution finish, a transaction is simulated against account code in which
+/// the calls to the procedure of the standard library verifying Falcon signatures (i.e.,
+/// `rpo_falcon512::verify`) are replaced by code which only drops the public key and the message
+/// from the stack (see [`SimulatedAccountCode`]). The placeholder signature is therefore empty.
+pub(crate) struct SignatureSimulator {
+    mast_forests: Vec<Arc<MastForest>>,
+    requests: Vec<(Word, Word)>,
+}
+
+impl SignatureSimulator {
+    /// Returns a new [SignatureSimulator] for a transaction executed against the provided
+    /// simulated account code, if any.
+    ///
+    /// The MAST of the simulated code is served by the host, since it is not available in the
+    /// [`DataStore`](crate::DataStore).
+    pub fn new(simulated_code: Option<&SimulatedAccountCode>) -> Self {
+        Self {
+            mast_forests: simulated_code
+                .map(|simulated_code| simulated_code.mast_forests.clone())
+                .unwrap_or_default(),
+            requests: Vec::new(),
+        }
+    }
+
+    /// Records a signature request for the provided public key and message, and returns the
+    /// placeholder signature answering it.
+    pub fn answer_request(&mut self, pub_key: Word, message: Word) -> Vec<Felt> {
+        self.requests.push((pub_key, message));
+        Vec::new()
+    }
+
+    /// Returns the MAST forest of the simulated account code in which the procedure with the
+    /// provided root is defined, if any.
+    pub fn get_mast_forest(&self, procedure_root: &Digest) -> Option<Arc<MastForest>> {
+        self.mast_forests
+            .iter()
+            .find(|forest| {
+                forest
+                    .find_procedure_root(*procedure_root)
+                    .is_some_and(|root| !forest[root].is_external())
+            })
+            .cloned()
+    }
+
+    /// Returns the public keys and messages of the answered signature requests, in the order in
+    /// which they were requested.
+    pub fn requests(&self) -> &[(Word, Word)] {
+        &self.requests
+    }
+}
+
+// SIMULATED ACCOUNT CODE
+// ================================================================================================
+
+/// Account code in which all calls to the procedure verifying Falcon signatures are replaced by
+/// code which drops the inputs of the procedure.
+///
+/// The procedures of the standard authentication components are re-exported from the miden
+/// library, so their calls to the verification procedure are not part of the MAST of the account
+/// code. Hence, the procedures of the miden library are rewritten as well, and the account code
+/// references the rewritten procedures.
+///
+/// Procedures which do not verify signatures retain their MAST roots, while the roots of the
+/// procedures which do (and thus the commitment of the account code) change.
+pub(crate) struct SimulatedAccountCode {
+    code: AccountCode,
+    /// The MAST forests of the simulated account code and of the rewritten miden library.
+    mast_forests: Vec<Arc<MastForest>>,
+}
+
+impl SimulatedAccountCode {
+    /// Returns the simulated version of the provided account code, or `None` if the code does not
+    /// verify Falcon signatures.
+    pub fn new(code: &AccountCode) -> Option<Self> {
+        let std_lib = StdLibrary::default();
+        let std_lib: &Library = std_lib.as_ref();
+        let verify_root = std_lib
+            .exports()
+            .find(|name| name.to_string() == FALCON_VERIFY_PROCEDURE)
+            .map(|name| std_lib.mast_forest()[std_lib.get_export_node_id(name)].digest())
+            .expect("standard library should export the falcon signature verification procedure");
+        let skip_verification = |external: &ExternalNode| {
+            // drops [PUB_KEY, MESSAGE] from the stack
+            (external.digest() == verify_root).then(|| {
+                MastNode::new_basic_block(vec![Operation::Drop; 8], None)
+                    .expect("basic block should not be empty")
+            })
+        };
+
+        let miden_lib = MidenLib::default();
+        let miden_lib = miden_lib.mast_forest();
+        let (simulated_lib, lib_node_ids) = rebuild_forest(miden_lib, skip_verification);
+
+        let (simulated_mast, node_ids) = rebuild_forest(&code.mast(), |external| {
+            skip_verification(external).or_else(|| {
+                let lib_root = miden_lib.find_procedure_root(external.digest())?;
+                let simulated_root = lib_node_ids[lib_root.as_usize()];
+                (simulated_root != lib_root)
+                    .then(|| MastNode::new_external(simulated_lib[simulated_root].digest()))
+            })
+        });
+
+        let procedures: Vec<AccountProcedureInfo> = code
+            .procedures()
+            .iter()
+            .map(|procedure| {
+                let root = code
+                    .mast()
+                    .find_procedure_root(*procedure.mast_root())
+                    .expect("account procedures should be roots of the account code");
+                AccountProcedureInfo::new(
+                    simulated_mast[node_ids[root.as_usize()]].digest(),
+                    procedure.storage_offset(),
+                    procedure.storage_size(),
+                )
+                .expect("storage of the procedure should be unchanged")
+            })
+            .collect();
+
+        if procedures == code.procedures() {
+            return None;
+        }
+
+        let simulated_mast = Arc::new(simulated_mast);
+        Some(Self {
+            code: AccountCode::from_parts(simulated_mast.clone(), procedures),
+            mast_forests: vec![simulated_mast, Arc::new(simulated_lib)],
+        })
+    }
+
+    /// Returns the simulated account code.
+    pub fn code(&self) -> &AccountCode {
+        &self.code
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a copy of the provided forest to which rebuilt versions of the nodes which reference
+/// the external nodes replaced by `replace_external` were added, together with the ID of the
+/// (possibly rebuilt) version of every node of the provided forest.
+///
+/// Rebuilt procedures are added as procedure roots, while the original nodes are retained so that
+/// the IDs of decorators and the error messages of the forest remain valid.
+fn rebuild_forest(
+    mast: &MastForest,
+    replace_external: impl Fn(&ExternalNode) -> Option<MastNode>,
+) -> (MastForest, Vec<MastNodeId>) {
+    let mut rebuilt_mast = mast.clone();
+
+    // children are added before their parents, so a node only needs to be rebuilt if any of its
+    // children were
+    let mut node_ids: Vec<MastNodeId> = Vec::with_capacity(mast.nodes().len());
+    for (index, node) in mast.nodes().iter().enumerate() {
+        let node_id =
+            MastNodeId::from_usize_safe(index, mast).expect("node should be in the forest");
+        let rebuilt_id = |id: MastNodeId| node_ids[id.as_usize()];
+        let is_rebuilt = |id: MastNodeId| rebuilt_id(id) != id;
+
+        let rebuilt_node = match node {
+            MastNode::External(external) => replace_external(external).map(Ok),
+            MastNode::Join(join) if is_rebuilt(join.first()) || is_rebuilt(join.second()) => {
+                Some(MastNode::new_join(
+                    rebuilt_id(join.first()),
+                    rebuilt_id(join.second()),
+                    &rebuilt_mast,
+                ))
+            },
+            MastNode::Split(split)
+                if is_rebuilt(split.on_true()) || is_rebuilt(split.on_false()) =>
+            {
+                Some(MastNode::new_split(
+                    rebuilt_id(split.on_true()),
+                    rebuilt_id(split.on_false()),
+                    &rebuilt_mast,
+                ))
+            },
+            MastNode::Loop(loop_node) if is_rebuilt(loop_node.body()) => {
+                Some(MastNode::new_loop(rebuilt_id(loop_node.body()), &rebuilt_mast))
+            },
+            MastNode::Call(call) if is_rebuilt(call.callee()) => Some(if call.is_syscall() {
+                MastNode::new_syscall(rebuilt_id(call.callee()), &rebuilt_mast)
+            } else {
+                MastNode::new_call(rebuilt_id(call.callee()), &rebuilt_mast)
+            }),
+            _ => None,
+        };
+
+        let rebuilt_id = match rebuilt_node {
+            Some(rebuilt_node) => {
+                let mut rebuilt_node =
+                    rebuilt_node.expect("children of a node should be added before the node");
+                rebuilt_node.append_before_enter(node.before_enter());
+                rebuilt_node.append_after_exit(node.after_exit());
+                rebuilt_mast.add_node(rebuilt_node).expect("nodes should fit")
+            },
+            None => node_id,
+        };
+        node_ids.push(rebuilt_id);
+    }
+
+    for root in mast.procedure_roots() {
+        rebuilt_mast.make_root(node_ids[root.as_usize()]);
+    }
+
+    (rebuilt_mast, node_ids)
+}
@@ -1,12 +1,13 @@
+use alloc::collections::BTreeMap;
 pub use alloc::vec::Vec;
 
-use super::{NoteId, RowIndex, TransactionMeasurements};
+use super::{AccountId, NoteId, RowIndex, TransactionMeasurements, Word};
 
 // TRANSACTION PROGRESS
 // ================================================================================================
 
 /// Contains the information about the number of cycles for each of the transaction execution
-/// stages.
+/// stages, as well as the number of account and note operations performed by the transaction.
 #[derive(Clone, Default, Debug)]
 pub struct TransactionProgress {
     prologue: CycleInterval,
@@ -14,6 +15,17 @@ pub struct TransactionProgress {
     note_execution: Vec<(NoteId, CycleInterval)>,
     tx_script_processing: CycleInterval,
     epilogue: CycleInterval,
+    foreign_account_execution: Vec<(AccountId, CycleInterval)>,
+    output_note_creation: Vec<usize>,
+    note_operation_start: Option<RowIndex>,
+    storage_reads: usize,
+    storage_writes: usize,
+    map_reads: usize,
+    map_writes: usize,
+    vault_adds: usize,
+    vault_removes: usize,
+    provided_signatures: usize,
+    signature_requests: Vec<(RowIndex, Word)>,
 }
 
 impl TransactionProgress {
@@ -40,6 +52,68 @@ impl TransactionProgress {
         &self.epilogue
     }
 
+    /// Returns the cycle intervals of the executions of foreign account procedures, in the order
+    /// in which they were started.
+    ///
+    /// If foreign procedures are invoked from a foreign account, the interval of the outer
+    /// foreign account includes the intervals of the nested ones.
+    pub fn foreign_account_execution(&self) -> &Vec<(AccountId, CycleInterval)> {
+        &self.foreign_account_execution
+    }
+
+    /// Returns the number of cycles spent creating each output note, indexed by the index of the
+    /// output note.
+    ///
+    /// The cycles include creating the note and adding assets to it.
+    pub fn output_note_creation(&self) -> &[usize] {
+        &self.output_note_creation
+    }
+
+    /// Returns the number of items read from account storage value slots.
+    pub fn storage_reads(&self) -> usize {
+        self.storage_reads
+    }
+
+    /// Returns the number of items written to account storage value slots.
+    pub fn storage_writes(&self) -> usize {
+        self.storage_writes
+    }
+
+    /// Returns the number of entries read from account storage maps.
+    pub fn map_reads(&self) -> usize {
+        self.map_reads
+    }
+
+    /// Returns the number of entries written to account storage maps.
+    pub fn map_writes(&self) -> usize {
+        self.map_writes
+    }
+
+    /// Returns the number of assets added to the account vault.
+    pub fn vault_adds(&self) -> usize {
+        self.vault_adds
+    }
+
+    /// Returns the number of assets removed from the account vault.
+    pub fn vault_removes(&self) -> usize {
+        self.vault_removes
+    }
+
+    /// Returns the number of signatures pushed onto the advice stack in response to signature
+    /// requests (i.e., `FALCON_SIG_TO_STACK` events).
+    ///
+    /// Whether a provided signature is verified is up to the account component which requested
+    /// it.
+    pub fn provided_signatures(&self) -> usize {
+        self.provided_signatures
+    }
+
+    /// Returns the cycles at which signatures were requested from the host, together with the
+    /// public keys against which the signatures were requested, in the order of the requests.
+    pub fn signature_requests(&self) -> &[(RowIndex, Word)] {
+        &self.signature_requests
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -84,6 +158,88 @@ impl TransactionProgress {
     pub fn end_epilogue(&mut self, cycle: RowIndex) {
         self.epilogue.set_end(cycle);
     }
+
+    pub fn start_foreign_context(&mut self, cycle: RowIndex, account_id: AccountId) {
+        self.foreign_account_execution.push((account_id, CycleInterval::new(cycle)));
+    }
+
+    /// Ends the innermost foreign context which has not been ended yet.
+    pub fn end_foreign_context(&mut self, cycle: RowIndex) {
+        if let Some((_, interval)) = self
+            .foreign_account_execution
+            .iter_mut()
+            .rev()
+            .find(|(_, interval)| interval.end().is_none())
+        {
+            interval.set_end(cycle)
+        }
+    }
+
+    pub fn start_note_creation(&mut self, cycle: RowIndex) {
+        self.note_operation_start = Some(cycle);
+    }
+
+    pub fn end_note_creation(&mut self, cycle: RowIndex) {
+        let cycles = self.take_note_operation_cycles(cycle);
+        self.output_note_creation.push(cycles);
+    }
+
+    pub fn start_note_asset_addition(&mut self, cycle: RowIndex) {
+        self.note_operation_start = Some(cycle);
+    }
+
+    pub fn end_note_asset_addition(&mut self, cycle: RowIndex, note_idx: usize) {
+        let cycles = self.take_note_operation_cycles(cycle);
+        if let Some(note_cycles) = self.output_note_creation.get_mut(note_idx) {
+            *note_cycles += cycles;
+        }
+    }
+
+    pub fn record_storage_read(&mut self) {
+        self.storage_reads += 1;
+    }
+
+    pub fn record_storage_write(&mut self) {
+        self.storage_writes += 1;
+    }
+
+    pub fn record_map_read(&mut self) {
+        self.map_reads += 1;
+    }
+
+    pub fn record_map_write(&mut self) {
+        self.map_writes += 1;
+    }
+
+    pub fn record_vault_add(&mut self) {
+        self.vault_adds += 1;
+    }
+
+    pub fn record_vault_remove(&mut self) {
+        self.vault_removes += 1;
+    }
+
+    pub fn record_provided_signature(&mut self) {
+        self.provided_signatures += 1;
+    }
+
+    pub fn record_signature_request(&mut self, cycle: RowIndex, pub_key: Word) {
+        self.signature_requests.push((cycle, pub_key));
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of cycles elapsed since the start of the current note operation and
+    /// resets the start.
+    fn take_note_operation_cycles(&mut self, cycle: RowIndex) -> usize {
+        let mut interval = CycleInterval::default();
+        if let Some(start) = self.note_operation_start.take() {
+            interval.set_start(start);
+            interval.set_end(cycle);
+        }
+        interval.len()
+    }
 }
 
 impl From<TransactionProgress> for TransactionMeasurements {
@@ -102,12 +258,28 @@ impl From<TransactionProgress> for TransactionMeasurements {
 
         let epilogue = tx_progress.epilogue().len();
 
+        let mut foreign_account_execution = BTreeMap::new();
+        for (account_id, interval) in tx_progress.foreign_account_execution() {
+            *foreign_account_execution.entry(*account_id).or_default() += interval.len();
+        }
+
         Self {
             prologue,
             notes_processing,
             note_execution,
             tx_script_processing,
             epilogue,
+            foreign_account_execution,
+            output_note_creation: tx_progress.output_note_creation().to_vec(),
+            storage_reads: tx_progress.storage_reads(),
+            storage_writes: tx_progress.storage_writes(),
+            map_reads: tx_progress.map_reads(),
+            map_writes: tx_progress.map_writes(),
+            vault_adds: tx_progress.vault_adds(),
+            vault_removes: tx_progress.vault_removes(),
+            provided_signatures: tx_progress.provided_signatures(),
+            range_trace_length: 0,
+            chiplets_trace_length: 0,
         }
     }
 }
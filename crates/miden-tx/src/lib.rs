@@ -10,27 +10,48 @@ pub use miden_objects::transaction::TransactionInputs;
 
 mod executor;
 pub use executor::{
-    DataStore, ExecutionOptions, MastForestStore, NoteAccountExecution, NoteConsumptionChecker,
-    NoteInputsCheck, TransactionExecutor,
+    CachingDataStore, DataStore, ExecutionOptions, MastForestStore, NoteAccountExecution,
+    NoteConsumptionChecker, NoteInputsCheck, SigningRequest, SimulatedTransaction, TraceExporter,
+    TraceSpan, TraceSpanKind, TransactionExecution, TransactionExecutor, TransactionProfile,
+    TransactionTrace, ViewCall, ViewCallOutput, ViewValue, ViewValueType,
 };
 
 pub mod host;
-pub use host::{TransactionHost, TransactionProgress};
+pub use host::{
+    CancellationToken, EventAdvice, EventError, EventHandler, EventHandlerRegistry,
+    ExecutionInterrupt, TransactionHost, TransactionProgress,
+};
 
 mod prover;
-pub use prover::{LocalTransactionProver, ProvingOptions, TransactionMastStore, TransactionProver};
+pub use prover::{
+    CalibrationPoint, CostEstimate, LocalTransactionProver, ProvingCalibration, ProvingOptions,
+    TransactionMastStore, TransactionProver,
+};
+#[cfg(all(feature = "std", not(feature = "async")))]
+pub use prover::{ProverServer, ProvingJobStatus, ProvingPool, ProvingProgressListener};
+#[cfg(feature = "std")]
+pub use prover::{RemoteTransactionProver, remote_prover};
+
+mod replay;
+pub use replay::{ReplayedTransaction, TransactionDiff, TransactionReplayer};
 
 mod verifier;
 pub use verifier::TransactionVerifier;
 
 mod errors;
 pub use errors::{
-    AuthenticationError, DataStoreError, TransactionExecutorError, TransactionProverError,
-    TransactionVerifierError,
+    AuthenticationError, DataStoreError, EventHandlerRegistryError, ExecutionFailure,
+    InterruptReason, MissingAccountData, ProvingCalibrationError, TransactionExecutorError,
+    TransactionProverError, TransactionReplayError, TransactionVerifierError, ViewCallDecodeError,
 };
 
 pub mod auth;
 
+#[cfg(feature = "std")]
+mod transport;
+
 // RE-EXPORTS
 // ================================================================================================
 pub use miden_objects::utils;
+#[cfg(feature = "std")]
+pub use transport::ServiceAddress;
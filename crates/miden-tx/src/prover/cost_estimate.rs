@@ -0,0 +1,266 @@
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+use core::time::Duration;
+
+use miden_objects::{
+    MIN_TX_EXECUTION_CYCLES,
+    transaction::TransactionMeasurements,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+use miden_prover::ProvingOptions;
+use winter_air::ProofOptions;
+
+use crate::errors::ProvingCalibrationError;
+
+// COST ESTIMATE
+// ================================================================================================
+
+/// An estimate of the cost of proving a transaction.
+///
+/// The estimate is derived from the [`TransactionMeasurements`] of an executed transaction and the
+/// [`ProvingOptions`] used to prove it, using a [`ProvingCalibration`] which contains the proving
+/// times and proof sizes measured for a set of reference trace lengths. The padded trace length of
+/// the transaction is derived from its longest trace segment, which is often the chiplets trace
+/// rather than the number of cycles (see [`TransactionMeasurements::trace_length`]). The
+/// measurements of the calibration point closest to this trace length are scaled as follows:
+/// - the proving time is scaled linearly with the trace length and the blowup factor.
+/// - the proof size is scaled linearly with the number of queries and the logarithm of the
+///   evaluation domain size.
+///
+/// The impact of other proving options (e.g., the hash function) is not modeled, so the most
+/// accurate estimates are obtained with a calibration measured using the same hash function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostEstimate {
+    trace_length: usize,
+    proving_time: Duration,
+    proof_size: usize,
+}
+
+impl CostEstimate {
+    /// Returns a new [CostEstimate] for a transaction with the provided measurements proven with
+    /// the provided options.
+    pub fn new(
+        measurements: &TransactionMeasurements,
+        proving_options: &ProvingOptions,
+        calibration: &ProvingCalibration,
+    ) -> Self {
+        let trace_length = measurements.trace_length().max(MIN_TX_EXECUTION_CYCLES as usize);
+        let proof_options: ProofOptions = proving_options.clone().into();
+        let blowup_factor = proof_options.blowup_factor() as u128;
+        let num_queries = proof_options.num_queries() as u128;
+
+        let point = calibration.closest_point(trace_length);
+
+        let proving_time_nanos =
+            point.proving_time.as_nanos() * trace_length as u128 * blowup_factor
+                / (point.trace_length as u128 * calibration.blowup_factor as u128);
+        let proving_time = Duration::from_nanos(proving_time_nanos.try_into().unwrap_or(u64::MAX));
+
+        let log_domain_size = (trace_length as u128 * blowup_factor).ilog2() as u128;
+        let ref_log_domain_size =
+            (point.trace_length as u128 * calibration.blowup_factor as u128).ilog2() as u128;
+        let proof_size = point.proof_size as u128 * num_queries * log_domain_size
+            / (calibration.num_queries as u128 * ref_log_domain_size);
+
+        Self {
+            trace_length,
+            proving_time,
+            proof_size: proof_size.try_into().unwrap_or(usize::MAX),
+        }
+    }
+
+    /// Returns the padded length of the execution trace of the transaction.
+    pub fn trace_length(&self) -> usize {
+        self.trace_length
+    }
+
+    /// Returns the estimated time required to prove the transaction.
+    pub fn proving_time(&self) -> Duration {
+        self.proving_time
+    }
+
+    /// Returns the estimated size of the transaction proof in bytes.
+    pub fn proof_size(&self) -> usize {
+        self.proof_size
+    }
+}
+
+// PROVING CALIBRATION
+// ================================================================================================
+
+/// The proving time and proof size measured for a transaction with the specified trace length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalibrationPoint {
+    /// The padded length of the execution trace of the measured transaction.
+    pub trace_length: usize,
+    /// The time it took to prove the measured transaction.
+    pub proving_time: Duration,
+    /// The size of the proof of the measured transaction in bytes.
+    pub proof_size: usize,
+}
+
+/// A table of proving times and proof sizes measured for a set of trace lengths, used to compute
+/// [`CostEstimate`]s.
+///
+/// The default calibration is based on the `bench-prover` benchmarks, which prove transactions
+/// with a padded trace length of 2^17 using the default [`ProvingOptions`], and thus consists of a
+/// single point. Estimates for this trace length match the benchmarks, while estimates for other
+/// trace lengths are extrapolated linearly from it. Since proving has fixed costs and its FFTs grow
+/// slightly faster than linearly with the trace length, the accuracy of these estimates decreases
+/// with the distance of the trace length from 2^17. Calibrations with points for several trace
+/// lengths, measured e.g. by benchmarking transactions of different sizes, yield more accurate
+/// estimates.
+///
+/// Since proving times heavily depend on the hardware, the calibration should be refreshed from
+/// the output of these benchmarks on the target machine: running them serializes the measured
+/// calibration to `proving_calibration.bin` in the criterion output directory, which can be
+/// loaded via [`ProvingCalibration::read_from_bytes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvingCalibration {
+    blowup_factor: usize,
+    num_queries: usize,
+    points: Vec<CalibrationPoint>,
+}
+
+impl ProvingCalibration {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The calibration points of the default calibration.
+    const DEFAULT_POINTS: [CalibrationPoint; 1] = [CalibrationPoint {
+        trace_length: 1 << 17,
+        proving_time: Duration::from_millis(2950),
+        proof_size: 87_500,
+    }];
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ProvingCalibration] consisting of the provided points which were measured
+    /// using the provided proving options.
+    ///
+    /// If several points have the same trace length, only the last one is kept.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No calibration points are provided.
+    /// - The trace length of any point is not a power of two.
+    pub fn new(
+        proving_options: &ProvingOptions,
+        points: impl IntoIterator<Item = CalibrationPoint>,
+    ) -> Result<Self, ProvingCalibrationError> {
+        let proof_options: ProofOptions = proving_options.clone().into();
+        Self::from_parts(proof_options.blowup_factor(), proof_options.num_queries(), points)
+    }
+
+    /// Returns a new [ProvingCalibration] instantiated from the provided parts.
+    fn from_parts(
+        blowup_factor: usize,
+        num_queries: usize,
+        points: impl IntoIterator<Item = CalibrationPoint>,
+    ) -> Result<Self, ProvingCalibrationError> {
+        let mut points_by_length = BTreeMap::new();
+        for point in points {
+            if !point.trace_length.is_power_of_two() {
+                return Err(ProvingCalibrationError::InvalidTraceLength(point.trace_length));
+            }
+            points_by_length.insert(point.trace_length, point);
+        }
+
+        if points_by_length.is_empty() {
+            return Err(ProvingCalibrationError::NoCalibrationPoints);
+        }
+
+        Ok(Self {
+            blowup_factor,
+            num_queries,
+            points: points_by_length.into_values().collect(),
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the calibration points sorted by trace length.
+    pub fn points(&self) -> &[CalibrationPoint] {
+        &self.points
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the calibration point with the trace length closest to the provided one, preferring
+    /// the larger trace length on ties.
+    fn closest_point(&self, trace_length: usize) -> &CalibrationPoint {
+        let log_length = trace_length.ilog2();
+        self.points
+            .iter()
+            .min_by_key(|point| {
+                let distance = point.trace_length.ilog2().abs_diff(log_length);
+                (distance, usize::MAX - point.trace_length)
+            })
+            .expect("calibration should contain at least one point")
+    }
+}
+
+impl Default for ProvingCalibration {
+    fn default() -> Self {
+        Self::new(&ProvingOptions::default(), Self::DEFAULT_POINTS)
+            .expect("default calibration points should be valid")
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for CalibrationPoint {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.trace_length);
+        target.write_u64(self.proving_time.as_secs());
+        target.write_u32(self.proving_time.subsec_nanos());
+        target.write_usize(self.proof_size);
+    }
+}
+
+impl Deserializable for CalibrationPoint {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let trace_length = source.read_usize()?;
+        let secs = source.read_u64()?;
+        let nanos = source.read_u32()?;
+        if nanos >= 1_000_000_000 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "sub-second proving time of {nanos} nanoseconds exceeds one second"
+            )));
+        }
+        let proof_size = source.read_usize()?;
+
+        Ok(Self {
+            trace_length,
+            proving_time: Duration::new(secs, nanos),
+            proof_size,
+        })
+    }
+}
+
+impl Serializable for ProvingCalibration {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.blowup_factor);
+        target.write_usize(self.num_queries);
+        self.points.write_into(target);
+    }
+}
+
+impl Deserializable for ProvingCalibration {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let blowup_factor = source.read_usize()?;
+        let num_queries = source.read_usize()?;
+        if blowup_factor == 0 || num_queries == 0 {
+            return Err(DeserializationError::InvalidValue(
+                "blowup factor and number of queries must be non-zero".into(),
+            ));
+        }
+        let points = Vec::<CalibrationPoint>::read_from(source)?;
+
+        Self::from_parts(blowup_factor, num_queries, points)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
@@ -1,6 +1,8 @@
 use alloc::{collections::BTreeMap, sync::Arc};
 
-use miden_lib::{MidenLib, StdLibrary, transaction::TransactionKernel, utils::sync::RwLock};
+use miden_lib::{
+    MidenLib, StdLibrary, transaction::TransactionKernelRegistry, utils::sync::RwLock,
+};
 use miden_objects::{Digest, account::AccountCode, assembly::mast::MastForest};
 use vm_processor::MastForestStore;
 
@@ -25,14 +27,15 @@ impl TransactionMastStore {
     /// The default libraries include:
     /// - Miden standard library (miden-stdlib).
     /// - Miden protocol library (miden-lib).
-    /// - Transaction kernel.
+    /// - All transaction kernels built into miden-lib.
     pub fn new() -> Self {
         let mast_forests = RwLock::new(BTreeMap::new());
         let store = Self { mast_forests };
 
-        // load transaction kernel MAST forest
-        let kernels_forest = TransactionKernel::kernel().mast_forest().clone();
-        store.insert(kernels_forest);
+        // load MAST forests of the built-in transaction kernels
+        for kernel in TransactionKernelRegistry::default().kernels() {
+            store.insert(kernel.library().mast_forest().clone());
+        }
 
         // load miden-stdlib MAST forest
         let miden_stdlib_forest = StdLibrary::default().mast_forest().clone();
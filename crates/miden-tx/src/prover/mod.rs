@@ -1,24 +1,46 @@
-#[cfg(feature = "async")]
-use alloc::boxed::Box;
-use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{
+    errors::ErrorCodeRegistry,
+    transaction::{TransactionKernel, TransactionKernelRegistry},
+};
 use miden_objects::{
-    account::delta::AccountUpdateDetails,
+    account::{Account, delta::AccountUpdateDetails},
     assembly::DefaultSourceManager,
-    transaction::{OutputNote, ProvenTransaction, ProvenTransactionBuilder, TransactionWitness},
+    transaction::{
+        OutputNote, ProvenTransaction, ProvenTransactionBuilder, TransactionMeasurements,
+        TransactionWitness,
+    },
 };
 pub use miden_prover::ProvingOptions;
 use miden_prover::prove;
-use vm_processor::{Digest, MemAdviceProvider};
+use vm_processor::MemAdviceProvider;
 use winter_maybe_async::*;
 
 use super::{TransactionHost, TransactionProverError};
-use crate::host::ScriptMastForestStore;
+use crate::{
+    executor::build_execution_failure,
+    host::{EventHandlerRegistry, ScriptMastForestStore},
+};
+
+mod cost_estimate;
+pub use cost_estimate::{CalibrationPoint, CostEstimate, ProvingCalibration};
 
 mod mast_store;
 pub use mast_store::TransactionMastStore;
 
+#[cfg(all(feature = "std", not(feature = "async")))]
+mod pool;
+#[cfg(all(feature = "std", not(feature = "async")))]
+pub use pool::{ProvingJobStatus, ProvingPool, ProvingProgressListener};
+
+#[cfg(feature = "std")]
+pub mod remote_prover;
+#[cfg(all(feature = "std", not(feature = "async")))]
+pub use remote_prover::ProverServer;
+#[cfg(feature = "std")]
+pub use remote_prover::RemoteTransactionProver;
+
 // TRANSACTION PROVER TRAIT
 // ================================================================================================
 
@@ -48,6 +70,10 @@ pub trait TransactionProver {
 pub struct LocalTransactionProver {
     mast_store: Arc<TransactionMastStore>,
     proof_options: ProvingOptions,
+    calibration: ProvingCalibration,
+    event_handlers: EventHandlerRegistry,
+    kernel_registry: TransactionKernelRegistry,
+    error_codes: ErrorCodeRegistry,
 }
 
 impl LocalTransactionProver {
@@ -56,7 +82,61 @@ impl LocalTransactionProver {
         Self {
             mast_store: Arc::new(TransactionMastStore::new()),
             proof_options,
+            calibration: ProvingCalibration::default(),
+            event_handlers: EventHandlerRegistry::default(),
+            kernel_registry: TransactionKernelRegistry::default(),
+            error_codes: ErrorCodeRegistry::new(),
+        }
+    }
+
+    /// Sets the [ProvingCalibration] used to estimate the cost of proving transactions.
+    pub fn with_calibration(mut self, calibration: ProvingCalibration) -> Self {
+        self.calibration = calibration;
+        self
+    }
+
+    /// Sets the [EventHandlerRegistry] used to handle events emitted by user code while proving
+    /// transactions.
+    ///
+    /// The handlers must match the ones used to execute the transactions (see
+    /// [`TransactionExecutor::with_event_handlers()`](crate::TransactionExecutor::with_event_handlers)).
+    pub fn with_event_handlers(mut self, event_handlers: EventHandlerRegistry) -> Self {
+        self.event_handlers = event_handlers;
+        self
+    }
+
+    /// Sets the [TransactionKernelRegistry] from which the kernel used to prove a transaction is
+    /// selected.
+    ///
+    /// A transaction is proven against the latest kernel supported by its reference block, which
+    /// is the same kernel it was executed against (see
+    /// [`TransactionExecutor::with_kernel_registry()`](crate::TransactionExecutor::with_kernel_registry)).
+    /// The MAST of all registered kernels is loaded into the MAST store of this prover.
+    pub fn with_kernel_registry(mut self, kernel_registry: TransactionKernelRegistry) -> Self {
+        for kernel in kernel_registry.kernels() {
+            self.mast_store.insert(kernel.library().mast_forest().clone());
         }
+        self.kernel_registry = kernel_registry;
+        self
+    }
+
+    /// Sets the [ErrorCodeRegistry] used to decode the error codes of failed executions of the
+    /// transaction program.
+    ///
+    /// See [`TransactionExecutor::with_error_codes()`](crate::TransactionExecutor::with_error_codes)
+    /// for details.
+    pub fn with_error_codes(mut self, error_codes: ErrorCodeRegistry) -> Self {
+        self.error_codes = error_codes;
+        self
+    }
+
+    /// Returns an estimate of the cost of proving a transaction with the provided measurements
+    /// using the proving options of this prover.
+    ///
+    /// The measurements of an executed transaction are available via
+    /// [`ExecutedTransaction::measurements()`](miden_objects::transaction::ExecutedTransaction::measurements).
+    pub fn estimate_cost(&self, measurements: &TransactionMeasurements) -> CostEstimate {
+        CostEstimate::new(measurements, &self.proof_options, &self.calibration)
     }
 }
 
@@ -65,6 +145,10 @@ impl Default for LocalTransactionProver {
         Self {
             mast_store: Arc::new(TransactionMastStore::new()),
             proof_options: Default::default(),
+            calibration: ProvingCalibration::default(),
+            event_handlers: EventHandlerRegistry::default(),
+            kernel_registry: TransactionKernelRegistry::default(),
+            error_codes: ErrorCodeRegistry::new(),
         }
     }
 }
@@ -75,6 +159,19 @@ impl TransactionProver for LocalTransactionProver {
     fn prove(
         &self,
         tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        maybe_await!(self.prove_with_execution_end_callback(tx_witness, None))
+    }
+}
+
+impl LocalTransactionProver {
+    /// Proves the provided transaction, invoking the provided callback (if any) once the
+    /// transaction program has been executed and the generation of the STARK proof starts.
+    #[maybe_async]
+    pub(crate) fn prove_with_execution_end_callback(
+        &self,
+        tx_witness: TransactionWitness,
+        on_execution_end: Option<Box<dyn FnMut() + Send>>,
     ) -> Result<ProvenTransaction, TransactionProverError> {
         let TransactionWitness { tx_inputs, tx_args, advice_witness } = tx_witness;
 
@@ -83,15 +180,27 @@ impl TransactionProver for LocalTransactionProver {
         let ref_block_num = tx_inputs.block_header().block_num();
         let ref_block_commitment = tx_inputs.block_header().commitment();
 
+        // select the kernel the transaction was executed against
+        let tx_kernel_commitment = tx_inputs.block_header().tx_kernel_commitment();
+        let kernel = self
+            .kernel_registry
+            .select(tx_kernel_commitment)
+            .ok_or(TransactionProverError::UnsupportedTransactionKernel(tx_kernel_commitment))?;
+
         // execute and prove
-        let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, Some(advice_witness));
+        let (stack_inputs, advice_inputs) = self.kernel_registry.prepare_inputs(
+            kernel.version(),
+            &tx_inputs,
+            &tx_args,
+            Some(advice_witness),
+        );
         let advice_provider = MemAdviceProvider::from(advice_inputs.into_inner());
 
         // load the store with account/note/tx_script MASTs
         self.mast_store.load_account_code(account.code());
-
-        let account_code_commitments: BTreeSet<Digest> = tx_args.foreign_account_code_commitments();
+        for foreign_account in tx_args.foreign_account_inputs() {
+            self.mast_store.load_account_code(foreign_account.code());
+        }
 
         let script_mast_store = ScriptMastForestStore::new(
             tx_args.tx_script(),
@@ -99,27 +208,40 @@ impl TransactionProver for LocalTransactionProver {
         );
 
         let mut host: TransactionHost<_> = TransactionHost::new(
-            &account.into(),
+            account,
             advice_provider,
             self.mast_store.as_ref(),
             script_mast_store,
             None,
-            account_code_commitments,
+            &tx_args,
         )
-        .map_err(TransactionProverError::TransactionHostCreationFailed)?;
+        .map_err(TransactionProverError::TransactionHostCreationFailed)?
+        .with_event_handlers(self.event_handlers.clone());
+        if let Some(callback) = on_execution_end {
+            host = host.with_execution_end_callback(callback);
+        }
 
         // For the prover, we assume that the transaction witness was successfully executed and so
         // there is no need to provide the actual source manager, as it is only used to improve
         // error quality. So we simply pass an empty one.
         let source_manager = Arc::new(DefaultSourceManager::default());
-        let (stack_outputs, proof) = maybe_await!(prove(
-            &TransactionKernel::main(),
+        let (stack_outputs, proof) = match maybe_await!(prove(
+            kernel.main(),
             stack_inputs,
             &mut host,
             self.proof_options.clone(),
             source_manager
-        ))
-        .map_err(TransactionProverError::TransactionProgramExecutionFailed)?;
+        )) {
+            Ok(result) => result,
+            Err(err) => {
+                // the prover does not expose the state of the VM, so the failing procedure is
+                // unknown
+                let failure = build_execution_failure(&host, &self.error_codes, err, None);
+                return Err(TransactionProverError::TransactionProgramExecutionFailed(Box::new(
+                    failure,
+                )));
+            },
+        };
 
         // extract transaction outputs and process transaction data
         let (advice_provider, account_delta, output_notes, _signatures, _tx_progress) =
@@ -141,16 +263,19 @@ impl TransactionProver for LocalTransactionProver {
             ref_block_num,
             ref_block_commitment,
             tx_outputs.expiration_block_num,
+            tx_outputs.fee,
             proof,
         )
         .add_input_notes(input_notes)
         .add_output_notes(output_notes);
 
         // If the account is on-chain, add the update details.
-        let builder = match account.is_onchain() {
+        let builder = match account.id().is_onchain() {
             true => {
                 let account_update_details = if account.is_new() {
-                    let mut account = account.clone();
+                    // new accounts are always provided in full, so the conversion does not fail
+                    let mut account = Account::try_from(account.clone())
+                        .map_err(TransactionProverError::AccountDeltaApplyFailed)?;
                     account
                         .apply_delta(&account_delta)
                         .map_err(TransactionProverError::AccountDeltaApplyFailed)?;
@@ -0,0 +1,211 @@
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use std::{
+    any::Any,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::Mutex,
+    thread,
+};
+
+use miden_objects::transaction::{ProvenTransaction, TransactionWitness};
+
+use super::LocalTransactionProver;
+use crate::errors::TransactionProverError;
+
+// PROVING PROGRESS LISTENER
+// ================================================================================================
+
+/// Receives the status updates of the jobs proven by a [ProvingPool].
+///
+/// A listener is registered via [`ProvingPool::with_progress_listener()`] and is invoked from the
+/// worker threads of the pool, so it may be invoked concurrently for different jobs.
+pub trait ProvingProgressListener: Send + Sync {
+    /// Handles the transition of the job at the provided submission index to the provided status.
+    fn on_status_change(&self, job: usize, status: ProvingJobStatus);
+}
+
+impl<F> ProvingProgressListener for F
+where
+    F: Fn(usize, ProvingJobStatus) + Send + Sync,
+{
+    fn on_status_change(&self, job: usize, status: ProvingJobStatus) {
+        self(job, status)
+    }
+}
+
+/// The status of a job proven by a [ProvingPool].
+///
+/// Every job starts out as [`Queued`](Self::Queued) and transitions through the statuses in the
+/// order in which they are declared, ending up either [`Done`](Self::Done) or
+/// [`Failed`](Self::Failed). A job which fails during execution skips the
+/// [`Proving`](Self::Proving) status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProvingJobStatus {
+    /// The job is waiting for a worker.
+    Queued,
+    /// The transaction program is being executed.
+    Executing,
+    /// The STARK proof of the transaction is being generated.
+    Proving,
+    /// The transaction was proven successfully.
+    Done,
+    /// Proving the transaction failed.
+    Failed,
+}
+
+// PROVING POOL
+// ================================================================================================
+
+/// A pool of workers which prove many transactions in parallel.
+///
+/// All workers prove transactions using the same [LocalTransactionProver], so they share its
+/// [`TransactionMastStore`](super::TransactionMastStore) and its proving options. The progress of
+/// every job can be observed by registering a [ProvingProgressListener].
+///
+/// Note that with the `concurrent` feature enabled, proving a single transaction already makes use
+/// of all available cores, so the number of workers should be chosen accordingly.
+pub struct ProvingPool {
+    prover: LocalTransactionProver,
+    num_workers: NonZeroUsize,
+    progress_listener: Option<Arc<dyn ProvingProgressListener>>,
+}
+
+impl ProvingPool {
+    /// Returns a new [ProvingPool] which proves transactions using the provided prover.
+    ///
+    /// The number of workers defaults to the available parallelism of the machine.
+    pub fn new(prover: LocalTransactionProver) -> Self {
+        Self {
+            prover,
+            num_workers: thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+            progress_listener: None,
+        }
+    }
+
+    /// Sets the number of workers which prove transactions in parallel.
+    pub fn with_num_workers(mut self, num_workers: NonZeroUsize) -> Self {
+        self.num_workers = num_workers;
+        self
+    }
+
+    /// Registers a [ProvingProgressListener] which is notified whenever the status of a job
+    /// changes.
+    pub fn with_progress_listener(
+        mut self,
+        progress_listener: Arc<dyn ProvingProgressListener>,
+    ) -> Self {
+        self.progress_listener = Some(progress_listener);
+        self
+    }
+
+    /// Returns the number of workers which prove transactions in parallel.
+    pub fn num_workers(&self) -> NonZeroUsize {
+        self.num_workers
+    }
+
+    /// Proves the provided transaction witnesses and returns the results in the order in which
+    /// the witnesses were provided.
+    ///
+    /// Every witness is a separate job identified by its index. Jobs are picked up by the workers
+    /// in submission order, and the failure of one job does not affect the others. A job which
+    /// panics is reported as [`Failed`](ProvingJobStatus::Failed) with
+    /// [`TransactionProverError::ProvingPanicked`]. This method returns once all jobs have either
+    /// been proven or failed.
+    pub fn prove_all(
+        &self,
+        tx_witnesses: impl IntoIterator<Item = TransactionWitness>,
+    ) -> Vec<Result<ProvenTransaction, TransactionProverError>> {
+        let jobs: Vec<_> = tx_witnesses.into_iter().enumerate().collect();
+        let num_jobs = jobs.len();
+        for (job, _) in jobs.iter() {
+            self.notify(*job, ProvingJobStatus::Queued);
+        }
+
+        let queue = Mutex::new(jobs.into_iter());
+        let num_workers = self.num_workers.get().min(num_jobs);
+        let mut results: BTreeMap<_, _> = thread::scope(|scope| {
+            let workers: Vec<_> =
+                (0..num_workers).map(|_| scope.spawn(|| self.run_worker(&queue))).collect();
+
+            // a worker only panics if the progress listener panics when a job has finished, in
+            // which case the results of the jobs proven by this worker are lost
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap_or_default())
+                .collect()
+        });
+
+        (0..num_jobs)
+            .map(|job| {
+                results.remove(&job).unwrap_or_else(|| {
+                    Err(TransactionProverError::ProvingPanicked(
+                        "proving worker panicked".to_string(),
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Proves jobs taken from the queue until it is empty, and returns the results together with
+    /// the indices of the jobs.
+    fn run_worker(
+        &self,
+        queue: &Mutex<impl Iterator<Item = (usize, TransactionWitness)>>,
+    ) -> Vec<(usize, Result<ProvenTransaction, TransactionProverError>)> {
+        let mut results = Vec::new();
+        loop {
+            let Some((job, tx_witness)) = queue.lock().expect("queue lock poisoned").next() else {
+                return results;
+            };
+
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                self.notify(job, ProvingJobStatus::Executing);
+                let on_execution_end = self.progress_listener.clone().map(|listener| {
+                    Box::new(move || listener.on_status_change(job, ProvingJobStatus::Proving))
+                        as Box<dyn FnMut() + Send>
+                });
+                self.prover.prove_with_execution_end_callback(tx_witness, on_execution_end)
+            }))
+            .unwrap_or_else(|payload| {
+                Err(TransactionProverError::ProvingPanicked(panic_message(payload)))
+            });
+
+            let status = match result {
+                Ok(_) => ProvingJobStatus::Done,
+                Err(_) => ProvingJobStatus::Failed,
+            };
+            self.notify(job, status);
+            results.push((job, result));
+        }
+    }
+
+    /// Notifies the progress listener, if any, that the status of the job has changed.
+    fn notify(&self, job: usize, status: ProvingJobStatus) {
+        if let Some(listener) = &self.progress_listener {
+            listener.on_status_change(job, status);
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the message of the panic with the provided payload.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic payload".to_string(),
+        },
+    }
+}
@@ -0,0 +1,399 @@
+//! A prover which offloads proving to a prover service running in a separate process.
+//!
+//! # Protocol
+//!
+//! The [RemoteTransactionProver] and the prover service communicate over a Unix domain socket or
+//! a TCP connection by exchanging frames. Each frame consists of the length of its payload in
+//! bytes, encoded as a little-endian `u32`, followed by the payload itself. A frame may contain at
+//! most [MAX_FRAME_LENGTH] bytes of payload.
+//!
+//! The payloads are encoded using the [`Serializable`] encoding of the Miden types.
+//!
+//! A request payload contains:
+//! - the protocol version ([PROTOCOL_VERSION]) as a `u8`.
+//! - the [TransactionWitness] to prove.
+//!
+//! A response payload contains the protocol version as a `u8`, followed by a status byte and a
+//! body depending on the status:
+//! - [STATUS_OK]: the transaction was proven; the body contains the [ProvenTransaction].
+//! - [STATUS_FAILED]: proving failed; the body contains the reason as a string.
+//!
+//! A connection may be used for several requests, each of which is answered by exactly one
+//! response before the next request is read. Either side may close the connection between
+//! requests.
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::time::Duration;
+#[cfg(all(unix, not(feature = "async")))]
+use std::os::unix::net::UnixListener;
+use std::{
+    io::{self, ErrorKind},
+    thread,
+};
+#[cfg(not(feature = "async"))]
+use std::{
+    io::{Read, Write},
+    net::TcpListener,
+};
+
+use miden_objects::{
+    transaction::{ProvenTransaction, TransactionWitness},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+use winter_maybe_async::*;
+
+use super::TransactionProver;
+use crate::{
+    errors::TransactionProverError,
+    transport::{ServiceAddress, read_frame, write_frame},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The version of the prover protocol implemented by this module.
+pub const PROTOCOL_VERSION: u8 = 1;
+
+/// The maximum length of the payload of a single frame in bytes.
+pub const MAX_FRAME_LENGTH: usize = 1 << 26;
+
+/// The default timeout for each read from or write to a connection served by a [ProverServer].
+pub const DEFAULT_SERVER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The response status of a proven transaction.
+pub const STATUS_OK: u8 = 0;
+
+/// The response status of a transaction which could not be proven.
+pub const STATUS_FAILED: u8 = 1;
+
+// REMOTE TRANSACTION PROVER
+// ================================================================================================
+
+/// A [TransactionProver] which offloads proving to a prover service running in a separate
+/// process, e.g., on a more powerful machine on the local network.
+///
+/// The reference implementation of the service is [ProverServer], which wraps a
+/// [`LocalTransactionProver`](crate::LocalTransactionProver). A new connection is opened for
+/// every transaction, and requests are sent using blocking I/O, also when the `async` feature is
+/// enabled.
+///
+/// # Retries
+///
+/// An attempt to prove a transaction is retried up to the configured number of times if the
+/// connection to the prover could not be established, or if the connection was closed before a
+/// response was received. Attempts which time out while waiting for the response are not retried,
+/// since the prover may still be busy proving the transaction.
+#[derive(Debug, Clone)]
+pub struct RemoteTransactionProver {
+    address: ServiceAddress,
+    timeout: Option<Duration>,
+    max_retries: usize,
+    retry_delay: Duration,
+}
+
+impl RemoteTransactionProver {
+    /// Returns a new [RemoteTransactionProver] which sends transactions to the prover at the
+    /// provided address.
+    ///
+    /// By default, requests do not time out and failed attempts are not retried.
+    pub fn new(address: ServiceAddress) -> Self {
+        Self {
+            address,
+            timeout: None,
+            max_retries: 0,
+            retry_delay: Duration::ZERO,
+        }
+    }
+
+    /// Sets the timeout for connecting to the prover and for each read from or write to the
+    /// connection.
+    ///
+    /// Since the prover responds only once the transaction is proven, the timeout should exceed
+    /// the time required to prove the largest expected transaction.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the number of times a failed attempt to prove a transaction is retried, and the delay
+    /// between consecutive attempts.
+    pub fn with_retries(mut self, max_retries: usize, retry_delay: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Returns the address of the prover.
+    pub fn address(&self) -> &ServiceAddress {
+        &self.address
+    }
+
+    /// Sends the request payload to the prover and returns the response payload.
+    fn exchange(&self, request: &[u8]) -> Result<Vec<u8>, AttemptError> {
+        let mut stream = self.address.connect(self.timeout).map_err(AttemptError::Retryable)?;
+
+        let response = write_frame(&mut stream, request, MAX_FRAME_LENGTH)
+            .and_then(|_| read_frame(&mut stream, MAX_FRAME_LENGTH));
+        match response {
+            Ok(Some(response)) => Ok(response),
+            Ok(None) => Err(AttemptError::Retryable(ErrorKind::UnexpectedEof.into())),
+            Err(err) if matches!(err.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock) => {
+                Err(AttemptError::Fatal(err))
+            },
+            Err(err) if err.kind() == ErrorKind::InvalidInput => Err(AttemptError::Fatal(err)),
+            Err(err) => Err(AttemptError::Retryable(err)),
+        }
+    }
+}
+
+#[maybe_async_trait]
+impl TransactionProver for RemoteTransactionProver {
+    /// Sends the transaction witness to the prover and returns the [ProvenTransaction] it
+    /// responded with.
+    ///
+    /// Since the ID of a transaction commits to its outputs, it cannot be computed from the
+    /// witness. Instead, the account ID, the reference block and the commitments to the initial
+    /// account state and the input notes, from which the ID is derived, are checked against the
+    /// witness.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The connection to the prover failed in all attempts, or timed out.
+    /// - The prover failed to prove the transaction or sent a malformed response.
+    /// - The proven transaction does not match the witness.
+    #[maybe_async]
+    fn prove(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let tx_inputs = &tx_witness.tx_inputs;
+        let expected_account_id = tx_inputs.account().id();
+        let expected_init_commitment = tx_inputs.account().init_commitment();
+        let expected_input_notes_commitment = tx_inputs.input_notes().commitment();
+        let expected_ref_block_num = tx_inputs.block_header().block_num();
+
+        let mut request = Vec::new();
+        request.write_u8(PROTOCOL_VERSION);
+        tx_witness.write_into(&mut request);
+
+        let mut attempts = 1;
+        let response = loop {
+            match self.exchange(&request) {
+                Ok(response) => break response,
+                Err(AttemptError::Retryable(_)) if attempts <= self.max_retries => {
+                    attempts += 1;
+                    thread::sleep(self.retry_delay);
+                },
+                Err(AttemptError::Retryable(err) | AttemptError::Fatal(err)) => {
+                    return Err(TransactionProverError::RemoteProverConnectionFailed {
+                        address: self.address.to_string(),
+                        attempts,
+                        source: Box::new(err),
+                    });
+                },
+            }
+        };
+
+        let proven_tx = ProvingResponse::read_from_bytes(&response)
+            .map_err(|err| {
+                TransactionProverError::other_with_source("prover sent a malformed response", err)
+            })?
+            .0
+            .map_err(TransactionProverError::RemoteProvingFailed)?;
+
+        let mismatch = if proven_tx.account_id() != expected_account_id {
+            Some("account ID")
+        } else if proven_tx.ref_block_num() != expected_ref_block_num {
+            Some("reference block")
+        } else if proven_tx.account_update().initial_state_commitment() != expected_init_commitment
+        {
+            Some("initial account state")
+        } else if proven_tx.input_notes().commitment() != expected_input_notes_commitment {
+            Some("input notes")
+        } else {
+            None
+        };
+        if let Some(field) = mismatch {
+            return Err(TransactionProverError::RemoteProverResponseMismatch {
+                transaction_id: proven_tx.id(),
+                field,
+            });
+        }
+
+        Ok(proven_tx)
+    }
+}
+
+/// The error of a single attempt to prove a transaction remotely.
+enum AttemptError {
+    /// The attempt failed in a way which allows it to be retried.
+    Retryable(io::Error),
+    /// The attempt failed in a way which does not allow it to be retried.
+    Fatal(io::Error),
+}
+
+// PROVER SERVER
+// ================================================================================================
+
+/// A prover service which proves the transactions sent by [RemoteTransactionProver]s using the
+/// wrapped [TransactionProver].
+///
+/// This is the reference implementation of the prover side of the protocol. The server is not
+/// available when the `async` feature is enabled.
+///
+/// Connections are served one at a time, so that every transaction is proven with all resources
+/// of the machine. To prevent a client which stops sending or receiving data from blocking all
+/// other clients, a connection is closed once a read from or a write to it takes longer than the
+/// server timeout (see [ProverServer::with_timeout]). The time spent proving a transaction does
+/// not count towards the timeout.
+#[cfg(not(feature = "async"))]
+pub struct ProverServer<P> {
+    prover: P,
+    timeout: Duration,
+}
+
+#[cfg(not(feature = "async"))]
+impl<P: TransactionProver> ProverServer<P> {
+    /// Returns a new [ProverServer] which proves transactions using the provided prover.
+    ///
+    /// The server timeout is set to [DEFAULT_SERVER_TIMEOUT].
+    pub fn new(prover: P) -> Self {
+        Self { prover, timeout: DEFAULT_SERVER_TIMEOUT }
+    }
+
+    /// Sets the timeout for each read from or write to a served connection.
+    ///
+    /// Since a read blocks until the client sends its next request, this is also the maximum
+    /// time a client may keep an idle connection open.
+    ///
+    /// # Panics
+    /// Panics if the timeout is zero.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        assert!(!timeout.is_zero(), "server timeout must not be zero");
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the timeout for each read from or write to a served connection.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Serves connections accepted by the provided TCP listener until accepting a connection
+    /// fails.
+    ///
+    /// Connections which fail, violate the protocol or time out are closed without affecting the
+    /// server.
+    pub fn serve_tcp(&self, listener: TcpListener) -> io::Result<()> {
+        loop {
+            let (stream, _) = listener.accept()?;
+            let _ = stream
+                .set_read_timeout(Some(self.timeout))
+                .and_then(|_| stream.set_write_timeout(Some(self.timeout)))
+                .and_then(|_| self.handle_connection(stream));
+        }
+    }
+
+    /// Serves connections accepted by the provided Unix domain socket listener until accepting a
+    /// connection fails.
+    ///
+    /// Connections which fail, violate the protocol or time out are closed without affecting the
+    /// server.
+    #[cfg(unix)]
+    pub fn serve_unix(&self, listener: UnixListener) -> io::Result<()> {
+        loop {
+            let (stream, _) = listener.accept()?;
+            let _ = stream
+                .set_read_timeout(Some(self.timeout))
+                .and_then(|_| stream.set_write_timeout(Some(self.timeout)))
+                .and_then(|_| self.handle_connection(stream));
+        }
+    }
+
+    /// Serves requests received over the provided stream until it is closed by the client.
+    ///
+    /// # Errors
+    /// Returns an error if reading from or writing to the stream fails, or if the client sent a
+    /// malformed request.
+    pub fn handle_connection<S: Read + Write>(&self, mut stream: S) -> io::Result<()> {
+        while let Some(request) = read_frame(&mut stream, MAX_FRAME_LENGTH)? {
+            let request = ProvingRequest::read_from_bytes(&request)
+                .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+            let response =
+                ProvingResponse(self.prover.prove(request.0).map_err(|err| err.to_string()));
+            write_frame(&mut stream, &response.to_bytes(), MAX_FRAME_LENGTH)?;
+        }
+
+        Ok(())
+    }
+}
+
+// MESSAGES
+// ================================================================================================
+
+/// A request to prove a transaction, as sent by a [RemoteTransactionProver].
+#[cfg(not(feature = "async"))]
+struct ProvingRequest(TransactionWitness);
+
+#[cfg(not(feature = "async"))]
+impl Deserializable for ProvingRequest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        read_protocol_version(source)?;
+        Ok(Self(TransactionWitness::read_from(source)?))
+    }
+}
+
+/// The response to a [ProvingRequest], as sent by a [ProverServer].
+struct ProvingResponse(Result<ProvenTransaction, String>);
+
+impl Serializable for ProvingResponse {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(PROTOCOL_VERSION);
+        match &self.0 {
+            Ok(proven_tx) => {
+                target.write_u8(STATUS_OK);
+                proven_tx.write_into(target);
+            },
+            Err(reason) => {
+                target.write_u8(STATUS_FAILED);
+                reason.write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for ProvingResponse {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        read_protocol_version(source)?;
+        let result = match source.read_u8()? {
+            STATUS_OK => Ok(ProvenTransaction::read_from(source)?),
+            STATUS_FAILED => Err(String::read_from(source)?),
+            status => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "unknown response status {status}"
+                )));
+            },
+        };
+
+        Ok(Self(result))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads the protocol version from the source and checks that it is supported.
+fn read_protocol_version<R: ByteReader>(source: &mut R) -> Result<(), DeserializationError> {
+    let version = source.read_u8()?;
+    if version != PROTOCOL_VERSION {
+        return Err(DeserializationError::InvalidValue(format!(
+            "unsupported prover protocol version {version}, expected {PROTOCOL_VERSION}"
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,337 @@
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+
+use miden_lib::{
+    errors::ErrorCodeRegistry,
+    transaction::{TransactionKernel, TransactionKernelRegistry},
+};
+use miden_objects::{
+    Felt,
+    account::{AccountDelta, AccountHeader, AccountStorageDelta, AccountVaultDelta},
+    assembly::DefaultSourceManager,
+    asset::FungibleAsset,
+    block::BlockNumber,
+    transaction::{ExecutedTransaction, OutputNote, TransactionOutputs, TransactionWitness},
+};
+use vm_processor::{ExecutionOptions, MemAdviceProvider, Process};
+
+use crate::{
+    TransactionHost, TransactionMastStore, TransactionReplayError,
+    executor::{build_execution_failure, failing_procedure},
+    host::{EventHandlerRegistry, ScriptMastForestStore},
+};
+
+// TRANSACTION REPLAYER
+// ================================================================================================
+
+/// The [TransactionReplayer] re-executes [TransactionWitness]es and compares the results with the
+/// results of the original execution.
+///
+/// A witness is re-executed using only the data embedded in it, i.e., without access to a
+/// [`DataStore`](crate::DataStore) or an authenticator, exactly as it would be re-executed by a
+/// prover. Thus, a successful replay which matches the original [ExecutedTransaction] indicates
+/// that the witness is complete and that the transaction executes deterministically.
+pub struct TransactionReplayer {
+    mast_store: Arc<TransactionMastStore>,
+    exec_options: ExecutionOptions,
+    event_handlers: EventHandlerRegistry,
+    kernel_registry: TransactionKernelRegistry,
+    error_codes: ErrorCodeRegistry,
+}
+
+impl TransactionReplayer {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionReplayer] instance.
+    pub fn new() -> Self {
+        Self {
+            mast_store: Arc::new(TransactionMastStore::new()),
+            exec_options: ExecutionOptions::default(),
+            event_handlers: EventHandlerRegistry::default(),
+            kernel_registry: TransactionKernelRegistry::default(),
+            error_codes: ErrorCodeRegistry::new(),
+        }
+    }
+
+    /// Sets the [ExecutionOptions] used to re-execute transactions.
+    pub fn with_exec_options(mut self, exec_options: ExecutionOptions) -> Self {
+        self.exec_options = exec_options;
+        self
+    }
+
+    /// Sets the [EventHandlerRegistry] used to handle events emitted by user code while
+    /// re-executing transactions.
+    ///
+    /// The handlers must match the ones used to execute the transactions (see
+    /// [`TransactionExecutor::with_event_handlers()`](crate::TransactionExecutor::with_event_handlers)).
+    pub fn with_event_handlers(mut self, event_handlers: EventHandlerRegistry) -> Self {
+        self.event_handlers = event_handlers;
+        self
+    }
+
+    /// Sets the [TransactionKernelRegistry] from which the kernel used to re-execute a
+    /// transaction is selected.
+    ///
+    /// The registry must match the one used to execute the transactions (see
+    /// [`TransactionExecutor::with_kernel_registry()`](crate::TransactionExecutor::with_kernel_registry)).
+    /// The MAST of all registered kernels is loaded into the MAST store of this replayer.
+    pub fn with_kernel_registry(mut self, kernel_registry: TransactionKernelRegistry) -> Self {
+        for kernel in kernel_registry.kernels() {
+            self.mast_store.insert(kernel.library().mast_forest().clone());
+        }
+        self.kernel_registry = kernel_registry;
+        self
+    }
+
+    /// Sets the [ErrorCodeRegistry] used to decode the error codes of failed re-executions.
+    ///
+    /// See [`TransactionExecutor::with_error_codes()`](crate::TransactionExecutor::with_error_codes)
+    /// for details.
+    pub fn with_error_codes(mut self, error_codes: ErrorCodeRegistry) -> Self {
+        self.error_codes = error_codes;
+        self
+    }
+
+    // REPLAY
+    // --------------------------------------------------------------------------------------------
+
+    /// Re-executes the transaction described by the provided witness and returns its results.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The reference block of the transaction does not support any registered kernel.
+    /// - The transaction host could not be created from the witness.
+    /// - The transaction program fails to execute using the data in the witness.
+    /// - The transaction outputs could not be constructed from the results of the execution.
+    pub fn replay(
+        &self,
+        tx_witness: &TransactionWitness,
+    ) -> Result<ReplayedTransaction, TransactionReplayError> {
+        let TransactionWitness { tx_inputs, tx_args, advice_witness } = tx_witness;
+
+        let tx_kernel_commitment = tx_inputs.block_header().tx_kernel_commitment();
+        let kernel = self
+            .kernel_registry
+            .select(tx_kernel_commitment)
+            .ok_or(TransactionReplayError::UnsupportedTransactionKernel(tx_kernel_commitment))?;
+
+        let (stack_inputs, advice_inputs) = self.kernel_registry.prepare_inputs(
+            kernel.version(),
+            tx_inputs,
+            tx_args,
+            Some(advice_witness.clone()),
+        );
+        let advice_provider = MemAdviceProvider::from(advice_inputs.into_inner());
+
+        self.mast_store.load_account_code(tx_inputs.account().code());
+        for foreign_account in tx_args.foreign_account_inputs() {
+            self.mast_store.load_account_code(foreign_account.code());
+        }
+
+        let script_mast_store = ScriptMastForestStore::new(
+            tx_args.tx_script(),
+            tx_inputs.input_notes().iter().map(|n| n.note().script()),
+        );
+
+        let mut host: TransactionHost<_> = TransactionHost::new(
+            tx_inputs.account(),
+            advice_provider,
+            self.mast_store.as_ref(),
+            script_mast_store,
+            None,
+            tx_args,
+        )
+        .map_err(TransactionReplayError::TransactionHostCreationFailed)?
+        .with_event_handlers(self.event_handlers.clone());
+
+        // the witness carries no source information, so an empty source manager is used
+        let source_manager = Arc::new(DefaultSourceManager::default());
+        let program = kernel.main();
+        let mut process = Process::new(program.kernel().clone(), stack_inputs, self.exec_options)
+            .with_source_manager(source_manager);
+        let stack_outputs = match process.execute(program, &mut host) {
+            Ok(stack_outputs) => stack_outputs,
+            Err(err) => {
+                let procedure = failing_procedure(process, kernel, &self.error_codes);
+                let failure = build_execution_failure(&host, &self.error_codes, err, procedure);
+                return Err(TransactionReplayError::TransactionProgramExecutionFailed(Box::new(
+                    failure,
+                )));
+            },
+        };
+
+        let (advice_provider, account_delta, output_notes, _signatures, _tx_progress) =
+            host.into_parts();
+        let (_, map, _) = advice_provider.into_parts();
+        let tx_outputs =
+            TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
+                .map_err(TransactionReplayError::TransactionOutputConstructionFailed)?;
+
+        Ok(ReplayedTransaction { tx_outputs, account_delta })
+    }
+
+    /// Re-executes the transaction described by the provided witness and compares its results
+    /// with the provided executed transaction.
+    ///
+    /// Returns the differences between the results of the two executions, which is empty if the
+    /// results match.
+    ///
+    /// # Errors
+    /// Returns an error if the transaction could not be re-executed (see
+    /// [TransactionReplayer::replay]).
+    pub fn replay_and_diff(
+        &self,
+        tx_witness: &TransactionWitness,
+        executed_tx: &ExecutedTransaction,
+    ) -> Result<Vec<TransactionDiff>, TransactionReplayError> {
+        self.replay(tx_witness).map(|replayed_tx| replayed_tx.diff(executed_tx))
+    }
+}
+
+impl Default for TransactionReplayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// REPLAYED TRANSACTION
+// ================================================================================================
+
+/// The results of re-executing a transaction via a [TransactionReplayer].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayedTransaction {
+    tx_outputs: TransactionOutputs,
+    account_delta: AccountDelta,
+}
+
+impl ReplayedTransaction {
+    /// Returns the outputs of the re-executed transaction.
+    pub fn tx_outputs(&self) -> &TransactionOutputs {
+        &self.tx_outputs
+    }
+
+    /// Returns the changes made to the account by the re-executed transaction.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Compares the results of this transaction with the results of the provided executed
+    /// transaction, and returns the differences between them.
+    ///
+    /// The final account header, the account delta, the output notes, the expiration block number
+    /// and the fee are compared. In each returned [TransactionDiff], `expected` refers to the
+    /// executed transaction and `actual` to this transaction.
+    pub fn diff(&self, executed_tx: &ExecutedTransaction) -> Vec<TransactionDiff> {
+        let mut diffs = Vec::new();
+
+        if executed_tx.final_account() != &self.tx_outputs.account {
+            diffs.push(TransactionDiff::FinalAccount {
+                expected: executed_tx.final_account().clone(),
+                actual: self.tx_outputs.account.clone(),
+            });
+        }
+
+        let expected_delta = executed_tx.account_delta();
+        if expected_delta.storage() != self.account_delta.storage() {
+            diffs.push(TransactionDiff::StorageDelta {
+                expected: expected_delta.storage().clone(),
+                actual: self.account_delta.storage().clone(),
+            });
+        }
+        if expected_delta.vault() != self.account_delta.vault() {
+            diffs.push(TransactionDiff::VaultDelta {
+                expected: expected_delta.vault().clone(),
+                actual: self.account_delta.vault().clone(),
+            });
+        }
+        if expected_delta.nonce_delta() != self.account_delta.nonce_delta() {
+            diffs.push(TransactionDiff::NonceDelta {
+                expected: expected_delta.nonce_delta(),
+                actual: self.account_delta.nonce_delta(),
+            });
+        }
+
+        let expected_notes = executed_tx.output_notes();
+        let actual_notes = &self.tx_outputs.output_notes;
+        if expected_notes.num_notes() != actual_notes.num_notes() {
+            diffs.push(TransactionDiff::NumOutputNotes {
+                expected: expected_notes.num_notes(),
+                actual: actual_notes.num_notes(),
+            });
+        }
+        for (index, (expected, actual)) in
+            expected_notes.iter().zip(actual_notes.iter()).enumerate()
+        {
+            if expected != actual {
+                diffs.push(TransactionDiff::OutputNote {
+                    index,
+                    expected: expected.clone(),
+                    actual: actual.clone(),
+                });
+            }
+        }
+
+        if executed_tx.expiration_block_num() != self.tx_outputs.expiration_block_num {
+            diffs.push(TransactionDiff::ExpirationBlockNum {
+                expected: executed_tx.expiration_block_num(),
+                actual: self.tx_outputs.expiration_block_num,
+            });
+        }
+
+        if executed_tx.fee() != self.tx_outputs.fee {
+            diffs.push(TransactionDiff::Fee {
+                expected: executed_tx.fee(),
+                actual: self.tx_outputs.fee,
+            });
+        }
+
+        diffs
+    }
+}
+
+// TRANSACTION DIFF
+// ================================================================================================
+
+/// A difference between the results of two executions of the same transaction.
+///
+/// `expected` refers to the result of the original execution and `actual` to the result of the
+/// re-execution.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionDiff {
+    /// The final states of the account differ.
+    FinalAccount {
+        expected: AccountHeader,
+        actual: AccountHeader,
+    },
+    /// The changes made to the account storage differ.
+    StorageDelta {
+        expected: AccountStorageDelta,
+        actual: AccountStorageDelta,
+    },
+    /// The changes made to the account vault differ.
+    VaultDelta {
+        expected: AccountVaultDelta,
+        actual: AccountVaultDelta,
+    },
+    /// The nonce increments differ.
+    NonceDelta { expected: Felt, actual: Felt },
+    /// The numbers of created output notes differ.
+    NumOutputNotes { expected: usize, actual: usize },
+    /// The output notes at the specified index differ.
+    OutputNote {
+        index: usize,
+        expected: OutputNote,
+        actual: OutputNote,
+    },
+    /// The expiration block numbers differ.
+    ExpirationBlockNum {
+        expected: BlockNumber,
+        actual: BlockNumber,
+    },
+    /// The fees paid by the transactions differ.
+    Fee {
+        expected: FungibleAsset,
+        actual: FungibleAsset,
+    },
+}
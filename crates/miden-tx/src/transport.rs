@@ -0,0 +1,142 @@
+//! Connection and framing helpers shared by the components which talk to services running in a
+//! separate process, i.e., the socket signer and the remote prover.
+//!
+//! Both protocols exchange frames over a Unix domain socket or a TCP connection. Each frame
+//! consists of the length of its payload in bytes, encoded as a little-endian `u32`, followed by
+//! the payload itself.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{fmt, str::FromStr, time::Duration};
+use std::{
+    io::{self, ErrorKind, Read, Write},
+    net::{SocketAddr, TcpStream},
+};
+#[cfg(unix)]
+use std::{os::unix::net::UnixStream, path::PathBuf};
+
+// SERVICE ADDRESS
+// ================================================================================================
+
+/// The address of a service running in a separate process, such as a signer or a prover.
+///
+/// Addresses are parsed from strings of the form `unix:<path>` for Unix domain sockets and
+/// `<host>:<port>` (e.g., `127.0.0.1:7000`) for TCP connections.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServiceAddress {
+    /// A TCP socket address.
+    Tcp(SocketAddr),
+    /// The path of a Unix domain socket.
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+impl ServiceAddress {
+    /// Returns a new stream connected to this address.
+    ///
+    /// The timeout, if any, applies to establishing a TCP connection as well as to every
+    /// subsequent read from and write to the stream.
+    pub(crate) fn connect(&self, timeout: Option<Duration>) -> io::Result<Box<dyn Stream>> {
+        match self {
+            Self::Tcp(addr) => {
+                let stream = match timeout {
+                    Some(timeout) => TcpStream::connect_timeout(addr, timeout)?,
+                    None => TcpStream::connect(addr)?,
+                };
+                stream.set_read_timeout(timeout)?;
+                stream.set_write_timeout(timeout)?;
+                stream.set_nodelay(true)?;
+                Ok(Box::new(stream))
+            },
+            #[cfg(unix)]
+            Self::Unix(path) => {
+                let stream = UnixStream::connect(path)?;
+                stream.set_read_timeout(timeout)?;
+                stream.set_write_timeout(timeout)?;
+                Ok(Box::new(stream))
+            },
+        }
+    }
+}
+
+impl FromStr for ServiceAddress {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(unix)]
+        if let Some(path) = s.strip_prefix("unix:") {
+            return Ok(Self::Unix(PathBuf::from(path)));
+        }
+
+        s.parse::<SocketAddr>()
+            .map(Self::Tcp)
+            .map_err(|err| format!("invalid address `{s}`: {err}"))
+    }
+}
+
+impl fmt::Display for ServiceAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp(addr) => write!(f, "{addr}"),
+            #[cfg(unix)]
+            Self::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+/// A bidirectional byte stream to a service.
+pub(crate) trait Stream: Read + Write {}
+
+impl<T: Read + Write> Stream for T {}
+
+// FRAMING
+// ================================================================================================
+
+/// Writes the provided payload to the stream as a single frame.
+///
+/// # Errors
+/// Returns an error if the payload is longer than `max_length` bytes or if writing to the stream
+/// fails.
+pub(crate) fn write_frame(
+    stream: &mut impl Write,
+    payload: &[u8],
+    max_length: usize,
+) -> io::Result<()> {
+    if payload.len() > max_length {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("frame length {} exceeds the maximum of {max_length}", payload.len()),
+        ));
+    }
+
+    stream.write_all(&(payload.len() as u32).to_le_bytes())?;
+    stream.write_all(payload)?;
+    stream.flush()
+}
+
+/// Reads the payload of a single frame from the stream, or returns `None` if the stream was closed
+/// before the start of the frame.
+///
+/// # Errors
+/// Returns an error if the frame is longer than `max_length` bytes or if reading from the stream
+/// fails.
+pub(crate) fn read_frame(stream: &mut impl Read, max_length: usize) -> io::Result<Option<Vec<u8>>> {
+    let mut length = [0; 4];
+    match stream.read_exact(&mut length) {
+        Ok(()) => (),
+        Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
+
+    let length = u32::from_le_bytes(length) as usize;
+    if length > max_length {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            format!("frame length {length} exceeds the maximum of {max_length}"),
+        ));
+    }
+
+    let mut payload = vec![0; length];
+    stream.read_exact(&mut payload)?;
+
+    Ok(Some(payload))
+}
@@ -1,6 +1,14 @@
-use miden_lib::transaction::TransactionKernel;
-use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+use miden_lib::transaction::{TransactionKernel, TransactionKernelRegistry, VersionedKernel};
+use miden_objects::{
+    Digest,
+    transaction::{ProvenTransaction, TransactionId},
+};
 use miden_verifier::verify;
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
 
 use super::TransactionVerifierError;
 
@@ -9,28 +17,115 @@ use super::TransactionVerifierError;
 
 /// The [TransactionVerifier] is used to verify  [ProvenTransaction]s.
 ///
-/// The [TransactionVerifier] contains a [ProgramInfo] object which is associated with the
-/// transaction kernel program.  The `proof_security_level` specifies the minimum security
+/// The [TransactionVerifier] contains a [TransactionKernelRegistry] with the transaction kernels
+/// that proofs are verified against. The `proof_security_level` specifies the minimum security
 /// level that the transaction proof must have in order to be considered valid.
 pub struct TransactionVerifier {
-    tx_program_info: ProgramInfo,
+    kernel_registry: TransactionKernelRegistry,
     proof_security_level: u32,
 }
 
 impl TransactionVerifier {
     /// Returns a new [TransactionVerifier] instantiated with the specified security level.
+    ///
+    /// The verifier verifies proofs against the transaction kernels built into miden-lib.
     pub fn new(proof_security_level: u32) -> Self {
-        let tx_program_info = TransactionKernel::program_info();
-        Self { tx_program_info, proof_security_level }
+        Self {
+            kernel_registry: TransactionKernelRegistry::default(),
+            proof_security_level,
+        }
     }
 
-    /// Verifies the provided [ProvenTransaction] against the transaction kernel.
+    /// Sets the [TransactionKernelRegistry] with the transaction kernels that proofs are verified
+    /// against.
+    pub fn with_kernel_registry(mut self, kernel_registry: TransactionKernelRegistry) -> Self {
+        self.kernel_registry = kernel_registry;
+        self
+    }
+
+    /// Verifies the provided [ProvenTransaction] against the latest registered transaction kernel.
+    ///
+    /// Transactions whose reference block does not support the latest kernel, e.g. transactions
+    /// proven against a previous kernel during an upgrade of the kernel, must be verified via
+    /// [`Self::verify_with_kernel_commitment`] instead.
     ///
     /// # Errors
     /// Returns an error if:
     /// - Transaction verification fails.
     /// - The security level of the verified proof is insufficient.
     pub fn verify(&self, transaction: &ProvenTransaction) -> Result<(), TransactionVerifierError> {
+        self.verify_against(transaction, self.kernel_registry.latest())
+    }
+
+    /// Verifies the provided [ProvenTransaction] against the transaction kernel selected by the
+    /// provided transaction kernel commitment.
+    ///
+    /// This should be used with the transaction kernel commitment of the reference block of the
+    /// transaction. The proof is verified against the latest kernel supported by that block, which
+    /// is the kernel the transaction was executed and proven against.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The provided commitment does not match any prefix of the registered kernels.
+    /// - Transaction verification fails.
+    /// - The security level of the verified proof is insufficient.
+    pub fn verify_with_kernel_commitment(
+        &self,
+        transaction: &ProvenTransaction,
+        tx_kernel_commitment: Digest,
+    ) -> Result<(), TransactionVerifierError> {
+        let kernel = self
+            .kernel_registry
+            .select(tx_kernel_commitment)
+            .ok_or(TransactionVerifierError::UnsupportedTransactionKernel(tx_kernel_commitment))?;
+        self.verify_against(transaction, kernel)
+    }
+
+    /// Verifies the provided [ProvenTransaction]s, each against the transaction kernel selected by
+    /// the transaction kernel commitment it is paired with, i.e., the commitment of the reference
+    /// block of the transaction.
+    ///
+    /// Unlike [`Self::verify_with_kernel_commitment`], this does not stop at the first transaction
+    /// which fails to verify. When the `concurrent` feature is enabled, the proofs are verified in
+    /// parallel.
+    ///
+    /// # Errors
+    /// Returns the IDs of all transactions which failed to verify together with the reason for the
+    /// failure, in the order in which the transactions were provided. See
+    /// [`Self::verify_with_kernel_commitment`] for the possible reasons.
+    pub fn verify_many<T>(
+        &self,
+        transactions: &[(T, Digest)],
+    ) -> Result<(), Vec<(TransactionId, TransactionVerifierError)>>
+    where
+        T: Borrow<ProvenTransaction> + Sync,
+    {
+        #[cfg(feature = "concurrent")]
+        let transactions = transactions.par_iter();
+        #[cfg(not(feature = "concurrent"))]
+        let transactions = transactions.iter();
+
+        let failures: Vec<_> = transactions
+            .filter_map(|(tx, tx_kernel_commitment)| {
+                let tx = tx.borrow();
+                self.verify_with_kernel_commitment(tx, *tx_kernel_commitment)
+                    .err()
+                    .map(|err| (tx.id(), err))
+            })
+            .collect();
+
+        if failures.is_empty() { Ok(()) } else { Err(failures) }
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies the provided [ProvenTransaction] against the provided kernel.
+    fn verify_against(
+        &self,
+        transaction: &ProvenTransaction,
+        kernel: &VersionedKernel,
+    ) -> Result<(), TransactionVerifierError> {
         // build stack inputs and outputs
         let stack_inputs = TransactionKernel::build_input_stack(
             transaction.account_id(),
@@ -43,17 +138,14 @@ impl TransactionVerifier {
             transaction.account_update().final_state_commitment(),
             transaction.account_update().account_delta_commitment(),
             transaction.output_notes().commitment(),
+            transaction.fee(),
             transaction.expiration_block_num(),
         );
 
         // verify transaction proof
-        let proof_security_level = verify(
-            self.tx_program_info.clone(),
-            stack_inputs,
-            stack_outputs,
-            transaction.proof().clone(),
-        )
-        .map_err(TransactionVerifierError::TransactionVerificationFailed)?;
+        let proof_security_level =
+            verify(kernel.program_info(), stack_inputs, stack_outputs, transaction.proof().clone())
+                .map_err(TransactionVerifierError::TransactionVerificationFailed)?;
 
         // check security level
         if proof_security_level < self.proof_security_level {